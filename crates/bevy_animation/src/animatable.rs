@@ -2,6 +2,7 @@ use crate::util;
 use bevy_ecs::world::World;
 use bevy_math::*;
 use bevy_reflect::Reflect;
+use bevy_render::color::Color;
 use bevy_transform::prelude::Transform;
 use bevy_utils::FloatOrd;
 
@@ -88,6 +89,27 @@ impl Animatable for Vec3 {
     }
 }
 
+// Colors are blended in linear space, so the result is always a `Color::RgbaLinear`
+impl Animatable for Color {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        Color::rgba_linear_from_array(Vec4::interpolate(
+            &Vec4::from(a.as_linear_rgba_f32()),
+            &Vec4::from(b.as_linear_rgba_f32()),
+            t,
+        ))
+    }
+
+    #[inline]
+    fn blend(inputs: impl Iterator<Item = BlendInput<Self>>) -> Self {
+        Color::rgba_linear_from_array(Vec4::blend(inputs.map(|input| BlendInput {
+            weight: input.weight,
+            value: Vec4::from(input.value.as_linear_rgba_f32()),
+            additive: input.additive,
+        })))
+    }
+}
+
 impl Animatable for bool {
     #[inline]
     fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
@@ -0,0 +1,74 @@
+//! Events attached to [`AnimationClip`]s, triggered when playback reaches their time.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy_ecs::{entity::Entity, system::Commands};
+
+use crate::AnimationClip;
+
+/// A function called when the playback of an [`AnimationClip`] reaches an event.
+///
+/// It receives the entity of the [`AnimationPlayer`](crate::AnimationPlayer) playing the clip.
+#[derive(Clone)]
+pub struct AnimationEventFn(pub Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>);
+
+impl Debug for AnimationEventFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AnimationEventFn").finish()
+    }
+}
+
+/// An event of an [`AnimationClip`], see [`AnimationClip::add_event`].
+#[derive(Clone, Debug)]
+pub struct AnimationEvent {
+    /// The time of the event in the clip, in seconds.
+    pub time: f32,
+    /// What to do when the event is reached.
+    pub trigger: AnimationEventFn,
+}
+
+/// The part of a clip that an [`ActiveAnimation`](crate::ActiveAnimation) went through
+/// during a single update.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PlaybackStep {
+    /// The seek time before the update.
+    pub(crate) from: f32,
+    /// The seek time after the update.
+    pub(crate) to: f32,
+    /// Whether playback wrapped around the end (or the start, when reversed) of the clip.
+    pub(crate) wrapped: bool,
+    pub(crate) reversed: bool,
+}
+
+impl PlaybackStep {
+    /// Whether playback went through `time` during this step.
+    ///
+    /// The time the step started from is included and the time it stopped at is excluded, so
+    /// that an event is reached exactly once when playback goes through it frame by frame.
+    /// A playback reaching the end of a clip without looping includes the end of the clip.
+    fn contains(&self, time: f32, duration: f32) -> bool {
+        match (self.reversed, self.wrapped) {
+            (false, false) => self.from <= time && (time < self.to || self.to >= duration),
+            (false, true) => self.from <= time || time < self.to,
+            (true, false) => self.to < time && time <= self.from,
+            (true, true) => time <= self.from || self.to < time,
+        }
+    }
+}
+
+impl AnimationClip {
+    /// Calls the events of this clip that `step` went through, for the player `entity`.
+    pub(crate) fn trigger_events(
+        &self,
+        step: PlaybackStep,
+        entity: Entity,
+        commands: &mut Commands,
+    ) {
+        for event in &self.events {
+            if step.contains(event.time, self.duration) {
+                (event.trigger.0)(commands, entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,446 @@
+//! The [`AnimationGraph`] asset, which describes how multiple animation clips are blended together.
+
+use std::io::Write;
+
+use bevy_asset::{io::Reader, Asset, AssetLoader, AssetPath, AsyncReadExt, Handle, LoadContext};
+use bevy_core::Name;
+use bevy_reflect::TypePath;
+use bevy_utils::{
+    petgraph::{
+        algo::is_cyclic_directed,
+        graph::{DiGraph, NodeIndex},
+        visit::EdgeRef,
+        Direction,
+    },
+    BoxedFuture, HashMap,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{AnimationClip, EntityPath};
+
+/// The index of a node in an [`AnimationGraph`].
+pub type AnimationNodeIndex = NodeIndex<u32>;
+
+/// A set of mask groups, one per bit.
+///
+/// Targets of an [`AnimationGraph`] can be assigned to mask groups with
+/// [`AnimationGraph::add_target_to_mask_group`], and a node whose [`AnimationGraphNode::mask`]
+/// contains one of the groups of a target doesn't affect it. This is how, for instance, an
+/// upper-body layer is prevented from animating the legs.
+pub type AnimationMask = u64;
+
+/// A graph of animation clips, blend nodes and additive nodes, driving many clips at once.
+///
+/// The [`AnimationPlayer`](crate::AnimationPlayer) of an entity that also has a
+/// [`Handle<AnimationGraph>`] plays the clip nodes started with
+/// [`AnimationPlayer::start_node`](crate::AnimationPlayer::start_node), and evaluates the graph
+/// from its root to find the value of every animated property:
+///
+/// - A [`Clip`](AnimationNodeType::Clip) node outputs the value of its clip at the current
+///   time of the node, if it's playing.
+/// - A [`Blend`](AnimationNodeType::Blend) node outputs the weighted average of its children.
+/// - An [`Add`](AnimationNodeType::Add) node outputs its first child, with the other children
+///   added on top of it as offsets.
+///
+/// The weight of each node multiplies the weights of its output, and when the total weight of
+/// a property is below `1.0`, the property is only partially blended into its current value.
+///
+/// Graphs can be loaded from `.animgraph.ron` files, which reference their clips by asset path,
+/// and written to such files with [`AnimationGraph::save`].
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct AnimationGraph {
+    /// The weight of each edge is its insertion order, which orders the children of a node.
+    graph: DiGraph<AnimationGraphNode, u32>,
+    root: AnimationNodeIndex,
+    next_edge: u32,
+    mask_groups: HashMap<EntityPath, AnimationMask>,
+}
+
+/// A node of an [`AnimationGraph`].
+#[derive(Clone, Debug)]
+pub struct AnimationGraphNode {
+    /// What this node does with its children.
+    pub node_type: AnimationNodeType,
+    /// The weight of the output of this node.
+    pub weight: f32,
+    /// The mask groups this node doesn't affect, see [`AnimationMask`].
+    ///
+    /// Masked targets are also excluded from all the descendants of this node.
+    pub mask: AnimationMask,
+}
+
+/// The kind of an [`AnimationGraphNode`].
+#[derive(Clone, Debug)]
+pub enum AnimationNodeType {
+    /// Plays an [`AnimationClip`]. Clip nodes are leaves: they ignore their children.
+    Clip(Handle<AnimationClip>),
+    /// Blends its children together, as a weighted average.
+    Blend,
+    /// Adds its children (except the first one) on top of its first child.
+    ///
+    /// Translations and morph weights of the added children are summed, rotations and scales
+    /// are multiplied, each scaled by the weight of the child.
+    Add,
+}
+
+impl AnimationGraphNode {
+    /// Returns the clip played by this node, if it is a [`Clip`](AnimationNodeType::Clip) node.
+    pub fn clip(&self) -> Option<&Handle<AnimationClip>> {
+        match &self.node_type {
+            AnimationNodeType::Clip(clip) => Some(clip),
+            _ => None,
+        }
+    }
+}
+
+impl Default for AnimationGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationGraph {
+    /// Creates a graph with a single [`Blend`](AnimationNodeType::Blend) root node.
+    pub fn new() -> Self {
+        let mut graph = DiGraph::default();
+        let root = graph.add_node(AnimationGraphNode {
+            node_type: AnimationNodeType::Blend,
+            weight: 1.0,
+            mask: 0,
+        });
+        Self {
+            graph,
+            root,
+            next_edge: 0,
+            mask_groups: HashMap::default(),
+        }
+    }
+
+    /// Creates a graph playing a single clip, and returns it with the index of the clip node.
+    pub fn from_clip(clip: Handle<AnimationClip>) -> (Self, AnimationNodeIndex) {
+        let mut graph = Self::new();
+        let node = graph.add_clip(clip, 1.0, graph.root);
+        (graph, node)
+    }
+
+    /// Creates a graph blending the given clips under its root, and returns it with the index
+    /// of each clip node.
+    pub fn from_clips(
+        clips: impl IntoIterator<Item = Handle<AnimationClip>>,
+    ) -> (Self, Vec<AnimationNodeIndex>) {
+        let mut graph = Self::new();
+        let nodes = clips
+            .into_iter()
+            .map(|clip| graph.add_clip(clip, 1.0, graph.root))
+            .collect();
+        (graph, nodes)
+    }
+
+    /// Adds a [`Clip`](AnimationNodeType::Clip) node as a child of `parent`.
+    pub fn add_clip(
+        &mut self,
+        clip: Handle<AnimationClip>,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeType::Clip(clip), weight, parent)
+    }
+
+    /// Adds a [`Blend`](AnimationNodeType::Blend) node as a child of `parent`.
+    pub fn add_blend(&mut self, weight: f32, parent: AnimationNodeIndex) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeType::Blend, weight, parent)
+    }
+
+    /// Adds an [`Add`](AnimationNodeType::Add) node as a child of `parent`.
+    pub fn add_additive_blend(
+        &mut self,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeType::Add, weight, parent)
+    }
+
+    fn add_node(
+        &mut self,
+        node_type: AnimationNodeType,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        let node = self.graph.add_node(AnimationGraphNode {
+            node_type,
+            weight,
+            mask: 0,
+        });
+        self.add_edge(parent, node);
+        node
+    }
+
+    /// Makes `child` a child of `parent`, in addition to its existing parents.
+    ///
+    /// The graph must stay acyclic: `parent` can't be a descendant of `child`.
+    /// `child` comes after the existing children of `parent` in [`AnimationGraph::children`].
+    pub fn add_edge(&mut self, parent: AnimationNodeIndex, child: AnimationNodeIndex) {
+        self.graph.add_edge(parent, child, self.next_edge);
+        self.next_edge += 1;
+    }
+
+    /// Removes `child` from the children of `parent`, returning whether it was one.
+    pub fn remove_edge(&mut self, parent: AnimationNodeIndex, child: AnimationNodeIndex) -> bool {
+        self.graph
+            .find_edge(parent, child)
+            .and_then(|edge| self.graph.remove_edge(edge))
+            .is_some()
+    }
+
+    /// The root node of the graph.
+    pub fn root(&self) -> AnimationNodeIndex {
+        self.root
+    }
+
+    /// Returns the node at the given index.
+    pub fn get(&self, node: AnimationNodeIndex) -> Option<&AnimationGraphNode> {
+        self.graph.node_weight(node)
+    }
+
+    /// Returns the node at the given index, mutably.
+    pub fn get_mut(&mut self, node: AnimationNodeIndex) -> Option<&mut AnimationGraphNode> {
+        self.graph.node_weight_mut(node)
+    }
+
+    /// Iterates over the indices of all the nodes of the graph.
+    pub fn nodes(&self) -> impl Iterator<Item = AnimationNodeIndex> {
+        self.graph.node_indices()
+    }
+
+    /// Iterates over the children of `node`, in the order in which they were added.
+    pub fn children(&self, node: AnimationNodeIndex) -> impl Iterator<Item = AnimationNodeIndex> {
+        let mut children: Vec<_> = self
+            .graph
+            .edges_directed(node, Direction::Outgoing)
+            .map(|edge| (*edge.weight(), edge.target()))
+            .collect();
+        children.sort_unstable_by_key(|(order, _)| *order);
+        children.into_iter().map(|(_, child)| child)
+    }
+
+    /// Adds the target at `path` to the mask group `group`, which must be below 64.
+    pub fn add_target_to_mask_group(&mut self, path: EntityPath, group: u32) {
+        *self.mask_groups.entry(path).or_default() |= 1 << group;
+    }
+
+    /// The mask groups of the target at `path`.
+    pub fn mask_groups(&self, path: &EntityPath) -> AnimationMask {
+        self.mask_groups.get(path).copied().unwrap_or_default()
+    }
+
+    /// Writes this graph in the `.animgraph.ron` format to `writer`.
+    ///
+    /// Every clip of the graph must have been loaded from an asset path.
+    pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), AnimationGraphSaveError> {
+        let serialized = SerializedAnimationGraph::try_from(self)?;
+        let ron = ron::ser::to_string_pretty(&serialized, ron::ser::PrettyConfig::default())?;
+        writer.write_all(ron.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// The serialized form of an [`AnimationGraph`], where clips are referenced by asset path.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SerializedAnimationGraph {
+    /// The nodes of the graph, by index.
+    pub nodes: Vec<SerializedAnimationGraphNode>,
+    /// The `(parent, child)` pairs of node indices, in the order in which the children were added.
+    pub edges: Vec<(u32, u32)>,
+    /// The index of the root node.
+    pub root: u32,
+    /// The mask groups of targets, keyed by the names in their [`EntityPath`].
+    #[serde(default)]
+    pub mask_groups: Vec<(Vec<String>, AnimationMask)>,
+}
+
+/// The serialized form of an [`AnimationGraphNode`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SerializedAnimationGraphNode {
+    /// What this node does with its children.
+    pub node_type: SerializedAnimationNodeType,
+    /// The weight of the output of this node.
+    pub weight: f32,
+    /// The mask groups this node doesn't affect.
+    #[serde(default)]
+    pub mask: AnimationMask,
+}
+
+/// The serialized form of an [`AnimationNodeType`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum SerializedAnimationNodeType {
+    /// Plays the clip at the given asset path.
+    Clip(AssetPath<'static>),
+    /// Blends its children together.
+    Blend,
+    /// Adds its children on top of its first child.
+    Add,
+}
+
+impl TryFrom<&AnimationGraph> for SerializedAnimationGraph {
+    type Error = AnimationGraphSaveError;
+
+    fn try_from(graph: &AnimationGraph) -> Result<Self, Self::Error> {
+        let nodes = graph
+            .graph
+            .node_indices()
+            .map(|index| {
+                let node = &graph.graph[index];
+                let node_type = match &node.node_type {
+                    AnimationNodeType::Clip(clip) => SerializedAnimationNodeType::Clip(
+                        clip.path()
+                            .cloned()
+                            .ok_or(AnimationGraphSaveError::ClipWithoutPath(index.index()))?,
+                    ),
+                    AnimationNodeType::Blend => SerializedAnimationNodeType::Blend,
+                    AnimationNodeType::Add => SerializedAnimationNodeType::Add,
+                };
+                Ok(SerializedAnimationGraphNode {
+                    node_type,
+                    weight: node.weight,
+                    mask: node.mask,
+                })
+            })
+            .collect::<Result<_, AnimationGraphSaveError>>()?;
+        let mut edges: Vec<_> = graph.graph.raw_edges().iter().collect();
+        edges.sort_unstable_by_key(|edge| edge.weight);
+        let edges = edges
+            .into_iter()
+            .map(|edge| (edge.source().index() as u32, edge.target().index() as u32))
+            .collect();
+        let mask_groups = graph
+            .mask_groups
+            .iter()
+            .map(|(path, mask)| {
+                let names = path.parts.iter().map(|name| name.to_string()).collect();
+                (names, *mask)
+            })
+            .collect();
+        Ok(Self {
+            nodes,
+            edges,
+            root: graph.root.index() as u32,
+            mask_groups,
+        })
+    }
+}
+
+impl SerializedAnimationGraph {
+    /// Converts this graph into an [`AnimationGraph`], using `load_clip` to get the handle
+    /// of each clip.
+    pub fn into_graph(
+        self,
+        mut load_clip: impl FnMut(AssetPath<'static>) -> Handle<AnimationClip>,
+    ) -> Result<AnimationGraph, AnimationGraphLoadError> {
+        let mut graph = DiGraph::with_capacity(self.nodes.len(), self.edges.len());
+        for node in self.nodes {
+            let node_type = match node.node_type {
+                SerializedAnimationNodeType::Clip(path) => AnimationNodeType::Clip(load_clip(path)),
+                SerializedAnimationNodeType::Blend => AnimationNodeType::Blend,
+                SerializedAnimationNodeType::Add => AnimationNodeType::Add,
+            };
+            graph.add_node(AnimationGraphNode {
+                node_type,
+                weight: node.weight,
+                mask: node.mask,
+            });
+        }
+        let node_count = graph.node_count() as u32;
+        let mut next_edge = 0;
+        for (parent, child) in self.edges {
+            if parent >= node_count || child >= node_count {
+                return Err(AnimationGraphLoadError::InvalidNode(parent.max(child)));
+            }
+            graph.add_edge(
+                NodeIndex::new(parent as usize),
+                NodeIndex::new(child as usize),
+                next_edge,
+            );
+            next_edge += 1;
+        }
+        if self.root >= node_count {
+            return Err(AnimationGraphLoadError::InvalidNode(self.root));
+        }
+        if is_cyclic_directed(&graph) {
+            return Err(AnimationGraphLoadError::Cyclic);
+        }
+        let mask_groups = self
+            .mask_groups
+            .into_iter()
+            .map(|(names, mask)| {
+                let parts = names.into_iter().map(Name::new).collect();
+                (EntityPath { parts }, mask)
+            })
+            .collect();
+        Ok(AnimationGraph {
+            graph,
+            root: NodeIndex::new(self.root as usize),
+            next_edge,
+            mask_groups,
+        })
+    }
+}
+
+/// An error that occurs when loading an [`AnimationGraph`].
+#[derive(Error, Debug)]
+pub enum AnimationGraphLoadError {
+    /// An I/O error occurred while reading the file.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file isn't a valid serialized [`AnimationGraph`].
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// An edge or the root references a node that doesn't exist.
+    #[error("node {0} doesn't exist")]
+    InvalidNode(u32),
+    /// The graph contains a cycle.
+    #[error("the animation graph contains a cycle")]
+    Cyclic,
+}
+
+/// An error that occurs when saving an [`AnimationGraph`].
+#[derive(Error, Debug)]
+pub enum AnimationGraphSaveError {
+    /// An I/O error occurred while writing the file.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The graph couldn't be serialized.
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::Error),
+    /// A clip node references a clip that wasn't loaded from an asset path.
+    #[error("the clip of node {0} has no asset path")]
+    ClipWithoutPath(usize),
+}
+
+/// Loads [`AnimationGraph`]s from `.animgraph.ron` files.
+#[derive(Default)]
+pub struct AnimationGraphAssetLoader;
+
+impl AssetLoader for AnimationGraphAssetLoader {
+    type Asset = AnimationGraph;
+    type Settings = ();
+    type Error = AnimationGraphLoadError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let serialized: SerializedAnimationGraph = ron::de::from_bytes(&bytes)?;
+            serialized.into_graph(|path| load_context.load(path))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["animgraph", "animgraph.ron"]
+    }
+}
@@ -1,29 +1,41 @@
 //! Animation for the game engine Bevy
 
 mod animatable;
+mod event;
+mod graph;
+mod property;
+mod root_motion;
 mod util;
 
+use std::any::TypeId;
 use std::ops::{Add, Deref, Mul};
+use std::sync::Arc;
 use std::time::Duration;
 
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_asset::{Asset, AssetApp, Assets, Handle};
 use bevy_core::Name;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemState};
 use bevy_hierarchy::{Children, Parent};
 use bevy_math::{FloatExt, Quat, Vec3};
-use bevy_reflect::Reflect;
+use bevy_reflect::{ParsedPath, Reflect};
 use bevy_render::mesh::morph::MorphWeights;
 use bevy_time::Time;
 use bevy_transform::{prelude::Transform, TransformSystem};
-use bevy_utils::{tracing::warn, HashMap};
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+
+pub use event::*;
+pub use graph::*;
+pub use property::*;
+pub use root_motion::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        animatable::*, AnimationClip, AnimationPlayer, AnimationPlugin, EntityPath, Interpolation,
-        Keyframes, VariableCurve,
+        animatable::*, ActiveAnimation, AnimationClip, AnimationGraph, AnimationNodeIndex,
+        AnimationPlayer, AnimationPlugin, EntityPath, Interpolation, Keyframes, PropertyCurve,
+        RootMotion, VariableCurve,
     };
 }
 
@@ -151,6 +163,10 @@ pub struct AnimationClip {
     curves: Vec<Vec<VariableCurve>>,
     paths: HashMap<EntityPath, usize>,
     duration: f32,
+    #[reflect(ignore)]
+    events: Vec<AnimationEvent>,
+    #[reflect(ignore)]
+    property_curves: HashMap<EntityPath, Vec<PropertyCurve>>,
 }
 
 impl AnimationClip {
@@ -197,9 +213,67 @@ impl AnimationClip {
         }
     }
 
+    /// Add a [`PropertyCurve`] animating a component field of the entity at an [`EntityPath`].
+    pub fn add_property_curve_to_path(&mut self, path: EntityPath, curve: PropertyCurve) {
+        self.duration = self
+            .duration
+            .max(*curve.keyframe_timestamps().last().unwrap_or(&0.0));
+        self.property_curves.entry(path).or_default().push(curve);
+    }
+
+    /// Gets the [`PropertyCurve`]s animating the entity at an [`EntityPath`].
+    ///
+    /// Returns `None` if no field of the entity is animated.
+    #[inline]
+    pub fn get_property_curves_by_path(&self, path: &EntityPath) -> Option<&'_ Vec<PropertyCurve>> {
+        self.property_curves.get(path)
+    }
+
+    /// Add an event triggered on the entity of the [`AnimationPlayer`] playing this clip
+    /// when playback reaches `time`.
+    ///
+    /// The event can be observed with an [`Observer`] watching the player entity, or a global one.
+    /// Events are reached when playback goes through their time, forward or in reverse, and
+    /// after looping. Times skipped with [`AnimationPlayer::seek_to`] are not gone through, and
+    /// clips fading out during a transition don't trigger their events.
+    pub fn add_event(&mut self, time: f32, event: impl Event + Clone) {
+        self.add_event_fn(time, move |commands, entity| {
+            commands.trigger_targets(event.clone(), entity);
+        });
+    }
+
+    /// Add a function called with the entity of the [`AnimationPlayer`] playing this clip
+    /// when playback reaches `time`.
+    ///
+    /// This can be used to send buffered events, or to act on other entities.
+    /// See [`AnimationClip::add_event`] for when the function is called.
+    pub fn add_event_fn(
+        &mut self,
+        time: f32,
+        function: impl Fn(&mut Commands, Entity) + Send + Sync + 'static,
+    ) {
+        self.duration = self.duration.max(time);
+        let index = self.events.partition_point(|event| event.time <= time);
+        self.events.insert(
+            index,
+            AnimationEvent {
+                time,
+                trigger: AnimationEventFn(Arc::new(function)),
+            },
+        );
+    }
+
+    /// The events of this clip, sorted by time.
+    pub fn events(&self) -> &[AnimationEvent] {
+        &self.events
+    }
+
     /// Whether this animation clip can run on entity with given [`Name`].
     pub fn compatible_with(&self, name: &Name) -> bool {
-        self.paths.keys().any(|path| &path.parts[0] == name)
+        self.paths
+            .keys()
+            .chain(self.property_curves.keys())
+            .any(|path| &path.parts[0] == name)
     }
 }
 
@@ -215,8 +289,14 @@ pub enum RepeatAnimation {
     Forever,
 }
 
-#[derive(Debug, Reflect)]
-struct PlayingAnimation {
+/// The playback state of an animation.
+///
+/// This is the state of the clip played by an [`AnimationPlayer`], and of each node of its
+/// [`AnimationGraph`] started with [`AnimationPlayer::start_node`].
+#[derive(Debug, Clone, Reflect)]
+pub struct ActiveAnimation {
+    /// The weight of the animation, multiplied with the weight of its node in the graph.
+    weight: f32,
     repeat: RepeatAnimation,
     speed: f32,
     /// Total time the animation has been played.
@@ -227,28 +307,25 @@ struct PlayingAnimation {
     ///
     /// Note: This will always be in the range [0.0, animation clip duration]
     seek_time: f32,
-    animation_clip: Handle<AnimationClip>,
-    path_cache: Vec<Vec<Option<Entity>>>,
     /// Number of times the animation has completed.
     /// If the animation is playing in reverse, this increments when the animation passes the start.
     completions: u32,
 }
 
-impl Default for PlayingAnimation {
+impl Default for ActiveAnimation {
     fn default() -> Self {
         Self {
+            weight: 1.0,
             repeat: RepeatAnimation::default(),
             speed: 1.0,
             elapsed: 0.0,
             seek_time: 0.0,
-            animation_clip: Default::default(),
-            path_cache: Vec::new(),
             completions: 0,
         }
     }
 }
 
-impl PlayingAnimation {
+impl ActiveAnimation {
     /// Check if the animation has finished, based on its repetition behavior and the number of times it has repeated.
     ///
     /// Note: An animation with `RepeatAnimation::Forever` will never finish.
@@ -262,40 +339,126 @@ impl PlayingAnimation {
     }
 
     /// Update the animation given the delta time and the duration of the clip being played.
+    ///
+    /// Returns the part of the clip that playback went through, if it moved.
     #[inline]
-    fn update(&mut self, delta: f32, clip_duration: f32) {
+    fn update(&mut self, delta: f32, clip_duration: f32) -> Option<PlaybackStep> {
         if self.is_finished() {
-            return;
+            return None;
         }
 
+        let from = self.seek_time;
         self.elapsed += delta;
         self.seek_time += delta * self.speed;
 
         let over_time = self.speed > 0.0 && self.seek_time >= clip_duration;
         let under_time = self.speed < 0.0 && self.seek_time < 0.0;
+        let mut step = PlaybackStep {
+            from,
+            to: self.seek_time,
+            wrapped: false,
+            reversed: self.speed < 0.0,
+        };
 
         if over_time || under_time {
             self.completions += 1;
 
             if self.is_finished() {
-                return;
+                return Some(step);
             }
         }
         if self.seek_time >= clip_duration {
             self.seek_time %= clip_duration;
+            step.wrapped = true;
         }
         // Note: assumes delta is never lower than -clip_duration
         if self.seek_time < 0.0 {
             self.seek_time += clip_duration;
+            step.wrapped = true;
         }
+        step.to = self.seek_time;
+
+        (step.wrapped || step.from != step.to).then_some(step)
     }
 
     /// Reset back to the initial state as if no time has elapsed.
-    fn replay(&mut self) {
+    pub fn replay(&mut self) {
         self.completions = 0;
         self.elapsed = 0.0;
         self.seek_time = 0.0;
     }
+
+    /// The weight of the animation.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Set the weight of the animation, used when it's played by an [`AnimationGraph`].
+    pub fn set_weight(&mut self, weight: f32) -> &mut Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets repeat to [`RepeatAnimation::Forever`].
+    pub fn repeat(&mut self) -> &mut Self {
+        self.repeat = RepeatAnimation::Forever;
+        self
+    }
+
+    /// Set the repetition behaviour of the animation.
+    pub fn set_repeat(&mut self, repeat: RepeatAnimation) -> &mut Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Repetition behavior of the animation.
+    pub fn repeat_mode(&self) -> RepeatAnimation {
+        self.repeat
+    }
+
+    /// Number of times the animation has completed.
+    pub fn completions(&self) -> u32 {
+        self.completions
+    }
+
+    /// Check if the animation is playing in reverse.
+    pub fn is_playback_reversed(&self) -> bool {
+        self.speed < 0.0
+    }
+
+    /// Speed of the animation playback
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Set the speed of the animation playback
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Time elapsed playing the animation
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Seek time inside of the animation. Always within the range [0.0, clip duration].
+    pub fn seek_time(&self) -> f32 {
+        self.seek_time
+    }
+
+    /// Seek to a specific time in the animation.
+    pub fn seek_to(&mut self, seek_time: f32) -> &mut Self {
+        self.seek_time = seek_time;
+        self
+    }
+}
+
+#[derive(Debug, Default, Reflect)]
+struct PlayingAnimation {
+    state: ActiveAnimation,
+    animation_clip: Handle<AnimationClip>,
+    path_cache: Vec<Vec<Option<Entity>>>,
 }
 
 /// An animation that is being faded out as part of a transition
@@ -323,6 +486,12 @@ pub struct AnimationPlayer {
     // Once a transition is finished, it will be automatically removed from the list
     #[reflect(ignore)]
     transitions: Vec<AnimationTransition>,
+
+    // The nodes of the entity's `AnimationGraph` that are playing, when it has one.
+    #[reflect(ignore)]
+    active_nodes: HashMap<AnimationNodeIndex, ActiveAnimation>,
+    #[reflect(ignore)]
+    graph_path_cache: HashMap<EntityPath, Vec<Option<Entity>>>,
 }
 
 impl AnimationPlayer {
@@ -399,36 +568,36 @@ impl AnimationPlayer {
 
     /// Check if the playing animation has finished, according to the repetition behavior.
     pub fn is_finished(&self) -> bool {
-        self.animation.is_finished()
+        self.animation.state.is_finished()
     }
 
     /// Sets repeat to [`RepeatAnimation::Forever`].
     ///
     /// See also [`Self::set_repeat`].
     pub fn repeat(&mut self) -> &mut Self {
-        self.animation.repeat = RepeatAnimation::Forever;
+        self.animation.state.repeat();
         self
     }
 
     /// Set the repetition behaviour of the animation.
     pub fn set_repeat(&mut self, repeat: RepeatAnimation) -> &mut Self {
-        self.animation.repeat = repeat;
+        self.animation.state.set_repeat(repeat);
         self
     }
 
     /// Repetition behavior of the animation.
     pub fn repeat_mode(&self) -> RepeatAnimation {
-        self.animation.repeat
+        self.animation.state.repeat_mode()
     }
 
     /// Number of times the animation has completed.
     pub fn completions(&self) -> u32 {
-        self.animation.completions
+        self.animation.state.completions()
     }
 
     /// Check if the animation is playing in reverse.
     pub fn is_playback_reversed(&self) -> bool {
-        self.animation.speed < 0.0
+        self.animation.state.is_playback_reversed()
     }
 
     /// Pause the animation
@@ -448,34 +617,84 @@ impl AnimationPlayer {
 
     /// Speed of the animation playback
     pub fn speed(&self) -> f32 {
-        self.animation.speed
+        self.animation.state.speed()
     }
 
     /// Set the speed of the animation playback
     pub fn set_speed(&mut self, speed: f32) -> &mut Self {
-        self.animation.speed = speed;
+        self.animation.state.set_speed(speed);
         self
     }
 
     /// Time elapsed playing the animation
     pub fn elapsed(&self) -> f32 {
-        self.animation.elapsed
+        self.animation.state.elapsed()
     }
 
     /// Seek time inside of the animation. Always within the range [0.0, clip duration].
     pub fn seek_time(&self) -> f32 {
-        self.animation.seek_time
+        self.animation.state.seek_time()
     }
 
     /// Seek to a specific time in the animation.
     pub fn seek_to(&mut self, seek_time: f32) -> &mut Self {
-        self.animation.seek_time = seek_time;
+        self.animation.state.seek_to(seek_time);
         self
     }
 
     /// Reset the animation to its initial state, as if no time has elapsed.
     pub fn replay(&mut self) {
-        self.animation.replay();
+        self.animation.state.replay();
+    }
+
+    /// Start playing a node of the entity's [`AnimationGraph`], restarting it if it was already playing.
+    ///
+    /// Nodes are only played when the entity has a [`Handle<AnimationGraph>`], in which case
+    /// the clip set with [`AnimationPlayer::start`] is ignored. Any number of nodes can be
+    /// played at the same time, and are blended as described by the graph.
+    pub fn start_node(&mut self, node: AnimationNodeIndex) -> &mut ActiveAnimation {
+        let animation = self.active_nodes.entry(node).or_default();
+        *animation = ActiveAnimation::default();
+        animation
+    }
+
+    /// Start playing a node of the entity's [`AnimationGraph`], unless it's already playing.
+    pub fn play_node(&mut self, node: AnimationNodeIndex) -> &mut ActiveAnimation {
+        self.active_nodes.entry(node).or_default()
+    }
+
+    /// Stop playing a node of the entity's [`AnimationGraph`].
+    pub fn stop_node(&mut self, node: AnimationNodeIndex) -> &mut Self {
+        self.active_nodes.remove(&node);
+        self
+    }
+
+    /// Stop playing all the nodes of the entity's [`AnimationGraph`].
+    pub fn stop_all_nodes(&mut self) -> &mut Self {
+        self.active_nodes.clear();
+        self
+    }
+
+    /// Check if the given node of the entity's [`AnimationGraph`] is playing.
+    pub fn is_playing_node(&self, node: AnimationNodeIndex) -> bool {
+        self.active_nodes.contains_key(&node)
+    }
+
+    /// The playback state of a node of the entity's [`AnimationGraph`], if it's playing.
+    pub fn node_animation(&self, node: AnimationNodeIndex) -> Option<&ActiveAnimation> {
+        self.active_nodes.get(&node)
+    }
+
+    /// The playback state of a node of the entity's [`AnimationGraph`], mutably, if it's playing.
+    pub fn node_animation_mut(&mut self, node: AnimationNodeIndex) -> Option<&mut ActiveAnimation> {
+        self.active_nodes.get_mut(&node)
+    }
+
+    /// Iterates over the playing nodes of the entity's [`AnimationGraph`] and their playback state.
+    pub fn playing_nodes(&self) -> impl Iterator<Item = (AnimationNodeIndex, &ActiveAnimation)> {
+        self.active_nodes
+            .iter()
+            .map(|(node, animation)| (*node, animation))
     }
 }
 
@@ -559,34 +778,49 @@ fn verify_no_ancestor_player(
 
 /// System that will play all animations, using any entity with a [`AnimationPlayer`]
 /// and a [`Handle<AnimationClip>`] as an animation root
+///
+/// Players whose entity has a [`Handle<AnimationGraph>`] play the nodes of that graph instead.
 #[allow(clippy::too_many_arguments)]
 pub fn animation_player(
     time: Res<Time>,
     animations: Res<Assets<AnimationClip>>,
+    graphs: Res<Assets<AnimationGraph>>,
     children: Query<&Children>,
     names: Query<&Name>,
     transforms: Query<&mut Transform>,
     morphs: Query<&mut MorphWeights>,
     parents: Query<(Has<AnimationPlayer>, Option<&Parent>)>,
-    mut animation_players: Query<(Entity, Option<&Parent>, &mut AnimationPlayer)>,
+    par_commands: ParallelCommands,
+    mut animation_players: Query<(
+        Entity,
+        Option<&Parent>,
+        &mut AnimationPlayer,
+        Option<&Handle<AnimationGraph>>,
+        Option<&mut RootMotion>,
+    )>,
 ) {
-    animation_players
-        .par_iter_mut()
-        .for_each(|(root, maybe_parent, mut player)| {
+    animation_players.par_iter_mut().for_each(
+        |(root, maybe_parent, mut player, graph, root_motion)| {
             update_transitions(&mut player, &time);
-            run_animation_player(
-                root,
-                player,
-                &time,
-                &animations,
-                &names,
-                &transforms,
-                &morphs,
-                maybe_parent,
-                &parents,
-                &children,
-            );
-        });
+            par_commands.command_scope(|mut commands| {
+                run_animation_player(
+                    root,
+                    player,
+                    &time,
+                    &animations,
+                    graph.map(|graph| graphs.get(graph)),
+                    &names,
+                    &transforms,
+                    &morphs,
+                    maybe_parent,
+                    &parents,
+                    &children,
+                    root_motion,
+                    &mut commands,
+                );
+            });
+        },
+    );
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -595,13 +829,19 @@ fn run_animation_player(
     mut player: Mut<AnimationPlayer>,
     time: &Time,
     animations: &Assets<AnimationClip>,
+    graph: Option<Option<&AnimationGraph>>,
     names: &Query<&Name>,
     transforms: &Query<&mut Transform>,
     morphs: &Query<&mut MorphWeights>,
     maybe_parent: Option<&Parent>,
     parents: &Query<(Has<AnimationPlayer>, Option<&Parent>)>,
     children: &Query<&Children>,
+    mut root_motion: Option<Mut<RootMotion>>,
+    commands: &mut Commands,
 ) {
+    if let Some(root_motion) = &mut root_motion {
+        root_motion.set_delta((Vec3::ZERO, Quat::IDENTITY));
+    }
     let paused = player.paused;
     // Continue if paused unless the `AnimationPlayer` was changed
     // This allow the animation to still be updated if the player.elapsed field was manually updated in pause
@@ -609,8 +849,44 @@ fn run_animation_player(
         return;
     }
 
+    match graph {
+        Some(Some(graph)) => {
+            if !verify_no_ancestor_player(maybe_parent, parents) {
+                warn!("Animation player on {:?} has a conflicting animation player on an ancestor. Cannot safely animate.", root);
+                return;
+            }
+            let steps = apply_animation_graph(
+                root,
+                &mut player,
+                graph,
+                time,
+                animations,
+                names,
+                transforms,
+                morphs,
+                children,
+                commands,
+            );
+            if let Some(mut root_motion) = root_motion {
+                let motion = graph_root_motion(
+                    graph,
+                    &player.active_nodes,
+                    animations,
+                    &steps,
+                    &root_motion.root_bone,
+                );
+                root_motion.set_delta(motion);
+                root_motion.remove_from_bone(root, children, names, transforms);
+            }
+            return;
+        }
+        // The graph is still loading.
+        Some(None) => return,
+        None => {}
+    }
+
     // Apply the main animation
-    apply_animation(
+    let step = apply_animation(
         1.0,
         &mut player.animation,
         paused,
@@ -623,7 +899,15 @@ fn run_animation_player(
         maybe_parent,
         parents,
         children,
+        Some(commands),
     );
+    let mut motion = (Vec3::ZERO, Quat::IDENTITY);
+    if let (Some(root_motion), Some(clip)) = (
+        &root_motion,
+        animations.get(&player.animation.animation_clip),
+    ) {
+        motion = clip_root_motion(clip, &root_motion.root_bone, step);
+    }
 
     // Apply any potential fade-out transitions from previous animations
     for AnimationTransition {
@@ -632,7 +916,7 @@ fn run_animation_player(
         ..
     } in &mut player.transitions
     {
-        apply_animation(
+        let step = apply_animation(
             *current_weight,
             animation,
             paused,
@@ -645,7 +929,21 @@ fn run_animation_player(
             maybe_parent,
             parents,
             children,
+            None,
         );
+        if let (Some(root_motion), Some(clip)) =
+            (&root_motion, animations.get(&animation.animation_clip))
+        {
+            let transition_motion = clip_root_motion(clip, &root_motion.root_bone, step);
+            motion = lerp_root_motion(motion, transition_motion, *current_weight);
+        }
+    }
+
+    if let Some(mut root_motion) = root_motion {
+        if verify_no_ancestor_player(maybe_parent, parents) {
+            root_motion.set_delta(motion);
+            root_motion.remove_from_bone(root, children, names, transforms);
+        }
     }
 }
 
@@ -705,16 +1003,18 @@ fn apply_animation(
     maybe_parent: Option<&Parent>,
     parents: &Query<(Has<AnimationPlayer>, Option<&Parent>)>,
     children: &Query<&Children>,
-) {
-    let Some(animation_clip) = animations.get(&animation.animation_clip) else {
-        return;
-    };
+    commands: Option<&mut Commands>,
+) -> Option<PlaybackStep> {
+    let animation_clip = animations.get(&animation.animation_clip)?;
 
     // We don't return early because seek_to() may have been called on the animation player.
-    animation.update(
+    let step = animation.state.update(
         if paused { 0.0 } else { time.delta_seconds() },
         animation_clip.duration,
     );
+    if let (Some(step), Some(commands)) = (step, commands) {
+        animation_clip.trigger_events(step, root, commands);
+    }
 
     if animation.path_cache.len() != animation_clip.paths.len() {
         let new_len = animation_clip.paths.len();
@@ -723,7 +1023,7 @@ fn apply_animation(
     }
     if !verify_no_ancestor_player(maybe_parent, parents) {
         warn!("Animation player on {:?} has a conflicting animation player on an ancestor. Cannot safely animate.", root);
-        return;
+        return None;
     }
 
     let mut any_path_found = false;
@@ -751,73 +1051,353 @@ fn apply_animation(
         };
         // SAFETY: As above, there can't be other AnimationPlayers with this target so this fetch can't alias
         let mut morphs = unsafe { morphs.get_unchecked(target) }.ok();
+        let target_count = morphs.as_ref().map_or(0, |morphs| morphs.weights().len());
         for curve in curves {
-            // Some curves have only one keyframe used to set a transform
-            if curve.keyframe_timestamps.len() == 1 {
-                match &curve.keyframes {
-                    Keyframes::Rotation(keyframes) => {
-                        transform.rotation = transform.rotation.slerp(keyframes[0], weight);
-                    }
-                    Keyframes::Translation(keyframes) => {
-                        transform.translation = transform.translation.lerp(keyframes[0], weight);
+            if let Some(value) = sample_curve(curve, animation.state.seek_time, target_count) {
+                value.apply(weight, &mut transform, &mut morphs);
+            }
+        }
+    }
+
+    if !any_path_found {
+        warn!("Animation player on {root:?} did not match any entity paths.");
+    }
+    step
+}
+
+/// Advances the playing nodes of `graph`, and applies the result of the graph to every target.
+///
+/// Returns the part of their clip that the nodes went through.
+#[allow(clippy::too_many_arguments)]
+fn apply_animation_graph(
+    root: Entity,
+    player: &mut AnimationPlayer,
+    graph: &AnimationGraph,
+    time: &Time,
+    animations: &Assets<AnimationClip>,
+    names: &Query<&Name>,
+    transforms: &Query<&mut Transform>,
+    morphs: &Query<&mut MorphWeights>,
+    children: &Query<&Children>,
+    commands: &mut Commands,
+) -> HashMap<AnimationNodeIndex, PlaybackStep> {
+    let AnimationPlayer {
+        paused,
+        active_nodes,
+        graph_path_cache,
+        ..
+    } = player;
+
+    // We don't return early because seek_to() may have been called on the playing nodes.
+    let delta = if *paused { 0.0 } else { time.delta_seconds() };
+    // Different clips can animate the same target.
+    let mut paths = HashSet::new();
+    let mut steps = HashMap::new();
+    for (node, animation) in active_nodes.iter_mut() {
+        let Some(clip) = graph
+            .get(*node)
+            .and_then(AnimationGraphNode::clip)
+            .and_then(|clip| animations.get(clip))
+        else {
+            continue;
+        };
+        if let Some(step) = animation.update(delta, clip.duration) {
+            clip.trigger_events(step, root, commands);
+            steps.insert(*node, step);
+        }
+        paths.extend(clip.paths.keys());
+    }
+
+    for path in paths {
+        if !graph_path_cache.contains_key(path) {
+            graph_path_cache.insert(path.clone(), Vec::new());
+        }
+        let cached_path = graph_path_cache.get_mut(path).unwrap();
+        let Some(target) = entity_from_path(root, path, children, names, cached_path) else {
+            continue;
+        };
+        // SAFETY: The caller checked that no ancestor of `root` has an `AnimationPlayer`, see
+        // `apply_animation` for why this prevents aliasing.
+        let Ok(mut transform) = (unsafe { transforms.get_unchecked(target) }) else {
+            continue;
+        };
+        // SAFETY: As above, there can't be other AnimationPlayers with this target so this fetch can't alias
+        let mut morphs = unsafe { morphs.get_unchecked(target) }.ok();
+        let target_count = morphs.as_ref().map_or(0, |morphs| morphs.weights().len());
+        let evaluator = GraphEvaluator {
+            graph,
+            active_nodes,
+            animations,
+            mask_groups: graph.mask_groups(path),
+            sample: &|_, clip: &AnimationClip, animation: &ActiveAnimation| {
+                let mut pose = TargetPose::default();
+                for curve in clip.get_curves_by_path(path)? {
+                    if let Some(value) = sample_curve(curve, animation.seek_time, target_count) {
+                        pose.set(value, animation.weight);
                     }
-                    Keyframes::Scale(keyframes) => {
-                        transform.scale = transform.scale.lerp(keyframes[0], weight);
+                }
+                Some(pose)
+            },
+        };
+        if let Some(pose) = evaluator.evaluate(graph.root()) {
+            pose.apply(&mut transform, &mut morphs);
+        }
+    }
+    steps
+}
+
+/// The output of an [`AnimationGraph`] node for a single target.
+trait Pose: Default {
+    /// Multiplies the weight of every animated property by `factor`.
+    fn scale_weights(&mut self, factor: f32);
+
+    /// Blends `other` into `self`, as a weighted average of both.
+    fn blend(&mut self, other: Self);
+
+    /// Adds `other` on top of `self`. Properties that `self` doesn't animate are left untouched.
+    fn add(&mut self, other: Self);
+}
+
+/// Evaluates the nodes of an [`AnimationGraph`] for a single target.
+struct GraphEvaluator<'a, P> {
+    graph: &'a AnimationGraph,
+    active_nodes: &'a HashMap<AnimationNodeIndex, ActiveAnimation>,
+    animations: &'a Assets<AnimationClip>,
+    mask_groups: AnimationMask,
+    /// Samples the target in the clip of a playing node, returning `None` if the clip doesn't
+    /// animate it.
+    sample: &'a dyn Fn(AnimationNodeIndex, &AnimationClip, &ActiveAnimation) -> Option<P>,
+}
+
+impl<P: Pose> GraphEvaluator<'_, P> {
+    /// Returns the output of `node` for the target, or `None` if the node doesn't affect it.
+    fn evaluate(&self, node: AnimationNodeIndex) -> Option<P> {
+        let graph_node = self.graph.get(node)?;
+        if graph_node.mask & self.mask_groups != 0 {
+            return None;
+        }
+        let mut pose = match &graph_node.node_type {
+            AnimationNodeType::Clip(clip) => {
+                let animation = self.active_nodes.get(&node)?;
+                (self.sample)(node, self.animations.get(clip)?, animation)?
+            }
+            AnimationNodeType::Blend => {
+                let mut pose = P::default();
+                for child in self.graph.children(node) {
+                    if let Some(child_pose) = self.evaluate(child) {
+                        pose.blend(child_pose);
                     }
-                    Keyframes::Weights(keyframes) => {
-                        if let Some(morphs) = &mut morphs {
-                            let target_count = morphs.weights().len();
-                            lerp_morph_weights(
-                                morphs.weights_mut(),
-                                get_keyframe(target_count, keyframes, 0).iter().copied(),
-                                weight,
-                            );
-                        }
+                }
+                pose
+            }
+            AnimationNodeType::Add => {
+                let mut children = self.graph.children(node);
+                let mut pose = self.evaluate(children.next()?)?;
+                for child in children {
+                    if let Some(child_pose) = self.evaluate(child) {
+                        pose.add(child_pose);
                     }
                 }
-                continue;
+                pose
             }
+        };
+        pose.scale_weights(graph_node.weight);
+        Some(pose)
+    }
+}
 
-            // Find the current keyframe
-            let Some(step_start) = curve.find_current_keyframe(animation.seek_time) else {
-                continue;
-            };
+/// The animated properties of a target, each with the weight it should be applied with.
+#[derive(Default)]
+struct TargetPose {
+    translation: Option<(Vec3, f32)>,
+    rotation: Option<(Quat, f32)>,
+    scale: Option<(Vec3, f32)>,
+    weights: Option<(Vec<f32>, f32)>,
+}
 
-            let timestamp_start = curve.keyframe_timestamps[step_start];
-            let timestamp_end = curve.keyframe_timestamps[step_start + 1];
-            // Compute how far we are through the keyframe, normalized to [0, 1]
-            let lerp = f32::inverse_lerp(timestamp_start, timestamp_end, animation.seek_time);
+impl TargetPose {
+    fn set(&mut self, value: CurveValue, weight: f32) {
+        match value {
+            CurveValue::Translation(translation) => self.translation = Some((translation, weight)),
+            CurveValue::Rotation(rotation) => self.rotation = Some((rotation, weight)),
+            CurveValue::Scale(scale) => self.scale = Some((scale, weight)),
+            CurveValue::Weights(weights) => self.weights = Some((weights, weight)),
+        }
+    }
 
-            apply_keyframe(
-                curve,
-                step_start,
-                weight,
-                lerp,
-                timestamp_end - timestamp_start,
-                &mut transform,
-                &mut morphs,
-            );
+    fn apply(self, transform: &mut Mut<Transform>, morphs: &mut Option<Mut<MorphWeights>>) {
+        if let Some((translation, weight)) = self.translation {
+            CurveValue::Translation(translation).apply(weight.min(1.0), transform, morphs);
+        }
+        if let Some((rotation, weight)) = self.rotation {
+            CurveValue::Rotation(rotation).apply(weight.min(1.0), transform, morphs);
+        }
+        if let Some((scale, weight)) = self.scale {
+            CurveValue::Scale(scale).apply(weight.min(1.0), transform, morphs);
+        }
+        if let Some((weights, weight)) = self.weights {
+            CurveValue::Weights(weights).apply(weight.min(1.0), transform, morphs);
         }
     }
+}
 
-    if !any_path_found {
-        warn!("Animation player on {root:?} did not match any entity paths.");
+impl Pose for TargetPose {
+    fn scale_weights(&mut self, factor: f32) {
+        for weight in [
+            self.translation.as_mut().map(|(_, weight)| weight),
+            self.rotation.as_mut().map(|(_, weight)| weight),
+            self.scale.as_mut().map(|(_, weight)| weight),
+            self.weights.as_mut().map(|(_, weight)| weight),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *weight *= factor;
+        }
+    }
+
+    fn blend(&mut self, other: TargetPose) {
+        blend_property(&mut self.translation, other.translation, Vec3::lerp);
+        blend_property(&mut self.rotation, other.rotation, Quat::slerp);
+        blend_property(&mut self.scale, other.scale, Vec3::lerp);
+        blend_property(&mut self.weights, other.weights, |a, b, t| {
+            a.iter().zip(&b).map(|(a, b)| a.lerp(*b, t)).collect()
+        });
+    }
+
+    fn add(&mut self, other: TargetPose) {
+        add_property(
+            &mut self.translation,
+            other.translation,
+            |base, offset, weight| base + offset * weight,
+        );
+        add_property(
+            &mut self.rotation,
+            other.rotation,
+            |base, offset, weight| Quat::IDENTITY.slerp(offset, weight) * base,
+        );
+        add_property(&mut self.scale, other.scale, |base, offset, weight| {
+            base * Vec3::ONE.lerp(offset, weight)
+        });
+        add_property(&mut self.weights, other.weights, |base, offset, weight| {
+            base.iter()
+                .zip(&offset)
+                .map(|(a, b)| a + b * weight)
+                .collect()
+        });
     }
 }
 
+fn blend_property<T>(
+    property: &mut Option<(T, f32)>,
+    other: Option<(T, f32)>,
+    lerp: impl FnOnce(T, T, f32) -> T,
+) {
+    *property = match (property.take(), other) {
+        (Some((a, weight_a)), Some((b, weight_b))) => {
+            let total = weight_a + weight_b;
+            if total > 0.0 {
+                Some((lerp(a, b, weight_b / total), total))
+            } else {
+                Some((a, total))
+            }
+        }
+        (a, b) => a.or(b),
+    };
+}
+
+fn add_property<T>(
+    property: &mut Option<(T, f32)>,
+    other: Option<(T, f32)>,
+    add: impl FnOnce(T, T, f32) -> T,
+) {
+    *property = match (property.take(), other) {
+        (Some((base, weight)), Some((offset, offset_weight))) => {
+            Some((add(base, offset, offset_weight), weight))
+        }
+        (base, _) => base,
+    };
+}
+
+/// The value of a [`VariableCurve`] at a given time.
+enum CurveValue {
+    Rotation(Quat),
+    Translation(Vec3),
+    Scale(Vec3),
+    Weights(Vec<f32>),
+}
+
+impl CurveValue {
+    /// Blends this value into the matching property of `transform` or `morphs`.
+    fn apply(
+        self,
+        weight: f32,
+        transform: &mut Mut<Transform>,
+        morphs: &mut Option<Mut<MorphWeights>>,
+    ) {
+        match self {
+            CurveValue::Rotation(rotation) => {
+                transform.rotation = transform.rotation.slerp(rotation, weight);
+            }
+            CurveValue::Translation(translation) => {
+                transform.translation = transform.translation.lerp(translation, weight);
+            }
+            CurveValue::Scale(scale) => {
+                transform.scale = transform.scale.lerp(scale, weight);
+            }
+            CurveValue::Weights(weights) => {
+                if let Some(morphs) = morphs {
+                    lerp_morph_weights(morphs.weights_mut(), weights.into_iter(), weight);
+                }
+            }
+        }
+    }
+}
+
+/// Samples `curve` at `seek_time`, returning `None` if the curve isn't running at that time.
+///
+/// `target_count` is the number of morph targets of the animated entity.
+fn sample_curve(curve: &VariableCurve, seek_time: f32, target_count: usize) -> Option<CurveValue> {
+    // Some curves have only one keyframe used to set a transform
+    if curve.keyframe_timestamps.len() == 1 {
+        return Some(match &curve.keyframes {
+            Keyframes::Rotation(keyframes) => CurveValue::Rotation(keyframes[0]),
+            Keyframes::Translation(keyframes) => CurveValue::Translation(keyframes[0]),
+            Keyframes::Scale(keyframes) => CurveValue::Scale(keyframes[0]),
+            Keyframes::Weights(keyframes) => {
+                CurveValue::Weights(get_keyframe(target_count, keyframes, 0).to_vec())
+            }
+        });
+    }
+
+    // Find the current keyframe
+    let step_start = curve.find_current_keyframe(seek_time)?;
+
+    let timestamp_start = curve.keyframe_timestamps[step_start];
+    let timestamp_end = curve.keyframe_timestamps[step_start + 1];
+    // Compute how far we are through the keyframe, normalized to [0, 1]
+    let lerp = f32::inverse_lerp(timestamp_start, timestamp_end, seek_time);
+
+    Some(sample_keyframe(
+        curve,
+        step_start,
+        lerp,
+        timestamp_end - timestamp_start,
+        target_count,
+    ))
+}
+
 #[inline(always)]
-fn apply_keyframe(
+fn sample_keyframe(
     curve: &VariableCurve,
     step_start: usize,
-    weight: f32,
     lerp: f32,
     duration: f32,
-    transform: &mut Mut<Transform>,
-    morphs: &mut Option<Mut<MorphWeights>>,
-) {
+    target_count: usize,
+) -> CurveValue {
     match (&curve.interpolation, &curve.keyframes) {
         (Interpolation::Step, Keyframes::Rotation(keyframes)) => {
-            transform.rotation = transform.rotation.slerp(keyframes[step_start], weight);
+            CurveValue::Rotation(keyframes[step_start])
         }
         (Interpolation::Linear, Keyframes::Rotation(keyframes)) => {
             let rot_start = keyframes[step_start];
@@ -827,8 +1407,7 @@ fn apply_keyframe(
                 rot_end = -rot_end;
             }
             // Rotations are using a spherical linear interpolation
-            let rot = rot_start.normalize().slerp(rot_end.normalize(), lerp);
-            transform.rotation = transform.rotation.slerp(rot, weight);
+            CurveValue::Rotation(rot_start.normalize().slerp(rot_end.normalize(), lerp))
         }
         (Interpolation::CubicSpline, Keyframes::Rotation(keyframes)) => {
             let value_start = keyframes[step_start * 3 + 1];
@@ -843,83 +1422,73 @@ fn apply_keyframe(
                 lerp,
                 duration,
             );
-            transform.rotation = transform.rotation.slerp(result.normalize(), weight);
+            CurveValue::Rotation(result.normalize())
         }
         (Interpolation::Step, Keyframes::Translation(keyframes)) => {
-            transform.translation = transform.translation.lerp(keyframes[step_start], weight);
+            CurveValue::Translation(keyframes[step_start])
         }
         (Interpolation::Linear, Keyframes::Translation(keyframes)) => {
             let translation_start = keyframes[step_start];
             let translation_end = keyframes[step_start + 1];
-            let result = translation_start.lerp(translation_end, lerp);
-            transform.translation = transform.translation.lerp(result, weight);
+            CurveValue::Translation(translation_start.lerp(translation_end, lerp))
         }
         (Interpolation::CubicSpline, Keyframes::Translation(keyframes)) => {
             let value_start = keyframes[step_start * 3 + 1];
             let tangent_out_start = keyframes[step_start * 3 + 2];
             let tangent_in_end = keyframes[(step_start + 1) * 3];
             let value_end = keyframes[(step_start + 1) * 3 + 1];
-            let result = cubic_spline_interpolation(
+            CurveValue::Translation(cubic_spline_interpolation(
                 value_start,
                 tangent_out_start,
                 tangent_in_end,
                 value_end,
                 lerp,
                 duration,
-            );
-            transform.translation = transform.translation.lerp(result, weight);
+            ))
         }
         (Interpolation::Step, Keyframes::Scale(keyframes)) => {
-            transform.scale = transform.scale.lerp(keyframes[step_start], weight);
+            CurveValue::Scale(keyframes[step_start])
         }
         (Interpolation::Linear, Keyframes::Scale(keyframes)) => {
             let scale_start = keyframes[step_start];
             let scale_end = keyframes[step_start + 1];
-            let result = scale_start.lerp(scale_end, lerp);
-            transform.scale = transform.scale.lerp(result, weight);
+            CurveValue::Scale(scale_start.lerp(scale_end, lerp))
         }
         (Interpolation::CubicSpline, Keyframes::Scale(keyframes)) => {
             let value_start = keyframes[step_start * 3 + 1];
             let tangent_out_start = keyframes[step_start * 3 + 2];
             let tangent_in_end = keyframes[(step_start + 1) * 3];
             let value_end = keyframes[(step_start + 1) * 3 + 1];
-            let result = cubic_spline_interpolation(
+            CurveValue::Scale(cubic_spline_interpolation(
                 value_start,
                 tangent_out_start,
                 tangent_in_end,
                 value_end,
                 lerp,
                 duration,
-            );
-            transform.scale = transform.scale.lerp(result, weight);
+            ))
         }
         (Interpolation::Step, Keyframes::Weights(keyframes)) => {
-            if let Some(morphs) = morphs {
-                let target_count = morphs.weights().len();
-                let morph_start = get_keyframe(target_count, keyframes, step_start);
-                lerp_morph_weights(morphs.weights_mut(), morph_start.iter().copied(), weight);
-            }
+            CurveValue::Weights(get_keyframe(target_count, keyframes, step_start).to_vec())
         }
         (Interpolation::Linear, Keyframes::Weights(keyframes)) => {
-            if let Some(morphs) = morphs {
-                let target_count = morphs.weights().len();
-                let morph_start = get_keyframe(target_count, keyframes, step_start);
-                let morph_end = get_keyframe(target_count, keyframes, step_start + 1);
-                let result = morph_start
+            let morph_start = get_keyframe(target_count, keyframes, step_start);
+            let morph_end = get_keyframe(target_count, keyframes, step_start + 1);
+            CurveValue::Weights(
+                morph_start
                     .iter()
                     .zip(morph_end)
-                    .map(|(a, b)| a.lerp(*b, lerp));
-                lerp_morph_weights(morphs.weights_mut(), result, weight);
-            }
+                    .map(|(a, b)| a.lerp(*b, lerp))
+                    .collect(),
+            )
         }
         (Interpolation::CubicSpline, Keyframes::Weights(keyframes)) => {
-            if let Some(morphs) = morphs {
-                let target_count = morphs.weights().len();
-                let morph_start = get_keyframe(target_count, keyframes, step_start * 3 + 1);
-                let tangents_out_start = get_keyframe(target_count, keyframes, step_start * 3 + 2);
-                let tangents_in_end = get_keyframe(target_count, keyframes, (step_start + 1) * 3);
-                let morph_end = get_keyframe(target_count, keyframes, (step_start + 1) * 3 + 1);
-                let result = morph_start
+            let morph_start = get_keyframe(target_count, keyframes, step_start * 3 + 1);
+            let tangents_out_start = get_keyframe(target_count, keyframes, step_start * 3 + 2);
+            let tangents_in_end = get_keyframe(target_count, keyframes, (step_start + 1) * 3);
+            let morph_end = get_keyframe(target_count, keyframes, (step_start + 1) * 3 + 1);
+            CurveValue::Weights(
+                morph_start
                     .iter()
                     .zip(tangents_out_start)
                     .zip(tangents_in_end)
@@ -935,9 +1504,9 @@ fn apply_keyframe(
                                 duration,
                             )
                         },
-                    );
-                lerp_morph_weights(morphs.weights_mut(), result, weight);
-            }
+                    )
+                    .collect(),
+            )
         }
     }
 }
@@ -949,6 +1518,119 @@ fn update_transitions(player: &mut AnimationPlayer, time: &Time) {
     });
 }
 
+/// System that applies the [`PropertyCurve`]s of the clips played by every [`AnimationPlayer`].
+///
+/// This runs after [`animation_player`], which advances the playback of the clips, and writes
+/// to the animated components through reflection: they must be registered in the
+/// [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) with `#[reflect(Component)]`.
+#[allow(clippy::type_complexity)]
+pub fn animate_properties(
+    world: &mut World,
+    state: &mut SystemState<(
+        Res<Assets<AnimationClip>>,
+        Res<Assets<AnimationGraph>>,
+        Query<&Children>,
+        Query<&Name>,
+        Query<(
+            Entity,
+            Ref<AnimationPlayer>,
+            Option<&Handle<AnimationGraph>>,
+        )>,
+    )>,
+    mut warned: Local<HashSet<(TypeId, ParsedPath)>>,
+) {
+    let (animations, graphs, children, names, players) = state.get(world);
+    let mut samples = Vec::new();
+    for (root, player, graph) in &players {
+        if player.paused && !player.is_changed() {
+            continue;
+        }
+        match graph.map(|graph| graphs.get(graph)) {
+            Some(Some(graph)) => sample_graph_properties(
+                root,
+                &player,
+                graph,
+                &animations,
+                &children,
+                &names,
+                &mut samples,
+            ),
+            // The graph is still loading.
+            Some(None) => {}
+            None => {
+                let playing = std::iter::once((1.0, &player.animation)).chain(
+                    player
+                        .transitions
+                        .iter()
+                        .map(|transition| (transition.current_weight, &transition.animation)),
+                );
+                for (weight, animation) in playing {
+                    let Some(clip) = animations.get(&animation.animation_clip) else {
+                        continue;
+                    };
+                    for (path, curves) in &clip.property_curves {
+                        let Some(target) =
+                            entity_from_path(root, path, &children, &names, &mut Vec::new())
+                        else {
+                            continue;
+                        };
+                        samples.extend(curves.iter().filter_map(|curve| {
+                            let sample =
+                                PropertySample::new(curve, animation.state.seek_time, weight)?;
+                            Some((target, sample))
+                        }));
+                    }
+                }
+            }
+        }
+    }
+    apply_property_samples(world, samples, &mut warned);
+}
+
+/// Evaluates `graph` for every target of the [`PropertyCurve`]s of its playing nodes.
+fn sample_graph_properties(
+    root: Entity,
+    player: &AnimationPlayer,
+    graph: &AnimationGraph,
+    animations: &Assets<AnimationClip>,
+    children: &Query<&Children>,
+    names: &Query<&Name>,
+    samples: &mut Vec<(Entity, PropertySample)>,
+) {
+    let paths: HashSet<_> = player
+        .active_nodes
+        .keys()
+        .filter_map(|node| graph.get(*node)?.clip())
+        .filter_map(|clip| animations.get(clip))
+        .flat_map(|clip| clip.property_curves.keys())
+        .collect();
+    for path in paths {
+        let Some(target) = entity_from_path(root, path, children, names, &mut Vec::new()) else {
+            continue;
+        };
+        let evaluator = GraphEvaluator {
+            graph,
+            active_nodes: &player.active_nodes,
+            animations,
+            mask_groups: graph.mask_groups(path),
+            sample: &|_, clip: &AnimationClip, animation: &ActiveAnimation| {
+                let curves = clip.get_property_curves_by_path(path)?;
+                Some(PropertyPose(
+                    curves
+                        .iter()
+                        .filter_map(|curve| {
+                            PropertySample::new(curve, animation.seek_time, animation.weight)
+                        })
+                        .collect(),
+                ))
+            },
+        };
+        if let Some(pose) = evaluator.evaluate(graph.root()) {
+            samples.extend(pose.0.into_iter().map(|sample| (target, sample)));
+        }
+    }
+}
+
 /// Adds animation support to an app
 #[derive(Default)]
 pub struct AnimationPlugin;
@@ -956,19 +1638,35 @@ pub struct AnimationPlugin;
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip>()
+            .init_asset::<AnimationGraph>()
+            .init_asset_loader::<AnimationGraphAssetLoader>()
             .register_asset_reflect::<AnimationClip>()
             .register_type::<AnimationPlayer>()
             .add_systems(
                 PostUpdate,
-                animation_player.before(TransformSystem::TransformPropagate),
+                (animation_player, animate_properties)
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
             );
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::VariableCurve;
+    use crate::{
+        AnimationClip, AnimationGraph, AnimationNodeIndex, AnimationPlayer, EntityPath,
+        PropertyCurve, RootMotion, SerializedAnimationGraph, VariableCurve,
+    };
+    use bevy_app::{App, Update};
+    use bevy_asset::{Assets, Handle};
+    use bevy_core::{Name, TaskPoolPlugin};
+    use bevy_ecs::prelude::*;
     use bevy_math::Vec3;
+    use bevy_reflect::{ParsedPath, Reflect};
+    use bevy_render::color::Color;
+    use bevy_time::Time;
+    use bevy_transform::prelude::Transform;
+    use std::time::Duration;
 
     fn test_variable_curve() -> VariableCurve {
         let keyframe_timestamps = vec![1.0, 2.0, 3.0, 4.0];
@@ -1112,4 +1810,443 @@ mod tests {
             assert!(exact_keyframe == inexact_keyframe);
         }
     }
+
+    fn root_path() -> EntityPath {
+        EntityPath {
+            parts: vec![Name::new("root")],
+        }
+    }
+
+    /// A clip setting the translation of the root to `x` along the X axis.
+    fn translation_clip(app: &mut App, x: f32) -> Handle<AnimationClip> {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            root_path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0],
+                keyframes: crate::Keyframes::Translation(vec![Vec3::X * x]),
+                interpolation: crate::Interpolation::Linear,
+            },
+        );
+        app.world.resource_mut::<Assets<AnimationClip>>().add(clip)
+    }
+
+    fn graph_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .init_resource::<Time>()
+            .init_resource::<Assets<AnimationClip>>()
+            .init_resource::<Assets<AnimationGraph>>()
+            .add_systems(Update, crate::animation_player);
+        app
+    }
+
+    fn spawn_player(app: &mut App, graph: AnimationGraph, nodes: &[AnimationNodeIndex]) -> Entity {
+        let graph = app
+            .world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+        let mut player = AnimationPlayer::default();
+        for node in nodes {
+            player.start_node(*node);
+        }
+        app.world
+            .spawn((Name::new("root"), Transform::default(), player, graph))
+            .id()
+    }
+
+    fn translation_x(app: &App, entity: Entity) -> f32 {
+        app.world.get::<Transform>(entity).unwrap().translation.x
+    }
+
+    #[test]
+    fn graph_blends_playing_clips() {
+        let mut app = graph_app();
+        let clips = [
+            translation_clip(&mut app, 0.0),
+            translation_clip(&mut app, 2.0),
+        ];
+        let (graph, nodes) = AnimationGraph::from_clips(clips);
+        let root = spawn_player(&mut app, graph, &nodes);
+
+        app.update();
+        assert_eq!(translation_x(&app, root), 1.0);
+
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .node_animation_mut(nodes[1])
+            .unwrap()
+            .set_weight(3.0);
+        app.update();
+        assert_eq!(translation_x(&app, root), 1.5);
+
+        // Stopped nodes don't contribute.
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .stop_node(nodes[0]);
+        app.update();
+        assert_eq!(translation_x(&app, root), 2.0);
+    }
+
+    #[test]
+    fn graph_adds_additive_children_to_the_first_one() {
+        let mut app = graph_app();
+        let base = translation_clip(&mut app, 1.0);
+        let offset = translation_clip(&mut app, 2.0);
+        let mut graph = AnimationGraph::new();
+        let add = graph.add_additive_blend(1.0, graph.root());
+        let base = graph.add_clip(base, 1.0, add);
+        let offset = graph.add_clip(offset, 0.5, add);
+        let root = spawn_player(&mut app, graph, &[base, offset]);
+
+        app.update();
+        assert_eq!(translation_x(&app, root), 2.0);
+    }
+
+    #[test]
+    fn graph_masks_exclude_targets() {
+        let mut app = graph_app();
+        let clips = [
+            translation_clip(&mut app, 1.0),
+            translation_clip(&mut app, 3.0),
+        ];
+        let (mut graph, nodes) = AnimationGraph::from_clips(clips);
+        graph.add_target_to_mask_group(root_path(), 2);
+        graph.get_mut(nodes[1]).unwrap().mask = 1 << 2;
+        let root = spawn_player(&mut app, graph, &nodes);
+
+        app.update();
+        assert_eq!(translation_x(&app, root), 1.0);
+    }
+
+    #[test]
+    fn serialized_graph_round_trip() {
+        let ron = r#"(
+            nodes: [
+                (node_type: Blend, weight: 1.0),
+                (node_type: Clip("walk.gltf#Animation0"), weight: 0.5),
+                (node_type: Add, weight: 1.0, mask: 4),
+            ],
+            edges: [(0, 1), (0, 2)],
+            root: 0,
+            mask_groups: [(["root"], 4)],
+        )"#;
+        let serialized: SerializedAnimationGraph = ron::de::from_str(ron).unwrap();
+        let mut loaded_paths = Vec::new();
+        let graph = serialized
+            .clone()
+            .into_graph(|path| {
+                loaded_paths.push(path);
+                Handle::default()
+            })
+            .unwrap();
+        assert_eq!(loaded_paths.len(), 1);
+        assert_eq!(loaded_paths[0].to_string(), "walk.gltf#Animation0");
+        assert_eq!(graph.children(graph.root()).count(), 2);
+        assert_eq!(graph.mask_groups(&root_path()), 4);
+
+        let mut cyclic = serialized;
+        cyclic.edges.push((2, 0));
+        assert!(matches!(
+            cyclic.into_graph(|_| Handle::default()),
+            Err(crate::AnimationGraphLoadError::Cyclic)
+        ));
+    }
+
+    #[test]
+    fn children_are_in_edge_insertion_order() {
+        let mut graph = AnimationGraph::new();
+        let clip = graph.add_clip(Handle::default(), 1.0, graph.root());
+        let add = graph.add_additive_blend(1.0, graph.root());
+        let base = graph.add_clip(Handle::default(), 1.0, add);
+        // The clip node is older than the other child of the add node, but added after it.
+        graph.add_edge(add, clip);
+        assert_eq!(graph.children(add).collect::<Vec<_>>(), [base, clip]);
+
+        // Removing an edge keeps the order of the others.
+        let last = graph.add_clip(Handle::default(), 1.0, add);
+        assert!(graph.remove_edge(add, base));
+        assert_eq!(graph.children(add).collect::<Vec<_>>(), [clip, last]);
+        graph.add_edge(add, base);
+        assert_eq!(graph.children(add).collect::<Vec<_>>(), [clip, last, base]);
+    }
+
+    #[derive(Event, Clone)]
+    struct Footstep;
+
+    #[derive(Resource, Default)]
+    struct Footsteps(usize);
+
+    /// Plays a one second clip with an event at `0.45`, advancing time by `0.3` every update.
+    fn event_app() -> (App, Entity) {
+        let mut app = graph_app();
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            root_path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: crate::Keyframes::Translation(vec![Vec3::ZERO, Vec3::X]),
+                interpolation: crate::Interpolation::Linear,
+            },
+        );
+        clip.add_event(0.45, Footstep);
+        let clip = app.world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        let mut player = AnimationPlayer::default();
+        player.start(clip).repeat();
+        let root = app
+            .world
+            .spawn((Name::new("root"), Transform::default(), player))
+            .id();
+        app.init_resource::<Footsteps>();
+        app.world
+            .observe(|_: Trigger<Footstep>, mut footsteps: ResMut<Footsteps>| {
+                footsteps.0 += 1;
+            });
+        (app, root)
+    }
+
+    fn advance(app: &mut App) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.3));
+        app.update();
+    }
+
+    #[test]
+    fn clip_events_are_triggered_when_reached() {
+        let (mut app, _) = event_app();
+
+        // Seek times: 0.3, 0.6, 0.9, 0.2 (looped), 0.5.
+        let mut triggered = Vec::new();
+        for _ in 0..5 {
+            advance(&mut app);
+            triggered.push(app.world.resource::<Footsteps>().0);
+        }
+        assert_eq!(triggered, vec![0, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn clip_events_are_triggered_in_reverse() {
+        let (mut app, root) = event_app();
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .set_speed(-1.0);
+
+        // Seek times: 0.7 (looped), 0.4, 0.1.
+        let mut triggered = Vec::new();
+        for _ in 0..3 {
+            advance(&mut app);
+            triggered.push(app.world.resource::<Footsteps>().0);
+        }
+        assert_eq!(triggered, vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn clip_events_are_skipped_by_seeks() {
+        let (mut app, root) = event_app();
+
+        advance(&mut app);
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .seek_to(0.8);
+        advance(&mut app);
+        assert_eq!(app.world.resource::<Footsteps>().0, 0);
+
+        // Seeking onto an event reaches it.
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .seek_to(0.45);
+        advance(&mut app);
+        assert_eq!(app.world.resource::<Footsteps>().0, 1);
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Lamp {
+        intensity: f32,
+        color: Color,
+    }
+
+    /// A clip animating the intensity of the root's [`Lamp`] from `0` to `to` over one second.
+    fn intensity_clip(app: &mut App, to: f32) -> Handle<AnimationClip> {
+        let mut clip = AnimationClip::default();
+        clip.add_property_curve_to_path(
+            root_path(),
+            PropertyCurve::new::<Lamp, f32>(
+                ParsedPath::parse("intensity").unwrap(),
+                vec![0.0, 1.0],
+                vec![0.0, to],
+                crate::Interpolation::Linear,
+            ),
+        );
+        app.world.resource_mut::<Assets<AnimationClip>>().add(clip)
+    }
+
+    fn property_app() -> App {
+        let mut app = graph_app();
+        app.init_resource::<AppTypeRegistry>()
+            .register_type::<Lamp>()
+            .add_systems(
+                Update,
+                crate::animate_properties.after(crate::animation_player),
+            );
+        app
+    }
+
+    fn lamp(app: &App, entity: Entity) -> &Lamp {
+        app.world.get::<Lamp>(entity).unwrap()
+    }
+
+    #[test]
+    fn property_curves_animate_reflected_fields() {
+        let mut app = property_app();
+        let intensity = intensity_clip(&mut app, 800.0);
+        let mut color = AnimationClip::default();
+        color.add_property_curve_to_path(
+            root_path(),
+            PropertyCurve::new::<Lamp, Color>(
+                ParsedPath::parse("color").unwrap(),
+                vec![0.0],
+                vec![Color::rgba_linear(0.0, 0.5, 1.0, 1.0)],
+                crate::Interpolation::Step,
+            ),
+        );
+        let color = app.world.resource_mut::<Assets<AnimationClip>>().add(color);
+        let mut player = AnimationPlayer::default();
+        player.play(intensity);
+        let root = app
+            .world
+            .spawn((Name::new("root"), Lamp::default(), player))
+            .id();
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        app.update();
+        assert_eq!(lamp(&app, root).intensity, 400.0);
+
+        // Fields that no curve animates are left untouched.
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .play(color);
+        app.update();
+        assert_eq!(lamp(&app, root).intensity, 400.0);
+        assert_eq!(
+            lamp(&app, root).color.as_linear_rgba_f32(),
+            [0.0, 0.5, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn graph_blends_property_curves() {
+        let mut app = property_app();
+        let clips = [
+            intensity_clip(&mut app, 100.0),
+            intensity_clip(&mut app, 300.0),
+        ];
+        let (graph, nodes) = AnimationGraph::from_clips(clips);
+        let root = spawn_player(&mut app, graph, &nodes);
+        app.world.entity_mut(root).insert(Lamp::default());
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        app.update();
+        assert_eq!(lamp(&app, root).intensity, 100.0);
+    }
+
+    /// A looping clip moving the root along the X axis at `speed` units per second, for one second.
+    fn walk_clip(app: &mut App, speed: f32) -> Handle<AnimationClip> {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            root_path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: crate::Keyframes::Translation(vec![Vec3::ZERO, Vec3::X * speed]),
+                interpolation: crate::Interpolation::Linear,
+            },
+        );
+        app.world.resource_mut::<Assets<AnimationClip>>().add(clip)
+    }
+
+    fn root_motion_x(app: &App, entity: Entity) -> f32 {
+        app.world
+            .get::<RootMotion>(entity)
+            .unwrap()
+            .delta_translation()
+            .x
+    }
+
+    #[test]
+    fn root_motion_is_extracted_across_loops() {
+        let mut app = graph_app();
+        let walk = walk_clip(&mut app, 2.0);
+        let mut player = AnimationPlayer::default();
+        player.play(walk).repeat();
+        let root = app
+            .world
+            .spawn((
+                Name::new("root"),
+                Transform::default(),
+                player,
+                RootMotion::new(root_path()),
+            ))
+            .id();
+
+        // Seek times: 0.3, 0.6, 0.9, 0.2 (looped).
+        for _ in 0..4 {
+            advance(&mut app);
+            assert!((root_motion_x(&app, root) - 0.6).abs() < 1e-4);
+            assert_eq!(translation_x(&app, root), 0.0);
+        }
+    }
+
+    #[test]
+    fn root_motion_is_blended_during_transitions() {
+        let mut app = graph_app();
+        let walk = walk_clip(&mut app, 2.0);
+        let run = walk_clip(&mut app, 4.0);
+        let mut player = AnimationPlayer::default();
+        player.play(walk).repeat();
+        let root = app
+            .world
+            .spawn((
+                Name::new("root"),
+                Transform::default(),
+                player,
+                RootMotion::new(root_path()),
+            ))
+            .id();
+        advance(&mut app);
+
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .play_with_transition(run, Duration::from_secs(1))
+            .repeat();
+        advance(&mut app);
+        // The walk fades out with a weight of 0.7.
+        assert!((root_motion_x(&app, root) - (1.2 * 0.3 + 0.6 * 0.7)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn graph_blends_root_motion() {
+        let mut app = graph_app();
+        let clips = [walk_clip(&mut app, 2.0), walk_clip(&mut app, 4.0)];
+        let (graph, nodes) = AnimationGraph::from_clips(clips);
+        let root = spawn_player(&mut app, graph, &nodes);
+        app.world
+            .entity_mut(root)
+            .insert(RootMotion::new(root_path()));
+
+        advance(&mut app);
+        assert!((root_motion_x(&app, root) - 0.9).abs() < 1e-4);
+        assert_eq!(translation_x(&app, root), 0.0);
+    }
 }
@@ -0,0 +1,340 @@
+//! Curves animating the fields of arbitrary components, through reflection.
+
+use std::any::{Any, TypeId};
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::World,
+};
+use bevy_math::FloatExt;
+use bevy_reflect::{ParsedPath, Reflect, ReflectPath};
+use bevy_utils::{tracing::warn, HashSet};
+
+use crate::{
+    animatable::{Animatable, BlendInput},
+    Interpolation, Pose,
+};
+
+/// Describes how a field of a component should be animated.
+///
+/// The field is found with a [`ParsedPath`] from the component, which must be registered in the
+/// [`AppTypeRegistry`] with `#[reflect(Component)]`, and its type must be the [`Animatable`]
+/// type of the keyframes.
+///
+/// ```
+/// # use bevy_animation::{AnimationClip, EntityPath, Interpolation, PropertyCurve};
+/// # use bevy_core::Name;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::{ParsedPath, Reflect};
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Light {
+///     intensity: f32,
+/// }
+///
+/// let mut clip = AnimationClip::default();
+/// clip.add_property_curve_to_path(
+///     EntityPath {
+///         parts: vec![Name::new("lamp")],
+///     },
+///     PropertyCurve::new::<Light, f32>(
+///         ParsedPath::parse("intensity").unwrap(),
+///         vec![0.0, 1.0],
+///         vec![0.0, 800.0],
+///         Interpolation::Linear,
+///     ),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct PropertyCurve {
+    component: TypeId,
+    path: ParsedPath,
+    keyframe_timestamps: Vec<f32>,
+    keyframes: Arc<dyn PropertyKeyframes>,
+    interpolation: Interpolation,
+}
+
+impl Debug for PropertyCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PropertyCurve")
+            .field("component", &self.component)
+            .field("path", &self.path)
+            .field("keyframe_timestamps", &self.keyframe_timestamps)
+            .field("interpolation", &self.interpolation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PropertyCurve {
+    /// Creates a curve animating the field at `path` in the component `C`.
+    ///
+    /// `keyframe_timestamps` and `keyframes` should have the same length, except for
+    /// [`Interpolation::CubicSpline`] where each keyframe is made of three values, like in
+    /// [`VariableCurve`](crate::VariableCurve). The tangents of cubic spline keyframes are
+    /// ignored: values are interpolated linearly.
+    pub fn new<C: Component + Reflect, T: Animatable + Clone>(
+        path: ParsedPath,
+        keyframe_timestamps: Vec<f32>,
+        keyframes: Vec<T>,
+        interpolation: Interpolation,
+    ) -> Self {
+        Self::from_type_id(
+            TypeId::of::<C>(),
+            path,
+            keyframe_timestamps,
+            keyframes,
+            interpolation,
+        )
+    }
+
+    /// Creates a curve animating the field at `path` in the component with the given [`TypeId`].
+    ///
+    /// This is useful for curves whose component is only known at runtime, for instance
+    /// through a [`TypeRegistration`](bevy_reflect::TypeRegistration).
+    pub fn from_type_id<T: Animatable + Clone>(
+        component: TypeId,
+        path: ParsedPath,
+        keyframe_timestamps: Vec<f32>,
+        keyframes: Vec<T>,
+        interpolation: Interpolation,
+    ) -> Self {
+        Self {
+            component,
+            path,
+            keyframe_timestamps,
+            keyframes: Arc::new(keyframes),
+            interpolation,
+        }
+    }
+
+    /// The [`TypeId`] of the animated component.
+    pub fn component(&self) -> TypeId {
+        self.component
+    }
+
+    /// The path to the animated field, from the component.
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+
+    /// Timestamp for each of the keyframes.
+    pub fn keyframe_timestamps(&self) -> &[f32] {
+        &self.keyframe_timestamps
+    }
+
+    /// Interpolation method to use between keyframes.
+    pub fn interpolation(&self) -> &Interpolation {
+        &self.interpolation
+    }
+
+    /// Samples the curve at `seek_time`, returning `None` if it isn't running at that time.
+    pub(crate) fn sample(&self, seek_time: f32) -> Option<Box<dyn AnimatedValue>> {
+        let value_index = |keyframe: usize| match self.interpolation {
+            Interpolation::CubicSpline => keyframe * 3 + 1,
+            Interpolation::Linear | Interpolation::Step => keyframe,
+        };
+        // Some curves have only one keyframe used to set a value
+        if self.keyframe_timestamps.len() == 1 {
+            return self.keyframes.value(value_index(0));
+        }
+
+        let step_start = find_current_keyframe(&self.keyframe_timestamps, seek_time)?;
+        let timestamp_start = self.keyframe_timestamps[step_start];
+        let timestamp_end = self.keyframe_timestamps[step_start + 1];
+        match self.interpolation {
+            Interpolation::Step => self.keyframes.value(value_index(step_start)),
+            Interpolation::Linear | Interpolation::CubicSpline => self.keyframes.interpolate(
+                value_index(step_start),
+                value_index(step_start + 1),
+                f32::inverse_lerp(timestamp_start, timestamp_end, seek_time),
+            ),
+        }
+    }
+}
+
+/// Finds the index of the keyframe at or before `seek_time`, if there is one after it.
+///
+/// See [`VariableCurve::find_current_keyframe`](crate::VariableCurve::find_current_keyframe).
+fn find_current_keyframe(keyframe_timestamps: &[f32], seek_time: f32) -> Option<usize> {
+    let step_start = keyframe_timestamps.partition_point(|timestamp| *timestamp <= seek_time);
+    (step_start > 0 && step_start < keyframe_timestamps.len()).then(|| step_start - 1)
+}
+
+/// The keyframes of a [`PropertyCurve`], with their type erased.
+trait PropertyKeyframes: Send + Sync + 'static {
+    fn value(&self, index: usize) -> Option<Box<dyn AnimatedValue>>;
+
+    fn interpolate(&self, start: usize, end: usize, t: f32) -> Option<Box<dyn AnimatedValue>>;
+}
+
+impl<T: Animatable + Clone> PropertyKeyframes for Vec<T> {
+    fn value(&self, index: usize) -> Option<Box<dyn AnimatedValue>> {
+        Some(Box::new(self.get(index)?.clone()))
+    }
+
+    fn interpolate(&self, start: usize, end: usize, t: f32) -> Option<Box<dyn AnimatedValue>> {
+        Some(Box::new(T::interpolate(
+            self.get(start)?,
+            self.get(end)?,
+            t,
+        )))
+    }
+}
+
+/// A sampled [`Animatable`] value, with its type erased.
+pub(crate) trait AnimatedValue: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    /// Interpolates between `self` and `other`, which must have the same type.
+    fn interpolate(&self, other: &dyn AnimatedValue, t: f32) -> Box<dyn AnimatedValue>;
+
+    /// Adds `offset`, which must have the same type, on top of `self`.
+    fn add(&self, offset: &dyn AnimatedValue, weight: f32) -> Box<dyn AnimatedValue>;
+
+    /// Blends `self` into `target`, returning `false` if `target` doesn't have the same type.
+    fn apply(&self, target: &mut dyn Reflect, weight: f32) -> bool;
+}
+
+impl<T: Animatable + Clone> AnimatedValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn interpolate(&self, other: &dyn AnimatedValue, t: f32) -> Box<dyn AnimatedValue> {
+        match other.as_any().downcast_ref::<T>() {
+            Some(other) => Box::new(T::interpolate(self, other, t)),
+            None => Box::new(self.clone()),
+        }
+    }
+
+    fn add(&self, offset: &dyn AnimatedValue, weight: f32) -> Box<dyn AnimatedValue> {
+        let Some(offset) = offset.as_any().downcast_ref::<T>() else {
+            return Box::new(self.clone());
+        };
+        let inputs = [
+            BlendInput {
+                weight: 1.0,
+                value: self.clone(),
+                additive: false,
+            },
+            BlendInput {
+                weight,
+                value: offset.clone(),
+                additive: true,
+            },
+        ];
+        Box::new(T::blend(inputs.into_iter()))
+    }
+
+    fn apply(&self, target: &mut dyn Reflect, weight: f32) -> bool {
+        let Some(target) = target.downcast_mut::<T>() else {
+            return false;
+        };
+        *target = T::interpolate(target, self, weight);
+        true
+    }
+}
+
+/// The value of a field animated by a [`PropertyCurve`], with the weight it should be applied with.
+pub(crate) struct PropertySample {
+    pub(crate) component: TypeId,
+    pub(crate) path: ParsedPath,
+    pub(crate) value: Box<dyn AnimatedValue>,
+    pub(crate) weight: f32,
+}
+
+impl PropertySample {
+    pub(crate) fn new(curve: &PropertyCurve, seek_time: f32, weight: f32) -> Option<Self> {
+        Some(Self {
+            component: curve.component,
+            path: curve.path.clone(),
+            value: curve.sample(seek_time)?,
+            weight,
+        })
+    }
+
+    fn animates_same_field(&self, other: &PropertySample) -> bool {
+        self.component == other.component && self.path == other.path
+    }
+}
+
+/// The fields of a target animated by [`PropertyCurve`]s.
+#[derive(Default)]
+pub(crate) struct PropertyPose(pub(crate) Vec<PropertySample>);
+
+impl Pose for PropertyPose {
+    fn scale_weights(&mut self, factor: f32) {
+        for sample in &mut self.0 {
+            sample.weight *= factor;
+        }
+    }
+
+    fn blend(&mut self, other: Self) {
+        for sample in other.0 {
+            match self.0.iter_mut().find(|s| s.animates_same_field(&sample)) {
+                Some(existing) => {
+                    let total = existing.weight + sample.weight;
+                    if total > 0.0 {
+                        existing.value = existing
+                            .value
+                            .interpolate(&*sample.value, sample.weight / total);
+                    }
+                    existing.weight = total;
+                }
+                None => self.0.push(sample),
+            }
+        }
+    }
+
+    fn add(&mut self, other: Self) {
+        for sample in other.0 {
+            if let Some(existing) = self.0.iter_mut().find(|s| s.animates_same_field(&sample)) {
+                existing.value = existing.value.add(&*sample.value, sample.weight);
+            }
+        }
+    }
+}
+
+/// Blends the sampled fields into the components of their target entities.
+///
+/// Fields that cannot be animated are only reported once, and are then added to `warned`.
+pub(crate) fn apply_property_samples(
+    world: &mut World,
+    samples: Vec<(Entity, PropertySample)>,
+    warned: &mut HashSet<(TypeId, ParsedPath)>,
+) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for (entity, sample) in samples {
+        let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(sample.component)
+        else {
+            if warned.insert((sample.component, sample.path.clone())) {
+                warn!(
+                    "Cannot animate {:?}: the component isn't registered with `#[reflect(Component)]`",
+                    sample.path
+                );
+            }
+            continue;
+        };
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity_mut) else {
+            continue;
+        };
+        let applied = match sample.path.reflect_element_mut(component.as_reflect_mut()) {
+            Ok(field) => sample.value.apply(field, sample.weight.min(1.0)),
+            Err(_) => false,
+        };
+        if !applied && warned.insert((sample.component, sample.path.clone())) {
+            warn!(
+                "Cannot animate {:?} of {:?}: the path doesn't lead to a field of the keyframes' type",
+                sample.path, entity
+            );
+        }
+    }
+}
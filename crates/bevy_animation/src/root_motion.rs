@@ -0,0 +1,211 @@
+//! Extraction of the motion of a root bone out of the animations of an
+//! [`AnimationPlayer`](crate::AnimationPlayer).
+
+use bevy_asset::Assets;
+use bevy_core::Name;
+use bevy_ecs::{component::Component, entity::Entity, reflect::ReflectComponent, system::Query};
+use bevy_hierarchy::Children;
+use bevy_math::{Quat, Vec3};
+use bevy_reflect::Reflect;
+use bevy_transform::prelude::Transform;
+use bevy_utils::HashMap;
+
+use crate::{
+    entity_from_path, sample_curve, ActiveAnimation, AnimationClip, AnimationGraph,
+    AnimationNodeIndex, CurveValue, EntityPath, GraphEvaluator, Interpolation, Keyframes,
+    PlaybackStep, TargetPose, VariableCurve,
+};
+
+/// Extracts the motion of a root bone from the animations played by the
+/// [`AnimationPlayer`](crate::AnimationPlayer) of the same entity.
+///
+/// Every frame, the translation and rotation that the animations gave to the root bone since the
+/// previous frame are stored in this component, and removed from the bone: its extracted
+/// translation axes are set to zero, and its rotation to identity if it is extracted. A character
+/// controller can then move the character by [`RootMotion::delta_translation`] and
+/// [`RootMotion::delta_rotation`], which are expressed in the space of the root bone's parent.
+///
+/// Playback wrapping around the end of a looping clip (or its start, when reversed) is accounted
+/// for. Clips fading out during a transition, and the nodes of an
+/// [`AnimationGraph`], contribute to the motion with the same weight as they do to the pose.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct RootMotion {
+    /// The path of the root bone, starting from the entity of the player.
+    pub root_bone: EntityPath,
+    /// The axes of the translation that are extracted, each `0.0` or `1.0`.
+    ///
+    /// Defaults to [`Vec3::ONE`]. For example, `Vec3::new(1.0, 0.0, 1.0)` extracts the motion
+    /// on the ground while leaving the vertical motion on the bone.
+    pub translation_mask: Vec3,
+    /// Whether the rotation of the root bone is extracted.
+    pub extract_rotation: bool,
+    delta_translation: Vec3,
+    delta_rotation: Quat,
+    #[reflect(ignore)]
+    path_cache: Vec<Option<Entity>>,
+}
+
+impl Default for RootMotion {
+    fn default() -> Self {
+        Self::new(EntityPath::default())
+    }
+}
+
+impl RootMotion {
+    /// Extracts the translation and rotation of the bone at `root_bone`.
+    pub fn new(root_bone: EntityPath) -> Self {
+        Self {
+            root_bone,
+            translation_mask: Vec3::ONE,
+            extract_rotation: true,
+            delta_translation: Vec3::ZERO,
+            delta_rotation: Quat::IDENTITY,
+            path_cache: Vec::new(),
+        }
+    }
+
+    /// The translation extracted from the root bone during the last update of the player.
+    pub fn delta_translation(&self) -> Vec3 {
+        self.delta_translation
+    }
+
+    /// The rotation extracted from the root bone during the last update of the player.
+    pub fn delta_rotation(&self) -> Quat {
+        self.delta_rotation
+    }
+
+    /// Stores the motion of the root bone, keeping only the extracted parts.
+    pub(crate) fn set_delta(&mut self, (translation, rotation): (Vec3, Quat)) {
+        self.delta_translation = translation * self.translation_mask;
+        self.delta_rotation = if self.extract_rotation {
+            rotation
+        } else {
+            Quat::IDENTITY
+        };
+    }
+
+    /// Removes the extracted parts of the transform of the root bone.
+    pub(crate) fn remove_from_bone(
+        &mut self,
+        root: Entity,
+        children: &Query<&Children>,
+        names: &Query<&Name>,
+        transforms: &Query<&mut Transform>,
+    ) {
+        let Some(bone) =
+            entity_from_path(root, &self.root_bone, children, names, &mut self.path_cache)
+        else {
+            return;
+        };
+        // SAFETY: The caller checked that no ancestor of `root` has an `AnimationPlayer`, see
+        // `apply_animation` for why this prevents aliasing.
+        let Ok(mut transform) = (unsafe { transforms.get_unchecked(bone) }) else {
+            return;
+        };
+        transform.translation *= Vec3::ONE - self.translation_mask;
+        if self.extract_rotation {
+            transform.rotation = Quat::IDENTITY;
+        }
+    }
+}
+
+/// Interpolates between two root motions, like transforms are when fading out an animation.
+pub(crate) fn lerp_root_motion(a: (Vec3, Quat), b: (Vec3, Quat), weight: f32) -> (Vec3, Quat) {
+    (a.0.lerp(b.0, weight), a.1.slerp(b.1, weight))
+}
+
+/// The motion that `clip` gives to the bone at `root_bone` during a playback step.
+pub(crate) fn clip_root_motion(
+    clip: &AnimationClip,
+    root_bone: &EntityPath,
+    step: Option<PlaybackStep>,
+) -> (Vec3, Quat) {
+    let mut translation = Vec3::ZERO;
+    let mut rotation = Quat::IDENTITY;
+    let (Some(curves), Some(step)) = (clip.get_curves_by_path(root_bone), step) else {
+        return (translation, rotation);
+    };
+    // When wrapping around, the motion goes to one end of the clip, then starts from the other.
+    let duration = clip.duration();
+    let segments = match (step.wrapped, step.reversed) {
+        (false, _) => [Some((step.from, step.to)), None],
+        (true, false) => [Some((step.from, duration)), Some((0.0, step.to))],
+        (true, true) => [Some((step.from, 0.0)), Some((duration, step.to))],
+    };
+    for curve in curves {
+        for (from, to) in segments.into_iter().flatten() {
+            match (sample_clamped(curve, from), sample_clamped(curve, to)) {
+                (Some(CurveValue::Translation(from)), Some(CurveValue::Translation(to))) => {
+                    translation += to - from;
+                }
+                (Some(CurveValue::Rotation(from)), Some(CurveValue::Rotation(to))) => {
+                    rotation = to * from.inverse() * rotation;
+                }
+                _ => {}
+            }
+        }
+    }
+    (translation, rotation.normalize())
+}
+
+/// The motion that the playing nodes of `graph` give to the bone at `root_bone`, given the
+/// playback steps of the nodes during this update.
+pub(crate) fn graph_root_motion(
+    graph: &AnimationGraph,
+    active_nodes: &HashMap<AnimationNodeIndex, ActiveAnimation>,
+    animations: &Assets<AnimationClip>,
+    steps: &HashMap<AnimationNodeIndex, PlaybackStep>,
+    root_bone: &EntityPath,
+) -> (Vec3, Quat) {
+    let evaluator = GraphEvaluator {
+        graph,
+        active_nodes,
+        animations,
+        mask_groups: graph.mask_groups(root_bone),
+        sample: &|node, clip: &AnimationClip, animation: &ActiveAnimation| {
+            clip.get_curves_by_path(root_bone)?;
+            let (translation, rotation) =
+                clip_root_motion(clip, root_bone, steps.get(&node).copied());
+            let mut pose = TargetPose::default();
+            pose.set(CurveValue::Translation(translation), animation.weight);
+            pose.set(CurveValue::Rotation(rotation), animation.weight);
+            Some(pose)
+        },
+    };
+    let Some(pose) = evaluator.evaluate(graph.root()) else {
+        return (Vec3::ZERO, Quat::IDENTITY);
+    };
+    // Weights above 1 don't amplify the motion, like they don't amplify the pose.
+    let translation = pose
+        .translation
+        .map_or(Vec3::ZERO, |(translation, weight)| {
+            translation * weight.min(1.0)
+        });
+    let rotation = pose.rotation.map_or(Quat::IDENTITY, |(rotation, weight)| {
+        Quat::IDENTITY.slerp(rotation, weight.min(1.0))
+    });
+    (translation, rotation)
+}
+
+/// Samples a translation or rotation curve, clamping `time` to the keyframes of the curve.
+fn sample_clamped(curve: &VariableCurve, time: f32) -> Option<CurveValue> {
+    let first = *curve.keyframe_timestamps.first()?;
+    let last = curve.keyframe_timestamps.len() - 1;
+    let keyframe = if time <= first {
+        0
+    } else if time >= curve.keyframe_timestamps[last] {
+        last
+    } else {
+        return sample_curve(curve, time, 0);
+    };
+    let index = match curve.interpolation {
+        Interpolation::CubicSpline => keyframe * 3 + 1,
+        Interpolation::Linear | Interpolation::Step => keyframe,
+    };
+    match &curve.keyframes {
+        Keyframes::Translation(keyframes) => Some(CurveValue::Translation(*keyframes.get(index)?)),
+        Keyframes::Rotation(keyframes) => Some(CurveValue::Rotation(*keyframes.get(index)?)),
+        Keyframes::Scale(_) | Keyframes::Weights(_) => None,
+    }
+}
@@ -3,12 +3,17 @@ pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     prelude::*,
     schedule::{
-        apply_state_transition, common_conditions::run_once as run_once_condition,
-        run_enter_schedule, InternedScheduleLabel, IntoSystemConfigs, IntoSystemSetConfigs,
-        ScheduleBuildSettings, ScheduleLabel, StateTransitionEvent,
+        register_state_transition_systems, FreelyMutableState, InternedScheduleLabel,
+        IntoSystemConfigs, IntoSystemSetConfigs, ScheduleBuildSettings, ScheduleGraphExport,
+        ScheduleLabel, StateTransitionEvent,
     },
 };
-use bevy_utils::{intern::Interned, thiserror::Error, tracing::debug, HashMap, HashSet};
+use bevy_utils::{
+    intern::Interned,
+    thiserror::Error,
+    tracing::{debug, warn},
+    HashMap, HashSet,
+};
 use std::{
     fmt::Debug,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
@@ -183,7 +188,10 @@ impl Default for App {
     fn default() -> Self {
         let mut app = App::empty();
         #[cfg(feature = "bevy_reflect")]
-        app.init_resource::<AppTypeRegistry>();
+        {
+            app.init_resource::<AppTypeRegistry>();
+            app.init_resource::<AppFunctionRegistry>();
+        }
 
         app.add_plugins(MainSchedulePlugin);
 
@@ -353,31 +361,27 @@ impl App {
     ///
     /// If the [`State`] already exists, nothing happens.
     ///
-    /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
-    /// for each state variant (if they don't already exist), an instance of [`apply_state_transition::<S>`] in
-    /// [`StateTransition`] so that transitions happen before [`Update`](crate::Update) and
-    /// a instance of [`run_enter_schedule::<S>`] in [`StateTransition`] with a
-    /// [`run_once`](`run_once_condition`) condition to run the on enter schedule of the
-    /// initial state.
+    /// Adds [`State<S>`] and [`NextState<S>`] resources, and the systems applying the transitions
+    /// of `S` and running its [`OnEnter`], [`OnTransition`] and [`OnExit`] schedules in
+    /// [`StateTransition`], so that transitions happen before [`Update`](crate::Update).
+    /// The [`OnEnter`] schedule of the initial state runs during the first [`StateTransition`].
     ///
     /// If you would like to control how other systems run based on the current state,
     /// you can emulate this behavior using the [`in_state`] [`Condition`].
     ///
     /// Note that you can also apply state transitions at other points in the schedule
-    /// by adding the [`apply_state_transition`] system manually.
-    pub fn init_state<S: States + FromWorld>(&mut self) -> &mut Self {
+    /// by running the [`StateTransition`] schedule manually.
+    pub fn init_state<S: FreelyMutableState + FromWorld>(&mut self) -> &mut Self {
         if !self.world.contains_resource::<State<S>>() {
             self.init_resource::<State<S>>()
                 .init_resource::<NextState<S>>()
                 .add_event::<StateTransitionEvent<S>>()
-                .add_systems(
-                    StateTransition,
-                    (
-                        run_enter_schedule::<S>.run_if(run_once_condition()),
-                        apply_state_transition::<S>,
-                    )
-                        .chain(),
-                );
+                .edit_schedule(StateTransition, register_state_transition_systems::<S>);
+            let state = self.world.resource::<State<S>>().get().clone();
+            self.world.send_event(StateTransitionEvent {
+                before: None,
+                after: Some(state),
+            });
         }
 
         // The OnEnter, OnExit, and OnTransition schedules are lazily initialized
@@ -390,30 +394,36 @@ impl App {
     /// Inserts a specific [`State`] to the current [`App`] and
     /// overrides any [`State`] previously added of the same type.
     ///
-    /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
-    /// for each state variant (if they don't already exist), an instance of [`apply_state_transition::<S>`] in
-    /// [`StateTransition`] so that transitions happen before [`Update`](crate::Update) and
-    /// a instance of [`run_enter_schedule::<S>`] in [`StateTransition`] with a
-    /// [`run_once`](`run_once_condition`) condition to run the on enter schedule of the
-    /// initial state.
+    /// Adds [`State<S>`] and [`NextState<S>`] resources, and the systems applying the transitions
+    /// of `S` and running its [`OnEnter`], [`OnTransition`] and [`OnExit`] schedules in
+    /// [`StateTransition`], so that transitions happen before [`Update`](crate::Update).
+    /// The [`OnEnter`] schedule of the inserted state runs during the next [`StateTransition`].
     ///
     /// If you would like to control how other systems run based on the current state,
     /// you can emulate this behavior using the [`in_state`] [`Condition`].
     ///
     /// Note that you can also apply state transitions at other points in the schedule
-    /// by adding the [`apply_state_transition`] system manually.
-    pub fn insert_state<S: States>(&mut self, state: S) -> &mut Self {
-        self.insert_resource(State::new(state))
-            .init_resource::<NextState<S>>()
-            .add_event::<StateTransitionEvent<S>>()
-            .add_systems(
-                StateTransition,
-                (
-                    run_enter_schedule::<S>.run_if(run_once_condition()),
-                    apply_state_transition::<S>,
-                )
-                    .chain(),
-            );
+    /// by running the [`StateTransition`] schedule manually.
+    pub fn insert_state<S: FreelyMutableState>(&mut self, state: S) -> &mut Self {
+        let before = self
+            .world
+            .remove_resource::<State<S>>()
+            .map(|state| state.get().clone());
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            self.init_resource::<NextState<S>>()
+                .add_event::<StateTransitionEvent<S>>()
+                .edit_schedule(StateTransition, register_state_transition_systems::<S>);
+        }
+        self.insert_resource(State::new(state.clone()));
+        if before.as_ref() != Some(&state) {
+            self.world.send_event(StateTransitionEvent {
+                before,
+                after: Some(state),
+            });
+        }
 
         // The OnEnter, OnExit, and OnTransition schedules are lazily initialized
         // (i.e. when the first system is added to them), and World::try_run_schedule is used to fail
@@ -422,6 +432,45 @@ impl App {
         self
     }
 
+    /// Sets up a type implementing [`ComputedStates`].
+    ///
+    /// The value of the state is computed from its source states during [`StateTransition`],
+    /// after the source states have been updated, and its [`OnExit`], [`OnTransition`] and
+    /// [`OnEnter`] schedules are ordered relative to the ones of its sources.
+    ///
+    /// This method is idempotent: it has no effect when called again with the same type.
+    pub fn add_computed_state<S: ComputedStates>(&mut self) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            self.add_event::<StateTransitionEvent<S>>()
+                .edit_schedule(StateTransition, S::register_computed_state_systems);
+        }
+
+        self
+    }
+
+    /// Sets up a type implementing [`SubStates`].
+    ///
+    /// The state is created during [`StateTransition`] when its source states start matching,
+    /// and removed when they stop matching. While it exists, it can be changed through
+    /// [`NextState<S>`] like any other state.
+    ///
+    /// This method is idempotent: it has no effect when called again with the same type.
+    pub fn add_sub_state<S: SubStates>(&mut self) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            self.init_resource::<NextState<S>>()
+                .add_event::<StateTransitionEvent<S>>()
+                .edit_schedule(StateTransition, S::register_sub_state_systems);
+        }
+
+        self
+    }
+
     /// Adds a system to the given schedule in this app's [`Schedules`].
     ///
     /// # Examples
@@ -803,6 +852,40 @@ impl App {
         self
     }
 
+    /// Registers `function` under `name` in the [`AppFunctionRegistry`] resource, so that it can
+    /// be called by name through reflection.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_ecs::reflect::AppFunctionRegistry;
+    /// use bevy_reflect::func::ArgList;
+    ///
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.register_function("add", add);
+    ///
+    /// let registry = app.world.resource::<AppFunctionRegistry>().read();
+    /// let args = ArgList::new().push_owned(1_i32).push_owned(2_i32);
+    /// let sum = registry.call("add", args).unwrap().unwrap();
+    /// assert_eq!(sum.downcast_ref::<i32>(), Some(&3));
+    /// ```
+    ///
+    /// See [`bevy_reflect::func::FunctionRegistry::register`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_function<Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        function: impl bevy_reflect::func::IntoFunction<Marker>,
+    ) -> &mut Self {
+        let registry = self.world.resource_mut::<AppFunctionRegistry>();
+        registry.write().register(name, function);
+        self
+    }
+
     /// Retrieves a `SubApp` stored inside this [`App`].
     ///
     /// # Panics
@@ -1034,6 +1117,47 @@ impl App {
 
         self
     }
+
+    /// Exports the graphs of all the schedules in [`Schedules`], sorted by label, to visualize
+    /// them with [`ScheduleGraphExport::to_dot`] or [`ScheduleGraphExport::to_json`].
+    ///
+    /// The schedules are initialized first, so that the exported graphs contain the sync points
+    /// and conflicts found while building them. Schedules failing to build are still exported.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// fn system() {}
+    ///
+    /// let mut app = App::new();
+    /// app.add_systems(Update, system);
+    ///
+    /// for export in app.export_schedule_graphs() {
+    ///     let dot = export.to_dot();
+    ///     // write it to a file, or render it with Graphviz
+    /// }
+    /// ```
+    pub fn export_schedule_graphs(&mut self) -> Vec<ScheduleGraphExport> {
+        let mut labels: Vec<_> = self
+            .world
+            .resource::<Schedules>()
+            .iter()
+            .map(|(_, schedule)| schedule.label())
+            .collect();
+        labels.sort_by_cached_key(|label| format!("{label:?}"));
+
+        labels
+            .into_iter()
+            .map(|label| {
+                self.world.schedule_scope(label, |world, schedule| {
+                    if let Err(err) = schedule.initialize(world) {
+                        warn!("Failed to build schedule {label:?} for export: {err}");
+                    }
+                    schedule.export_graph(world.components())
+                })
+            })
+            .collect()
+    }
 }
 
 fn run_once(mut app: App) {
@@ -1065,7 +1189,7 @@ mod tests {
     use std::marker::PhantomData;
 
     use bevy_ecs::{
-        schedule::{OnEnter, States},
+        schedule::{ComputedStates, NextState, OnEnter, State, States, SubStates},
         system::Commands,
     };
 
@@ -1161,6 +1285,67 @@ mod tests {
         assert_eq!(app.world.entities().len(), 2);
     }
 
+    #[test]
+    fn computed_and_sub_states_follow_their_source() {
+        #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+        enum GameState {
+            #[default]
+            Menu,
+            Playing,
+            Paused,
+        }
+
+        #[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+        struct InGame;
+
+        impl ComputedStates for InGame {
+            type SourceStates = GameState;
+
+            fn compute(sources: GameState) -> Option<Self> {
+                matches!(sources, GameState::Playing | GameState::Paused).then_some(InGame)
+            }
+        }
+
+        #[derive(SubStates, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+        #[source(GameState = GameState::Playing)]
+        enum Turn {
+            #[default]
+            Player,
+            Enemy,
+        }
+
+        let mut app = App::new();
+        app.init_state::<GameState>()
+            .add_computed_state::<InGame>()
+            .add_sub_state::<Turn>()
+            .add_systems(OnEnter(InGame), foo);
+
+        app.update();
+        assert!(app.world.get_resource::<State<InGame>>().is_none());
+        assert!(app.world.get_resource::<State<Turn>>().is_none());
+
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        assert!(app.world.get_resource::<State<InGame>>().is_some());
+        assert_eq!(app.world.resource::<State<Turn>>().get(), &Turn::Player);
+        assert_eq!(app.world.entities().len(), 1);
+
+        app.world.resource_mut::<NextState<Turn>>().set(Turn::Enemy);
+        app.update();
+        assert_eq!(app.world.resource::<State<Turn>>().get(), &Turn::Enemy);
+
+        // Pausing keeps `InGame` without re-entering it, but removes the sub-state.
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        app.update();
+        assert!(app.world.get_resource::<State<InGame>>().is_some());
+        assert!(app.world.get_resource::<State<Turn>>().is_none());
+        assert_eq!(app.world.entities().len(), 1);
+    }
+
     #[test]
     fn test_derive_app_label() {
         use super::AppLabel;
@@ -1295,4 +1480,24 @@ mod tests {
             .add_systems(PreUpdate, my_system)
             .run();
     }
+
+    #[test]
+    fn export_schedule_graphs() {
+        use crate::{First, Update};
+
+        fn system() {}
+
+        let mut app = App::new();
+        app.add_systems(First, system).add_systems(Update, system);
+
+        let exports = app.export_schedule_graphs();
+        let labels: Vec<_> = exports.iter().map(|export| export.label.as_str()).collect();
+        assert!(labels.windows(2).all(|labels| labels[0] <= labels[1]));
+        for label in ["First", "Update"] {
+            let export = exports.iter().find(|export| export.label == label).unwrap();
+            assert!(export.systems.iter().any(|system| system.name == "system"));
+        }
+        // the schedules are still usable
+        app.update();
+    }
 }
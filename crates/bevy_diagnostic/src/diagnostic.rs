@@ -354,8 +354,16 @@ struct DiagnosticsBuffer(HashMap<DiagnosticPath, DiagnosticMeasurement, PassHash
 impl SystemBuffer for DiagnosticsBuffer {
     fn apply(
         &mut self,
-        _system_meta: &bevy_ecs::system::SystemMeta,
+        system_meta: &bevy_ecs::system::SystemMeta,
         world: &mut bevy_ecs::world::World,
+    ) {
+        self.queue(system_meta, world.into());
+    }
+
+    fn queue(
+        &mut self,
+        _system_meta: &bevy_ecs::system::SystemMeta,
+        mut world: bevy_ecs::world::DeferredWorld,
     ) {
         let mut diagnostics = world.resource_mut::<DiagnosticsStore>();
         for (path, measurement) in self.0.drain() {
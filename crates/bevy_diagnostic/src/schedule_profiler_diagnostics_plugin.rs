@@ -0,0 +1,135 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ScheduleLabel, ScheduleProfiler},
+};
+use bevy_utils::{HashMap, Instant};
+
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+/// Adds per-schedule and per-system timing diagnostics to an App, measured by the
+/// [`ScheduleProfiler`] in the multi-threaded executor.
+///
+/// For each schedule, it records these diagnostics:
+/// - `schedule/<schedule>/run_time`: the time it took to run the schedule
+/// - `schedule/<schedule>/blocked_time`: the time systems were blocked by conflicting access
+/// - `schedule/<schedule>/utilization`: the percentage of the thread time spent running systems
+/// - `schedule/<schedule>/system/<system>/run_time`: the time it took to run the system
+/// - `schedule/<schedule>/system/<system>/blocked_time`: the time the system was blocked
+///
+/// Durations are in milliseconds, and the instances of a system are summed.
+///
+/// This can be used to assert performance budgets in tests:
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::schedule::ExecutorKind;
+/// # use bevy_diagnostic::{DiagnosticsStore, ScheduleProfilerDiagnosticsPlugin};
+/// fn system() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(ScheduleProfilerDiagnosticsPlugin)
+///     .add_systems(Update, system)
+///     .edit_schedule(Update, |schedule| {
+///         schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+///     });
+/// app.update();
+///
+/// let diagnostics = app.world.resource::<DiagnosticsStore>();
+/// let path = ScheduleProfilerDiagnosticsPlugin::schedule_path(&Update, "run_time");
+/// let run_time = diagnostics.get_measurement(&path).unwrap().value;
+/// assert!(run_time < 100.0);
+/// ```
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct ScheduleProfilerDiagnosticsPlugin;
+
+impl Plugin for ScheduleProfilerDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduleProfiler>()
+            .init_resource::<DiagnosticsStore>()
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl ScheduleProfilerDiagnosticsPlugin {
+    /// Returns the path of a diagnostic of the schedule with the given label.
+    pub fn schedule_path(schedule: &dyn ScheduleLabel, name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["schedule", &format!("{schedule:?}"), name])
+    }
+
+    /// Returns the path of a diagnostic of the system with the given name, in the schedule with
+    /// the given label.
+    pub fn system_path(schedule: &dyn ScheduleLabel, system: &str, name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components([
+            "schedule",
+            &format!("{schedule:?}"),
+            "system",
+            system,
+            name,
+        ])
+    }
+
+    pub fn diagnostic_system(
+        profiler: Res<ScheduleProfiler>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
+        mut recorded_runs: Local<HashMap<String, u64>>,
+    ) {
+        let time = Instant::now();
+        for (label, profile) in profiler.iter() {
+            // only record the schedules that ran since the last time
+            let recorded = recorded_runs.entry(format!("{label:?}")).or_default();
+            if *recorded == profile.runs {
+                continue;
+            }
+            *recorded = profile.runs;
+
+            let schedule_measurements = [
+                ("run_time", "ms", profile.run_time.as_secs_f64() * 1000.0),
+                (
+                    "blocked_time",
+                    "ms",
+                    profile.blocked_time.as_secs_f64() * 1000.0,
+                ),
+                ("utilization", "%", profile.utilization() * 100.0),
+            ];
+            for (name, suffix, value) in schedule_measurements {
+                let path = Self::schedule_path(label, name);
+                add_measurement(&mut diagnostics, path, suffix, time, value);
+            }
+
+            let mut systems = HashMap::<&str, (f64, f64)>::new();
+            for system in &profile.systems {
+                let times = systems.entry(&system.name).or_default();
+                times.0 += system.run_time.as_secs_f64() * 1000.0;
+                times.1 += system.blocked_time.as_secs_f64() * 1000.0;
+            }
+            for (system, (run_time, blocked_time)) in systems {
+                for (name, value) in [("run_time", run_time), ("blocked_time", blocked_time)] {
+                    let path = Self::system_path(label, system, name);
+                    add_measurement(&mut diagnostics, path, "ms", time, value);
+                }
+            }
+        }
+    }
+}
+
+/// Adds a measurement to the diagnostic at `path`, registering it if needed.
+fn add_measurement(
+    diagnostics: &mut DiagnosticsStore,
+    path: DiagnosticPath,
+    suffix: &'static str,
+    time: Instant,
+    value: f64,
+) {
+    if diagnostics.get(&path).is_none() {
+        diagnostics.add(Diagnostic::new(path.clone()).with_suffix(suffix));
+    }
+    let diagnostic = diagnostics.get_mut(&path).unwrap();
+    if diagnostic.is_enabled {
+        diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+    }
+}
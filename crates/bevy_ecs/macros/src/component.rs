@@ -1,12 +1,26 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, Ident, LitStr, Path, Result};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, ExprPath, Fields, Ident,
+    Index, LitStr, Member, Path, Result, Type,
+};
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
+    let attrs = match parse_event_attr(&ast) {
+        Ok(attrs) => attrs,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let traversal = attrs
+        .traversal
+        .map(|traversal| quote! { #traversal })
+        .unwrap_or_else(|| quote! { () });
+    let auto_propagate = attrs.auto_propagate;
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -17,10 +31,48 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::event::Event for #struct_name #type_generics #where_clause {
+            type Traversal = #traversal;
+            const AUTO_PROPAGATE: bool = #auto_propagate;
+        }
+
+        impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
+            type Storage = #bevy_ecs_path::component::SparseStorage;
         }
     })
 }
 
+pub const EVENT: &str = "event";
+pub const TRAVERSAL: &str = "traversal";
+pub const AUTO_PROPAGATE: &str = "auto_propagate";
+
+struct EventAttrs {
+    traversal: Option<Type>,
+    auto_propagate: bool,
+}
+
+fn parse_event_attr(ast: &DeriveInput) -> Result<EventAttrs> {
+    let mut attrs = EventAttrs {
+        traversal: None,
+        auto_propagate: false,
+    };
+
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(EVENT)) {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(TRAVERSAL) {
+                attrs.traversal = Some(nested.value()?.parse::<Type>()?);
+                Ok(())
+            } else if nested.path.is_ident(AUTO_PROPAGATE) {
+                attrs.auto_propagate = true;
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+    }
+
+    Ok(attrs)
+}
+
 pub fn derive_resource(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
@@ -48,8 +100,49 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match parse_relationship_attrs(&ast, &attrs) {
+        Ok(relationship) => relationship,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
+    let mut on_insert = attrs.on_insert;
+    let mut on_replace = attrs.on_replace;
+    let mut on_despawn = attrs.on_despawn;
+    let relationship_impl = relationship.map(|relationship| match relationship {
+        RelationshipAttr::Relationship { target, member } => {
+            on_insert = Some(parse_quote! { <Self as #bevy_ecs_path::relationship::Relationship>::on_insert });
+            on_replace = Some(parse_quote! { <Self as #bevy_ecs_path::relationship::Relationship>::on_replace });
+            relationship_impl(&bevy_ecs_path, &ast, &target, &member)
+        }
+        RelationshipAttr::RelationshipTarget {
+            relationship,
+            linked_spawn,
+            member,
+            collection,
+        } => {
+            on_replace = Some(parse_quote! { <Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace });
+            if linked_spawn {
+                on_despawn = Some(parse_quote! { <Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_despawn });
+            }
+            relationship_target_impl(
+                &bevy_ecs_path,
+                &ast,
+                &relationship,
+                linked_spawn,
+                &member,
+                &collection,
+            )
+        }
+    });
+
+    let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
+    let on_insert = hook_register_function_call(quote! {on_insert}, on_insert);
+    let on_replace = hook_register_function_call(quote! {on_replace}, on_replace);
+    let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
+    let on_despawn = hook_register_function_call(quote! {on_despawn}, on_despawn);
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -61,15 +154,40 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             type Storage = #storage;
+
+            #[allow(unused_variables)]
+            fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
+                #on_add
+                #on_insert
+                #on_replace
+                #on_remove
+                #on_despawn
+            }
         }
+
+        #relationship_impl
     })
 }
 
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
+pub const ON_ADD: &str = "on_add";
+pub const ON_INSERT: &str = "on_insert";
+pub const ON_REPLACE: &str = "on_replace";
+pub const ON_REMOVE: &str = "on_remove";
+pub const ON_DESPAWN: &str = "on_despawn";
+
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+pub const LINKED_SPAWN: &str = "linked_spawn";
 
 struct Attrs {
     storage: StorageTy,
+    on_add: Option<ExprPath>,
+    on_insert: Option<ExprPath>,
+    on_replace: Option<ExprPath>,
+    on_remove: Option<ExprPath>,
+    on_despawn: Option<ExprPath>,
 }
 
 #[derive(Clone, Copy)]
@@ -85,6 +203,11 @@ const SPARSE_SET: &str = "SparseSet";
 fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     let mut attrs = Attrs {
         storage: StorageTy::Table,
+        on_add: None,
+        on_insert: None,
+        on_replace: None,
+        on_remove: None,
+        on_despawn: None,
     };
 
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
@@ -100,6 +223,21 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                     }
                 };
                 Ok(())
+            } else if nested.path.is_ident(ON_ADD) {
+                attrs.on_add = Some(nested.value()?.parse::<ExprPath>()?);
+                Ok(())
+            } else if nested.path.is_ident(ON_INSERT) {
+                attrs.on_insert = Some(nested.value()?.parse::<ExprPath>()?);
+                Ok(())
+            } else if nested.path.is_ident(ON_REPLACE) {
+                attrs.on_replace = Some(nested.value()?.parse::<ExprPath>()?);
+                Ok(())
+            } else if nested.path.is_ident(ON_REMOVE) {
+                attrs.on_remove = Some(nested.value()?.parse::<ExprPath>()?);
+                Ok(())
+            } else if nested.path.is_ident(ON_DESPAWN) {
+                attrs.on_despawn = Some(nested.value()?.parse::<ExprPath>()?);
+                Ok(())
             } else {
                 Err(nested.error("Unsupported attribute"))
             }
@@ -117,3 +255,187 @@ fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
 
     quote! { #bevy_ecs_path::component::#typename }
 }
+
+fn hook_register_function_call(
+    hook: TokenStream2,
+    function: Option<ExprPath>,
+) -> Option<TokenStream2> {
+    function.map(|meta| quote! { hooks. #hook (#meta); })
+}
+
+enum RelationshipAttr {
+    Relationship {
+        target: Type,
+        member: Member,
+    },
+    RelationshipTarget {
+        relationship: Type,
+        linked_spawn: bool,
+        member: Member,
+        collection: Box<Type>,
+    },
+}
+
+fn parse_relationship_attrs(ast: &DeriveInput, attrs: &Attrs) -> Result<Option<RelationshipAttr>> {
+    let mut relationship = None;
+
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(RELATIONSHIP)) {
+        if attrs.on_insert.is_some() || attrs.on_replace.is_some() {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Relationship components cannot define custom `on_insert` or `on_replace` hooks",
+            ));
+        }
+        let (member, _) = single_field(ast)?;
+        let mut target = None;
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                target = Some(nested.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+        let Some(target) = target else {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Missing `relationship_target = ...` in the `relationship` attribute",
+            ));
+        };
+        relationship = Some(RelationshipAttr::Relationship { target, member });
+    }
+
+    for meta in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(RELATIONSHIP_TARGET))
+    {
+        if relationship.is_some() {
+            return Err(syn::Error::new(
+                meta.span(),
+                "A component cannot be both a `relationship` and a `relationship_target`",
+            ));
+        }
+        if attrs.on_replace.is_some() || attrs.on_despawn.is_some() {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Relationship target components cannot define custom `on_replace` or `on_despawn` hooks",
+            ));
+        }
+        let (member, collection) = single_field(ast)?;
+        let mut source = None;
+        let mut linked_spawn = false;
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP) {
+                source = Some(nested.value()?.parse::<Type>()?);
+                Ok(())
+            } else if nested.path.is_ident(LINKED_SPAWN) {
+                linked_spawn = true;
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+        let Some(source) = source else {
+            return Err(syn::Error::new(
+                meta.span(),
+                "Missing `relationship = ...` in the `relationship_target` attribute",
+            ));
+        };
+        relationship = Some(RelationshipAttr::RelationshipTarget {
+            relationship: source,
+            linked_spawn,
+            member,
+            collection: Box::new(collection),
+        });
+    }
+
+    Ok(relationship)
+}
+
+/// Returns the member and type of the only field of a struct.
+fn single_field(ast: &DeriveInput) -> Result<(Member, Type)> {
+    let error = || {
+        syn::Error::new(
+            ast.ident.span(),
+            "Relationship components must be structs with exactly one field",
+        )
+    };
+    let Data::Struct(data) = &ast.data else {
+        return Err(error());
+    };
+    match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            let field = &fields.named[0];
+            Ok((
+                Member::Named(field.ident.clone().unwrap()),
+                field.ty.clone(),
+            ))
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            Ok((Member::Unnamed(Index::from(0)), fields.unnamed[0].ty.clone()))
+        }
+        _ => Err(error()),
+    }
+}
+
+fn relationship_impl(
+    bevy_ecs_path: &Path,
+    ast: &DeriveInput,
+    target: &Type,
+    member: &Member,
+) -> TokenStream2 {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self { #member: entity }
+            }
+        }
+    }
+}
+
+fn relationship_target_impl(
+    bevy_ecs_path: &Path,
+    ast: &DeriveInput,
+    relationship: &Type,
+    linked_spawn: bool,
+    member: &Member,
+    collection: &Type,
+) -> TokenStream2 {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            type Collection = #collection;
+            const LINKED_SPAWN: bool = #linked_spawn;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self { #member: collection }
+            }
+        }
+    }
+}
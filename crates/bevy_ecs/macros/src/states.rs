@@ -1,21 +1,106 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Pat, Path, Result, Token, Type};
 
 use crate::bevy_ecs_path;
 
+fn states_path() -> Path {
+    let mut path = bevy_ecs_path();
+    path.segments.push(format_ident!("schedule").into());
+    path
+}
+
 pub fn derive_states(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let generics = ast.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let mut trait_path = bevy_ecs_path();
-    trait_path.segments.push(format_ident!("schedule").into());
+    let mut trait_path = states_path();
     trait_path.segments.push(format_ident!("States").into());
+    let mut freely_mutable_path = states_path();
+    freely_mutable_path
+        .segments
+        .push(format_ident!("FreelyMutableState").into());
     let struct_name = &ast.ident;
 
     quote! {
         impl #impl_generics #trait_path for #struct_name #ty_generics #where_clause {}
+
+        impl #impl_generics #freely_mutable_path for #struct_name #ty_generics #where_clause {}
+    }
+    .into()
+}
+
+struct Source {
+    source_type: Type,
+    source_value: Pat,
+}
+
+fn parse_sources_attr(ast: &DeriveInput) -> Result<Source> {
+    let mut result = ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("source"))
+        .map(|meta| {
+            meta.parse_args_with(|input: syn::parse::ParseStream| {
+                let source_type = input.parse::<Type>()?;
+                input.parse::<Token![=]>()?;
+                let source_value = Pat::parse_multi(input)?;
+                Ok(Source {
+                    source_type,
+                    source_value,
+                })
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if result.len() > 1 {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Only one source can be defined for a `SubStates` derive",
+        ));
+    }
+
+    result.pop().ok_or_else(|| {
+        syn::Error::new(
+            ast.span(),
+            "A `SubStates` derive requires a `#[source(SourceState = SourceState::Variant)]` attribute",
+        )
+    })
+}
+
+pub fn derive_sub_states(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let Source {
+        source_type,
+        source_value,
+    } = match parse_sources_attr(&ast) {
+        Ok(source) => source,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let generics = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let state_path = states_path();
+    let struct_name = &ast.ident;
+
+    quote! {
+        impl #impl_generics #state_path::SubStates for #struct_name #ty_generics #where_clause {
+            type SourceStates = #source_type;
+
+            fn should_exist(sources: #source_type) -> Option<Self> {
+                if matches!(sources, #source_value) {
+                    Some(Self::default())
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl #impl_generics #state_path::States for #struct_name #ty_generics #where_clause {}
+
+        impl #impl_generics #state_path::FreelyMutableState for #struct_name #ty_generics #where_clause {}
     }
     .into()
 }
@@ -0,0 +1,217 @@
+//! Handling of the errors returned by fallible systems and commands.
+//!
+//! Systems that return a [`Result`] can be added to a [`Schedule`](crate::schedule::Schedule)
+//! like any other system, and fallible commands can be queued with
+//! [`Commands::add_fallible`](crate::system::Commands::add_fallible). When they fail, their error
+//! is passed to the [`ErrorHandler`] of the [`World`](crate::world::World) along with an
+//! [`ErrorContext`] naming the failing system or command.
+//!
+//! The default handler is [`panic`], which matches the behavior of commands that cannot be
+//! applied, such as inserting a component into a despawned entity. It can be replaced with
+//! [`World::set_error_handler`](crate::world::World::set_error_handler):
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::error::{self, BoxedError};
+//!
+//! fn fallible_system() -> Result<(), BoxedError> {
+//!     Err("something went wrong".into())
+//! }
+//!
+//! let mut world = World::new();
+//! // log errors instead of panicking
+//! world.set_error_handler(error::warn);
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(fallible_system);
+//! schedule.run(&mut world);
+//! ```
+
+use std::{borrow::Cow, error::Error, fmt};
+
+use bevy_utils::tracing::{error, warn};
+
+/// A type-erased error, that any error type implementing [`Error`] can be converted into.
+pub type BoxedError = Box<dyn Error + Send + Sync + 'static>;
+
+/// A function called with the errors returned by fallible systems and commands.
+///
+/// See the [module-level documentation](self) for the available handlers.
+pub type ErrorHandler = fn(BoxedError, ErrorContext);
+
+/// The kind of operation that returned an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorSource {
+    /// The error was returned by a system.
+    System,
+    /// The error was returned by a command.
+    Command,
+}
+
+/// Information about the system or command that returned an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Whether the error was returned by a system or a command.
+    pub source: ErrorSource,
+    /// The name of the failing system or command.
+    pub name: Cow<'static, str>,
+    /// The name of the system that queued the failing command, if it is known.
+    pub system: Option<Cow<'static, str>>,
+}
+
+impl ErrorContext {
+    /// Creates the context of an error returned by the system with the given `name`.
+    pub fn system(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            source: ErrorSource::System,
+            name: name.into(),
+            system: None,
+        }
+    }
+
+    /// Creates the context of an error returned by the command with the given `name`.
+    pub fn command(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            source: ErrorSource::Command,
+            name: name.into(),
+            system: None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            ErrorSource::System => write!(f, "system `{}`", self.name)?,
+            ErrorSource::Command => write!(f, "command `{}`", self.name)?,
+        }
+        if let Some(system) = &self.system {
+            write!(f, " queued by system `{system}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// An [`ErrorHandler`] that panics with the error. This is the default handler.
+pub fn panic(error: BoxedError, context: ErrorContext) {
+    panic!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] that logs the error at the `error` level.
+pub fn error(error: BoxedError, context: ErrorContext) {
+    error!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] that logs the error at the `warn` level.
+pub fn warn(error: BoxedError, context: ErrorContext) {
+    warn!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] that silently ignores the error.
+pub fn ignore(_error: BoxedError, _context: ErrorContext) {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        prelude::{Commands, IntoFallibleSystem, IntoSystemConfigs, Schedule, World},
+        schedule::ExecutorKind,
+        world::EntityWorldMut,
+    };
+
+    use super::{BoxedError, ErrorContext, ErrorSource};
+
+    thread_local! {
+        static ERRORS: RefCell<Vec<(String, ErrorContext)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(error: BoxedError, context: ErrorContext) {
+        ERRORS.with(|errors| errors.borrow_mut().push((error.to_string(), context)));
+    }
+
+    fn take_errors() -> Vec<(String, ErrorContext)> {
+        ERRORS.with(|errors| errors.take())
+    }
+
+    #[derive(Component)]
+    struct A;
+
+    fn fails() -> Result<(), BoxedError> {
+        Err("failed".into())
+    }
+
+    fn succeeds() -> Result<(), std::fmt::Error> {
+        Ok(())
+    }
+
+    #[test]
+    fn fallible_systems() {
+        let mut world = World::new();
+        world.set_error_handler(record);
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems((fails, succeeds.after(fails)));
+        schedule.run(&mut world);
+
+        let errors = take_errors();
+        assert_eq!(errors.len(), 1);
+        let (error, context) = &errors[0];
+        assert_eq!(error, "failed");
+        assert_eq!(context.source, ErrorSource::System);
+        assert!(context.name.ends_with("::fails"));
+        assert_eq!(context.system, None);
+    }
+
+    fn queue_commands(mut commands: Commands) {
+        let entity = commands.spawn_empty().id();
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(A);
+        commands.entity(entity).add(|_: EntityWorldMut| {});
+        commands.add_fallible(|_: &mut World| Err::<(), _>("failed"));
+        commands.add_fallible(|_: &mut World| Ok::<(), BoxedError>(()));
+    }
+
+    #[test]
+    fn failed_commands() {
+        let mut world = World::new();
+        world.set_error_handler(record);
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(queue_commands);
+        schedule.run(&mut world);
+        // commands applied outside of a system aren't attributed to any system
+        world
+            .commands()
+            .add_fallible(|_: &mut World| Err::<(), _>("failed"));
+        world.flush_commands();
+
+        let errors = take_errors();
+        let contexts: Vec<_> = errors.iter().map(|(_, context)| context).collect();
+        assert_eq!(contexts.len(), 4);
+        assert!(contexts
+            .iter()
+            .all(|context| context.source == ErrorSource::Command));
+        assert_eq!(contexts[0].name, "insert");
+        assert!(errors[0].0.contains("error[B0003]"));
+        assert!(contexts[1].name.contains("{{closure}}"));
+        assert_eq!(errors[2].0, "failed");
+        assert!(contexts[..3].iter().all(|context| context
+            .system
+            .as_deref()
+            .is_some_and(|system| system.ends_with("::queue_commands"))));
+        assert_eq!(contexts[3].system, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Encountered an error in system")]
+    fn panics_by_default() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(fails.fallible());
+        schedule.run(&mut world);
+    }
+}
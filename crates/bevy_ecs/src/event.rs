@@ -1,7 +1,9 @@
 //! Event handling types.
 
 use crate as bevy_ecs;
+use crate::component::Component;
 use crate::system::{Local, Res, ResMut, Resource, SystemParam};
+use crate::traversal::Traversal;
 pub use bevy_ecs_macros::Event;
 use bevy_ecs_macros::SystemSet;
 use bevy_utils::detailed_trace;
@@ -18,8 +20,44 @@ use std::{
 /// A type that can be stored in an [`Events<E>`] resource
 /// You can conveniently access events using the [`EventReader`] and [`EventWriter`] system parameter.
 ///
+/// Events can also be triggered on a [`World`](crate::world::World), which immediately runs any
+/// [`Observer`](crate::observer::Observer) watching for that event type.
+///
+/// Events are [`Component`]s so that each event type has a [`ComponentId`](crate::component::ComponentId)
+/// to identify it within the ECS. They are generally not inserted on entities directly.
+/// Deriving [`Event`] also derives [`Component`].
+///
+/// Events triggered at an entity can propagate to other entities, following the path described by
+/// [`Event::Traversal`]. The traversal and whether the event propagates by default can be set with the
+/// `#[event(traversal = ..., auto_propagate)]` attribute when deriving [`Event`]:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::traversal::Traversal;
+/// #[derive(Component)]
+/// struct Owner(Entity);
+///
+/// impl Traversal for &Owner {
+///     fn traverse(owner: &Owner) -> Option<Entity> {
+///         Some(owner.0)
+///     }
+/// }
+///
+/// #[derive(Event)]
+/// #[event(traversal = &'static Owner, auto_propagate)]
+/// struct Damaged;
+/// ```
+///
 /// Events must be thread-safe.
-pub trait Event: Send + Sync + 'static {}
+pub trait Event: Component {
+    /// The [`Traversal`] that describes which entity this event propagates to next,
+    /// when [propagation](crate::observer::Trigger::propagate) is enabled.
+    type Traversal: Traversal;
+
+    /// When true, this event will always attempt to propagate when triggered at an entity,
+    /// without requiring a call to [`Trigger::propagate`](crate::observer::Trigger::propagate).
+    const AUTO_PROPAGATE: bool = false;
+}
 
 /// An `EventId` uniquely identifies an event stored in a specific [`World`].
 ///
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{component::ComponentId, prelude::*};
 
-use super::{FilteredAccess, QueryData, QueryFilter};
+use super::{ChangeFilterTerm, FilteredAccess, QueryData, QueryFilter};
 
 /// Builder struct to create [`QueryState`] instances at runtime.
 ///
@@ -34,6 +34,7 @@ use super::{FilteredAccess, QueryData, QueryFilter};
 ///```
 pub struct QueryBuilder<'w, D: QueryData = (), F: QueryFilter = ()> {
     access: FilteredAccess<ComponentId>,
+    change_filters: Vec<ChangeFilterTerm>,
     world: &'w mut World,
     or: bool,
     first: bool,
@@ -61,6 +62,7 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
 
         Self {
             access,
+            change_filters: Vec::new(),
             world,
             or: false,
             first: false,
@@ -153,11 +155,42 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
         self
     }
 
+    /// Adds [`Added<T>`] to the filter of self from a runtime [`ComponentId`].
+    ///
+    /// The filter type of the query must contain a [`DynamicChangeFilter`](super::DynamicChangeFilter),
+    /// which checks this term when iterating.
+    pub fn added_id(&mut self, id: ComponentId) -> &mut Self {
+        self.with_id(id);
+        self.access.add_read(id);
+        self.change_filters.push(ChangeFilterTerm::Added(id));
+        self
+    }
+
+    /// Adds [`Changed<T>`] to the filter of self from a runtime [`ComponentId`].
+    ///
+    /// The filter type of the query must contain a [`DynamicChangeFilter`](super::DynamicChangeFilter),
+    /// which checks this term when iterating.
+    pub fn changed_id(&mut self, id: ComponentId) -> &mut Self {
+        self.with_id(id);
+        self.access.add_read(id);
+        self.change_filters.push(ChangeFilterTerm::Changed(id));
+        self
+    }
+
     /// Takes a function over mutable access to a [`QueryBuilder`], calls that function
     /// on an empty builder and then adds all accesses from that builder to self as optional.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function adds change detection terms with [`Self::added_id`] or
+    /// [`Self::changed_id`], which can't be optional.
     pub fn optional(&mut self, f: impl Fn(&mut QueryBuilder)) -> &mut Self {
         let mut builder = QueryBuilder::new(self.world);
         f(&mut builder);
+        assert!(
+            builder.change_filters.is_empty(),
+            "Change detection terms can't be optional."
+        );
         self.access.extend_access(builder.access());
         self
     }
@@ -170,7 +203,9 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
         let mut builder = QueryBuilder::new(self.world);
         f(&mut builder);
         let access = builder.access().clone();
+        let mut change_filters = std::mem::take(&mut builder.change_filters);
         self.extend_access(access);
+        self.change_filters.append(&mut change_filters);
         self
     }
 
@@ -195,11 +230,20 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
     /// // is equivalent to
     /// QueryBuilder::<Entity>::new(&mut world).filter::<Or<(With<A>, With<B>)>>();
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function adds change detection terms with [`Self::added_id`] or
+    /// [`Self::changed_id`], which are always combined with the other terms of the query.
     pub fn or(&mut self, f: impl Fn(&mut QueryBuilder)) -> &mut Self {
         let mut builder = QueryBuilder::new(self.world);
         builder.or = true;
         builder.first = true;
         f(&mut builder);
+        assert!(
+            builder.change_filters.is_empty(),
+            "Change detection terms can't be used in an or expression."
+        );
         self.access.extend(builder.access());
         self
     }
@@ -209,6 +253,11 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
         &self.access
     }
 
+    /// Returns the change detection terms added with [`Self::added_id`] and [`Self::changed_id`].
+    pub fn change_filters(&self) -> &[ChangeFilterTerm] {
+        &self.change_filters
+    }
+
     /// Transmute the existing builder adding required accesses.
     /// This will maintain all exisiting accesses.
     ///
@@ -254,6 +303,7 @@ mod tests {
     use crate::world::FilteredEntityRef;
 
     use super::QueryBuilder;
+    use crate::query::DynamicChangeFilter;
 
     #[derive(Component, PartialEq, Debug)]
     struct A(usize);
@@ -264,6 +314,10 @@ mod tests {
     #[derive(Component, PartialEq, Debug)]
     struct C(usize);
 
+    #[derive(Component, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct S(usize);
+
     #[test]
     fn builder_with_without_static() {
         let mut world = World::new();
@@ -398,4 +452,61 @@ mod tests {
             assert_eq!(1, b.deref::<B>().0);
         }
     }
+
+    #[test]
+    fn builder_change_filters_dynamic() {
+        let mut world = World::new();
+        let entity_a = world.spawn((A(0), S(0))).id();
+        let entity_b = world.spawn((A(1), B(0))).id();
+        let component_id_a = world.init_component::<A>();
+        let component_id_s = world.init_component::<S>();
+
+        let mut changed_a = QueryBuilder::<Entity, DynamicChangeFilter>::new(&mut world)
+            .changed_id(component_id_a)
+            .build();
+        let mut added_s = QueryBuilder::<Entity, DynamicChangeFilter>::new(&mut world)
+            .added_id(component_id_s)
+            .build();
+        assert_eq!(2, changed_a.iter(&world).count());
+        assert_eq!(entity_a, added_s.single(&world));
+
+        world.clear_trackers();
+        assert_eq!(0, changed_a.iter(&world).count());
+        assert_eq!(0, added_s.iter(&world).count());
+
+        world.get_mut::<A>(entity_b).unwrap().0 = 2;
+        world.get_mut::<S>(entity_a).unwrap().0 = 1;
+        assert_eq!(entity_b, changed_a.single(&world));
+        assert_eq!(0, added_s.iter(&world).count());
+
+        let entity_c = world.spawn(S(0)).id();
+        assert_eq!(entity_c, added_s.single(&world));
+    }
+
+    #[test]
+    fn builder_change_filters_in_tuple() {
+        let mut world = World::new();
+        world.spawn((A(0), B(0)));
+        let entity = world.spawn(A(1)).id();
+        let component_id_a = world.init_component::<A>();
+        world.clear_trackers();
+        world.get_mut::<A>(entity).unwrap().0 = 2;
+
+        let mut query = QueryBuilder::<&A, (Without<B>, DynamicChangeFilter)>::new(&mut world)
+            .and(|builder| {
+                builder.changed_id(component_id_a);
+            })
+            .build();
+        assert_eq!(&A(2), query.single(&world));
+    }
+
+    #[test]
+    #[should_panic]
+    fn builder_change_filters_without_dynamic_filter() {
+        let mut world = World::new();
+        let component_id_a = world.init_component::<A>();
+        QueryBuilder::<Entity>::new(&mut world)
+            .changed_id(component_id_a)
+            .build();
+    }
 }
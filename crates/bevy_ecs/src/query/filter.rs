@@ -91,6 +91,15 @@ pub trait QueryFilter: WorldQuery {
         entity: Entity,
         table_row: TableRow,
     ) -> bool;
+
+    /// Gives the filter the [`ChangeFilterTerm`]s added by the
+    /// [`QueryBuilder`](crate::query::QueryBuilder) the query is built with.
+    ///
+    /// Returns `true` if the filter applies the terms, which only [`DynamicChangeFilter`] and
+    /// tuples containing it do.
+    fn set_change_filters(_state: &mut Self::State, _terms: &[ChangeFilterTerm]) -> bool {
+        false
+    }
 }
 
 /// Filter that selects entities with a component `T`.
@@ -496,6 +505,11 @@ macro_rules! impl_tuple_query_filter {
                 let ($($name,)*) = fetch;
                 true $(&& $name::filter_fetch($name, _entity, _table_row))*
             }
+
+            fn set_change_filters(state: &mut Self::State, terms: &[ChangeFilterTerm]) -> bool {
+                let ($($name,)*) = state;
+                false $(| $name::set_change_filters($name, terms))*
+            }
         }
 
     };
@@ -898,6 +912,224 @@ impl<T: Component> QueryFilter for Changed<T> {
     }
 }
 
+/// A change detection term of a [`DynamicChangeFilter`], on a component known at runtime.
+///
+/// Terms are added to a query with [`QueryBuilder::added_id`](crate::query::QueryBuilder::added_id)
+/// and [`QueryBuilder::changed_id`](crate::query::QueryBuilder::changed_id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeFilterTerm {
+    /// Only retains results where the component was added after the system last ran, like [`Added`].
+    Added(ComponentId),
+    /// Only retains results where the component was added or mutably dereferenced after the system
+    /// last ran, like [`Changed`].
+    Changed(ComponentId),
+}
+
+impl ChangeFilterTerm {
+    /// Returns the id of the component checked by this term.
+    pub fn id(&self) -> ComponentId {
+        match *self {
+            ChangeFilterTerm::Added(id) | ChangeFilterTerm::Changed(id) => id,
+        }
+    }
+}
+
+/// A filter on components only known at runtime, retaining the results matching all of its
+/// [`ChangeFilterTerm`]s.
+///
+/// This is the dynamic counterpart of [`Added`] and [`Changed`]. The terms are given by the
+/// [`QueryBuilder`](crate::query::QueryBuilder) the query is built with, which must have this
+/// filter in its filter type, either directly or in a tuple.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::DynamicChangeFilter;
+/// #
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// #
+/// let mut world = World::new();
+/// let health = world.init_component::<Health>();
+/// let entity = world.spawn(Health(10)).id();
+///
+/// let mut query = QueryBuilder::<Entity, DynamicChangeFilter>::new(&mut world)
+///     .changed_id(health)
+///     .build();
+/// assert_eq!(query.single(&world), entity);
+///
+/// world.clear_trackers();
+/// assert!(query.get_single(&world).is_err());
+/// ```
+///
+/// # Time complexity
+///
+/// Like [`Changed`], this filter checks the ticks of every entity matched by the query.
+pub struct DynamicChangeFilter;
+
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct DynamicChangeFetch<'w> {
+    world: UnsafeWorldCell<'w>,
+    terms: Vec<(ChangeFilterTerm, DynamicChangeTicks<'w>)>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+#[derive(Clone, Copy)]
+enum DynamicChangeTicks<'w> {
+    Table(ThinSlicePtr<'w, UnsafeCell<Tick>>),
+    SparseSet(&'w ComponentSparseSet),
+}
+
+impl<'w> DynamicChangeFetch<'w> {
+    /// # Safety
+    ///
+    /// `archetype` must contain every component of `terms`, and `table` must be the table of
+    /// `archetype`.
+    unsafe fn set_terms(&mut self, terms: &[ChangeFilterTerm], table: &'w Table) {
+        self.terms.clear();
+        for &term in terms {
+            let ticks = match table.get_column(term.id()) {
+                Some(column) => DynamicChangeTicks::Table(
+                    match term {
+                        ChangeFilterTerm::Added(_) => Column::get_added_ticks_slice(column),
+                        ChangeFilterTerm::Changed(_) => Column::get_changed_ticks_slice(column),
+                    }
+                    .into(),
+                ),
+                None => DynamicChangeTicks::SparseSet(
+                    self.world
+                        .storages()
+                        .sparse_sets
+                        .get(term.id())
+                        .debug_checked_unwrap(),
+                ),
+            };
+            self.terms.push((term, ticks));
+        }
+    }
+}
+
+/// SAFETY:
+/// `fetch` accesses the ticks of the components of its terms in a readonly way.
+/// This is sound because [`QueryBuilder::added_id`](crate::query::QueryBuilder::added_id) and
+/// [`QueryBuilder::changed_id`](crate::query::QueryBuilder::changed_id) add read access for those
+/// components, and `update_component_access` does so too.
+/// This is sound because `matches_component_set` returns whether the set contains all those
+/// components.
+unsafe impl WorldQuery for DynamicChangeFilter {
+    type Item<'w> = bool;
+    type Fetch<'w> = DynamicChangeFetch<'w>;
+    type State = Vec<ChangeFilterTerm>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Vec<ChangeFilterTerm>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        DynamicChangeFetch {
+            world,
+            terms: Vec::with_capacity(state.len()),
+            last_run,
+            this_run,
+        }
+    }
+
+    const IS_DENSE: bool = false;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Vec<ChangeFilterTerm>,
+        _archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        fetch.set_terms(state, table);
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Vec<ChangeFilterTerm>,
+        table: &'w Table,
+    ) {
+        fetch.set_terms(state, table);
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        fetch.terms.iter().all(|&(term, ticks)| {
+            let tick = match (term, ticks) {
+                (_, DynamicChangeTicks::Table(ticks)) => ticks.get(table_row.as_usize()),
+                (ChangeFilterTerm::Added(_), DynamicChangeTicks::SparseSet(sparse_set)) => {
+                    sparse_set.get_added_tick(entity).debug_checked_unwrap()
+                }
+                (ChangeFilterTerm::Changed(_), DynamicChangeTicks::SparseSet(sparse_set)) => {
+                    sparse_set.get_changed_tick(entity).debug_checked_unwrap()
+                }
+            };
+            tick.deref().is_newer_than(fetch.last_run, fetch.this_run)
+        })
+    }
+
+    #[inline]
+    fn update_component_access(
+        state: &Vec<ChangeFilterTerm>,
+        access: &mut FilteredAccess<ComponentId>,
+    ) {
+        for term in state {
+            if access.access().has_write(term.id()) {
+                panic!("DynamicChangeFilter conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.");
+            }
+            access.add_read(term.id());
+        }
+    }
+
+    fn init_state(_world: &mut World) -> Vec<ChangeFilterTerm> {
+        Vec::new()
+    }
+
+    fn get_state(_world: &World) -> Option<Vec<ChangeFilterTerm>> {
+        Some(Vec::new())
+    }
+
+    fn matches_component_set(
+        state: &Vec<ChangeFilterTerm>,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        state.iter().all(|term| set_contains_id(term.id()))
+    }
+}
+
+impl QueryFilter for DynamicChangeFilter {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        Self::fetch(fetch, entity, table_row)
+    }
+
+    fn set_change_filters(state: &mut Self::State, terms: &[ChangeFilterTerm]) -> bool {
+        state.clear();
+        state.extend_from_slice(terms);
+        true
+    }
+}
+
 /// A marker trait to indicate that the filter works at an archetype level.
 ///
 /// This is needed to implement [`ExactSizeIterator`] for
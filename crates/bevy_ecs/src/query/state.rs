@@ -161,8 +161,13 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// Creates a new [`QueryState`] from a given [`QueryBuilder`] and inherits it's [`FilteredAccess`].
     pub fn from_builder(builder: &mut QueryBuilder<D, F>) -> Self {
         let mut fetch_state = D::init_state(builder.world_mut());
-        let filter_state = F::init_state(builder.world_mut());
+        let mut filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
+        let change_filters = builder.change_filters();
+        assert!(
+            F::set_change_filters(&mut filter_state, change_filters) || change_filters.is_empty(),
+            "The change detection terms of a QueryBuilder need a DynamicChangeFilter in the filter of the query."
+        );
 
         let mut state = Self {
             world_id: builder.world().id(),
@@ -1,7 +1,7 @@
 use crate::prelude::Mut;
 use crate::reflect::AppTypeRegistry;
 use crate::system::{Command, EntityCommands, Resource};
-use crate::{entity::Entity, reflect::ReflectComponent, world::World};
+use crate::{entity::Entity, error::ErrorContext, reflect::ReflectComponent, world::World};
 use bevy_reflect::{Reflect, TypeRegistry};
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -15,7 +15,8 @@ pub trait ReflectCommandExt {
     ///
     /// # Panics
     ///
-    /// - If the entity doesn't exist.
+    /// - If the entity doesn't exist, and the [`ErrorHandler`](crate::error::ErrorHandler) of the
+    ///   [`World`] panics, which it does by default.
     /// - If [`AppTypeRegistry`] does not have the reflection data for the given [`Component`](crate::component::Component).
     /// - If the component data is invalid. See [`Reflect::apply`] for further details.
     /// - If [`AppTypeRegistry`] is not present in the [`World`].
@@ -194,7 +195,11 @@ fn insert_reflect(
         .expect("component should represent a type.");
     let type_path = type_info.type_path();
     let Some(mut entity) = world.get_entity_mut(entity) else {
-        panic!("error[B0003]: Could not insert a reflected component (of type {type_path}) for entity {entity:?} because it doesn't exist in this World.");
+        world.handle_error(
+            format!("error[B0003]: Could not insert a reflected component (of type {type_path}) for entity {entity:?} because it doesn't exist in this World.").into(),
+            ErrorContext::command("insert_reflect"),
+        );
+        return;
     };
     let Some(type_registration) = type_registry.get_with_type_path(type_path) else {
         panic!("Could not get type registration (for component type {type_path}) because it doesn't exist in the TypeRegistry.");
@@ -4,13 +4,17 @@ use std::ops::{Deref, DerefMut};
 
 use crate as bevy_ecs;
 use crate::{entity::Entity, system::Resource};
-use bevy_reflect::{impl_reflect_value, ReflectDeserialize, ReflectSerialize, TypeRegistryArc};
+use bevy_reflect::{
+    func::FunctionRegistryArc, impl_reflect_value, ReflectDeserialize, ReflectSerialize,
+    TypeRegistryArc,
+};
 
 mod bundle;
 mod component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
+mod query;
 mod resource;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
@@ -18,6 +22,7 @@ pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::ReflectMapEntities;
+pub use query::{ReflectTerms, ReflectTermsError, ReflectedEntity, ReflectedEntityMut};
 pub use resource::{ReflectResource, ReflectResourceFns};
 
 /// A [`Resource`] storing [`TypeRegistry`](bevy_reflect::TypeRegistry) for
@@ -41,4 +46,25 @@ impl DerefMut for AppTypeRegistry {
     }
 }
 
+/// A [`Resource`] storing [`FunctionRegistry`](bevy_reflect::func::FunctionRegistry) for
+/// functions that can be called by name through reflection, for a whole app.
+#[derive(Resource, Clone, Default)]
+pub struct AppFunctionRegistry(pub FunctionRegistryArc);
+
+impl Deref for AppFunctionRegistry {
+    type Target = FunctionRegistryArc;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AppFunctionRegistry {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl_reflect_value!((in bevy_ecs) Entity(Hash, PartialEq, Serialize, Deserialize));
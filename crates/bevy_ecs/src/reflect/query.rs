@@ -0,0 +1,356 @@
+//! Definitions for reflected access to the items of dynamic queries.
+
+use crate::{
+    change_detection::Mut,
+    component::ComponentId,
+    entity::Entity,
+    world::{FilteredEntityMut, FilteredEntityRef, World, WorldId},
+};
+use bevy_reflect::{Reflect, ReflectFromPtr, TypeRegistry};
+use thiserror::Error;
+
+/// Reflected access to some components of the items of a query built with a
+/// [`QueryBuilder`](crate::query::QueryBuilder).
+///
+/// The [`FilteredEntityRef`] and [`FilteredEntityMut`] items of such queries only give untyped
+/// pointers to their components. [`ReflectTerms`] turns them into `&dyn Reflect` and
+/// `Mut<dyn Reflect>` with the [`ReflectFromPtr`] registered for each component, which lets scripts
+/// work with components they only know at runtime.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::ReflectTerms;
+/// # use bevy_ecs::world::FilteredEntityRef;
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// #
+/// #[derive(Component, Reflect)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let mut world = World::new();
+/// world.spawn(Health(10));
+/// let health = world.init_component::<Health>();
+///
+/// let terms = ReflectTerms::new(&world, &registry, [health]).unwrap();
+/// let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+///     .ref_id(health)
+///     .build();
+///
+/// for entity in terms.reflect_iter(query.iter(&world)) {
+///     let health = entity.get(0).unwrap();
+///     assert!(health.is::<Health>());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReflectTerms {
+    world_id: WorldId,
+    terms: Vec<(ComponentId, ReflectFromPtr)>,
+}
+
+/// An error that occurs when creating [`ReflectTerms`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ReflectTermsError {
+    /// The component doesn't exist in the world.
+    #[error("The component {0:?} does not exist in the world")]
+    MissingComponent(ComponentId),
+    /// The component isn't a Rust type, so it has no type registration.
+    #[error("The component {0} is not a Rust type")]
+    NotRustType(String),
+    /// The type of the component doesn't have [`ReflectFromPtr`] registered.
+    #[error("The component {0} does not have `ReflectFromPtr` registered")]
+    NotReflected(String),
+    /// The [`ReflectFromPtr`] registered for the type of the component was created for another
+    /// type, so it cannot read the component.
+    #[error("The `ReflectFromPtr` registered for the component {0} was created for another type")]
+    MismatchedType(String),
+}
+
+impl ReflectTerms {
+    /// Creates reflected terms for the components of `ids` in `world`, which are then accessed by
+    /// index.
+    pub fn new(
+        world: &World,
+        registry: &TypeRegistry,
+        ids: impl IntoIterator<Item = ComponentId>,
+    ) -> Result<Self, ReflectTermsError> {
+        let terms = ids
+            .into_iter()
+            .map(|id| {
+                let info = world
+                    .components()
+                    .get_info(id)
+                    .ok_or(ReflectTermsError::MissingComponent(id))?;
+                let type_id = info
+                    .type_id()
+                    .ok_or_else(|| ReflectTermsError::NotRustType(info.name().to_string()))?;
+                let reflect_from_ptr = registry
+                    .get_type_data::<ReflectFromPtr>(type_id)
+                    .ok_or_else(|| ReflectTermsError::NotReflected(info.name().to_string()))?;
+                if reflect_from_ptr.type_id() != type_id {
+                    return Err(ReflectTermsError::MismatchedType(info.name().to_string()));
+                }
+                Ok((id, reflect_from_ptr.clone()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            world_id: world.id(),
+            terms,
+        })
+    }
+
+    /// Returns the number of terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns `true` if there are no terms.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the ids of the components of the terms, in order.
+    pub fn component_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.terms.iter().map(|(id, _)| *id)
+    }
+
+    /// Returns the component of the term at `index` as a reflected reference.
+    ///
+    /// Returns `None` if there is no such term, or if `entity` doesn't have read access to the
+    /// component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` doesn't belong to the world these terms were created from.
+    pub fn get<'w>(&self, entity: &FilteredEntityRef<'w>, index: usize) -> Option<&'w dyn Reflect> {
+        self.validate_world(entity.world_id());
+        let (id, reflect_from_ptr) = self.terms.get(index)?;
+        let ptr = entity.get_by_id(*id)?;
+        // SAFETY: `reflect_from_ptr` was checked to be made for the type of the component `id` of
+        // this world.
+        Some(unsafe { reflect_from_ptr.as_reflect(ptr) })
+    }
+
+    /// Returns the component of the term at `index` as a mutable reflected reference, which
+    /// triggers change detection when dereferenced mutably.
+    ///
+    /// Returns `None` if there is no such term, or if `entity` doesn't have write access to the
+    /// component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` doesn't belong to the world these terms were created from.
+    pub fn get_mut<'a>(
+        &self,
+        entity: &'a mut FilteredEntityMut,
+        index: usize,
+    ) -> Option<Mut<'a, dyn Reflect>> {
+        self.validate_world(entity.world_id());
+        let (id, reflect_from_ptr) = self.terms.get(index)?;
+        let component = entity.get_mut_by_id(*id)?;
+        // SAFETY: `reflect_from_ptr` was checked to be made for the type of the component `id` of
+        // this world.
+        Some(component.map_unchanged(|ptr| unsafe { reflect_from_ptr.as_reflect_mut(ptr) }))
+    }
+
+    /// Returns the components of all the terms as reflected references, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` doesn't belong to the world these terms were created from.
+    pub fn iter<'a, 'w>(
+        &'a self,
+        entity: &'a FilteredEntityRef<'w>,
+    ) -> impl Iterator<Item = Option<&'w dyn Reflect>> + 'a {
+        (0..self.len()).map(move |index| self.get(entity, index))
+    }
+
+    /// Adapts an iterator over the items of a query, such as [`QueryState::iter`], to give the
+    /// reflected components of each item.
+    ///
+    /// [`QueryState::iter`]: crate::query::QueryState::iter
+    pub fn reflect_iter<'a, 'w>(
+        &'a self,
+        entities: impl IntoIterator<Item = FilteredEntityRef<'w>> + 'a,
+    ) -> impl Iterator<Item = ReflectedEntity<'a, 'w>> + 'a {
+        entities.into_iter().map(move |entity| ReflectedEntity {
+            terms: self,
+            entity,
+        })
+    }
+
+    /// Adapts an iterator over the mutable items of a query, such as [`QueryState::iter_mut`], to
+    /// give the reflected components of each item.
+    ///
+    /// [`QueryState::iter_mut`]: crate::query::QueryState::iter_mut
+    pub fn reflect_iter_mut<'a, 'w>(
+        &'a self,
+        entities: impl IntoIterator<Item = FilteredEntityMut<'w>> + 'a,
+    ) -> impl Iterator<Item = ReflectedEntityMut<'a, 'w>> + 'a {
+        entities.into_iter().map(move |entity| ReflectedEntityMut {
+            terms: self,
+            entity,
+        })
+    }
+
+    #[inline]
+    fn validate_world(&self, world_id: WorldId) {
+        assert_eq!(
+            self.world_id, world_id,
+            "Encountered a mismatched World. These ReflectTerms were created from {:?}, but were used with {:?}.",
+            self.world_id, world_id
+        );
+    }
+}
+
+/// An item of [`ReflectTerms::reflect_iter`], giving reflected access to its components.
+pub struct ReflectedEntity<'a, 'w> {
+    terms: &'a ReflectTerms,
+    entity: FilteredEntityRef<'w>,
+}
+
+impl<'a, 'w> ReflectedEntity<'a, 'w> {
+    /// Returns the [ID](Entity) of the entity.
+    pub fn id(&self) -> Entity {
+        self.entity.id()
+    }
+
+    /// Returns the underlying item of the query.
+    pub fn entity(&self) -> &FilteredEntityRef<'w> {
+        &self.entity
+    }
+
+    /// Returns the component of the term at `index`, see [`ReflectTerms::get`].
+    pub fn get(&self, index: usize) -> Option<&'w dyn Reflect> {
+        self.terms.get(&self.entity, index)
+    }
+
+    /// Returns the components of all the terms, see [`ReflectTerms::iter`].
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'w dyn Reflect>> + '_ {
+        self.terms.iter(&self.entity)
+    }
+}
+
+/// An item of [`ReflectTerms::reflect_iter_mut`], giving mutable reflected access to its
+/// components.
+pub struct ReflectedEntityMut<'a, 'w> {
+    terms: &'a ReflectTerms,
+    entity: FilteredEntityMut<'w>,
+}
+
+impl<'a, 'w> ReflectedEntityMut<'a, 'w> {
+    /// Returns the [ID](Entity) of the entity.
+    pub fn id(&self) -> Entity {
+        self.entity.id()
+    }
+
+    /// Returns the underlying item of the query.
+    pub fn entity(&mut self) -> &mut FilteredEntityMut<'w> {
+        &mut self.entity
+    }
+
+    /// Returns the component of the term at `index`, see [`ReflectTerms::get`].
+    pub fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.terms
+            .get(&FilteredEntityRef::from(&self.entity), index)
+    }
+
+    /// Returns the component of the term at `index` mutably, see [`ReflectTerms::get_mut`].
+    pub fn get_mut(&mut self, index: usize) -> Option<Mut<'_, dyn Reflect>> {
+        self.terms.get_mut(&mut self.entity, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReflectTerms, ReflectTermsError};
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::query::DynamicChangeFilter;
+    use crate::world::{FilteredEntityMut, FilteredEntityRef};
+    use bevy_reflect::{FromType, Reflect, ReflectFromPtr, TypeRegistry};
+    use std::any::TypeId;
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[component(storage = "SparseSet")]
+    struct Armor(u32);
+
+    #[derive(Component)]
+    struct Unreflected;
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry.register::<Armor>();
+        registry
+    }
+
+    #[test]
+    fn reflect_query_items() {
+        let registry = registry();
+        let mut world = World::new();
+        let entity = world.spawn((Health(10), Armor(2))).id();
+        world.spawn(Health(5));
+        let health = world.init_component::<Health>();
+        let armor = world.init_component::<Armor>();
+
+        let terms = ReflectTerms::new(&world, &registry, [health, armor]).unwrap();
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(health)
+            .ref_id(armor)
+            .build();
+
+        let items: Vec<_> = terms.reflect_iter(query.iter(&world)).collect();
+        assert_eq!(1, items.len());
+        assert_eq!(entity, items[0].id());
+        let values: Vec<_> = items[0].iter().map(Option::unwrap).collect();
+        assert!(values[0].reflect_partial_eq(&Health(10)).unwrap());
+        assert!(values[1].reflect_partial_eq(&Armor(2)).unwrap());
+        assert!(items[0].get(2).is_none());
+    }
+
+    #[test]
+    fn reflect_changed_query_items_mut() {
+        let registry = registry();
+        let mut world = World::new();
+        world.spawn(Health(10));
+        let entity = world.spawn(Health(5)).id();
+        let health = world.init_component::<Health>();
+        world.clear_trackers();
+        world.get_mut::<Health>(entity).unwrap().0 = 4;
+
+        let terms = ReflectTerms::new(&world, &registry, [health]).unwrap();
+        let mut query = QueryBuilder::<FilteredEntityMut, DynamicChangeFilter>::new(&mut world)
+            .mut_id(health)
+            .changed_id(health)
+            .build();
+
+        for mut item in terms.reflect_iter_mut(query.iter_mut(&mut world)) {
+            assert_eq!(entity, item.id());
+            item.get_mut(0).unwrap().apply(&Health(0));
+        }
+        assert_eq!(&Health(0), world.get::<Health>(entity).unwrap());
+    }
+
+    #[test]
+    fn reflect_terms_errors() {
+        let mut registry = registry();
+        let mut world = World::new();
+        let unreflected = world.init_component::<Unreflected>();
+        let health = world.init_component::<Health>();
+
+        let error = ReflectTerms::new(&world, &registry, [unreflected]).err();
+        assert!(matches!(error, Some(ReflectTermsError::NotReflected(_))));
+
+        registry
+            .get_mut(TypeId::of::<Health>())
+            .unwrap()
+            .insert(<ReflectFromPtr as FromType<Armor>>::from_type());
+        let error = ReflectTerms::new(&world, &registry, [health]).err();
+        assert!(matches!(error, Some(ReflectTermsError::MismatchedType(_))));
+    }
+}
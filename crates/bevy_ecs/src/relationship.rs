@@ -0,0 +1,470 @@
+//! Relationships between entities that are kept consistent in both directions.
+//!
+//! A relationship is a pair of components: a [`Relationship`] stored on the "source" entity, which
+//! points to a single "target" entity, and a [`RelationshipTarget`] stored on the target, which
+//! holds the collection of every source pointing at it.
+//!
+//! Only the [`Relationship`] side should be edited by users. Inserting, replacing or removing it
+//! (or despawning its entity) automatically updates the [`RelationshipTarget`] of the target entity
+//! through [component hooks](crate::component::ComponentHooks). Removing the [`RelationshipTarget`]
+//! or despawning the target entity removes the [`Relationship`] from every source, or despawns
+//! the sources if the relationship is configured with `linked_spawn`.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::relationship::RelationshipTarget;
+//!
+//! #[derive(Component)]
+//! #[relationship(relationship_target = Children)]
+//! struct ChildOf(Entity);
+//!
+//! #[derive(Component)]
+//! #[relationship_target(relationship = ChildOf, linked_spawn)]
+//! struct Children(Vec<Entity>);
+//!
+//! let mut world = World::new();
+//! let parent = world.spawn_empty().id();
+//! let child = world.spawn(ChildOf(parent)).id();
+//! assert_eq!(world.get::<Children>(parent).unwrap().collection(), &vec![child]);
+//!
+//! // Despawning the parent also despawns its children, because of `linked_spawn`.
+//! world.despawn(parent);
+//! assert!(world.get_entity(child).is_none());
+//! ```
+
+use bevy_utils::{tracing::warn, EntityHashSet};
+
+use crate::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+
+/// A [`Component`] on a "source" entity that points to a "target" entity, which will
+/// automatically be tracked by the [`Relationship::RelationshipTarget`] component of the target.
+///
+/// This trait is usually implemented with `#[derive(Component)]` and the
+/// `#[relationship(relationship_target = ...)]` attribute, on a struct with a single [`Entity`] field.
+/// The derive registers the [`Relationship::on_insert`] and [`Relationship::on_replace`] hooks,
+/// so the component cannot define its own `on_insert` or `on_replace` hooks.
+///
+/// An entity cannot be related to itself: such a relationship is removed as soon as it is inserted.
+pub trait Relationship: Component + Sized {
+    /// The [`RelationshipTarget`] that tracks all sources pointing to a given target entity.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] this relationship points to.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that adds the source entity to its target's
+    /// [`Relationship::RelationshipTarget`].
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                std::any::type_name::<Self>(),
+                std::any::type_name::<Self>()
+            );
+            world.commands().add(move |world: &mut World| {
+                if let Some(mut entity) = world.get_entity_mut(entity) {
+                    entity.remove::<Self>();
+                }
+            });
+            return;
+        }
+        if let Some(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().add(entity);
+            } else {
+                // The target component has to be inserted, which is a structural change.
+                world.commands().add(move |world: &mut World| {
+                    add_source_to_target::<Self>(world, entity, target_entity);
+                });
+            }
+        } else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                std::any::type_name::<Self>(),
+                std::any::type_name::<Self>()
+            );
+            world.commands().add(move |world: &mut World| {
+                if let Some(mut entity) = world.get_entity_mut(entity) {
+                    entity.remove::<Self>();
+                }
+            });
+        }
+    }
+
+    /// The `on_replace` component hook that removes the source entity from its target's
+    /// [`Relationship::RelationshipTarget`].
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if let Some(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().remove(entity);
+                if relationship_target.is_empty() {
+                    world.commands().add(move |world: &mut World| {
+                        if let Some(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+                            // Another source may have been added before this command was applied.
+                            if target_entity_mut
+                                .get::<Self::RelationshipTarget>()
+                                .is_some_and(|target| target.is_empty())
+                            {
+                                target_entity_mut.remove::<Self::RelationshipTarget>();
+                            }
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of all source entities whose [`Relationship`]
+/// points to this entity.
+///
+/// This trait is usually implemented with `#[derive(Component)]` and the
+/// `#[relationship_target(relationship = ...)]` attribute, on a struct with a single field of a
+/// [`RelationshipSourceCollection`] type such as `Vec<Entity>`. Adding `linked_spawn` to the
+/// attribute despawns all sources when the target entity is despawned.
+///
+/// The collection is maintained by the hooks of the [`Relationship`] and should not be modified
+/// directly, which is why the mutable accessors are suffixed with `_risky`.
+pub trait RelationshipTarget: Component + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// The collection type that stores the source entities.
+    type Collection: RelationshipSourceCollection;
+
+    /// If true, despawning the target entity will also despawn all of its sources.
+    const LINKED_SPAWN: bool;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// Modifying the collection directly can leave the relationship in an inconsistent state:
+    /// insert or remove the [`RelationshipTarget::Relationship`] on the source entities instead.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// The collection must match the [`RelationshipTarget::Relationship`] components of the world,
+    /// see [`RelationshipTarget::collection_mut_risky`].
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// Iterates the source entities in this collection.
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of source entities in this collection.
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this collection contains no source entities.
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook that removes the [`RelationshipTarget::Relationship`] from
+    /// every source entity.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        let mut commands = world.commands();
+        for source in sources {
+            commands.add(move |world: &mut World| {
+                if let Some(mut source_mut) = world.get_entity_mut(source) {
+                    // The source may have been retargeted before this command was applied.
+                    if source_mut
+                        .get::<Self::Relationship>()
+                        .map(Relationship::get)
+                        == Some(entity)
+                    {
+                        source_mut.remove::<Self::Relationship>();
+                    }
+                }
+            });
+        }
+    }
+
+    /// The `on_despawn` component hook that despawns every source entity.
+    /// It is registered when [`RelationshipTarget::LINKED_SPAWN`] is true.
+    fn on_despawn(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources: Vec<Entity> = world.entity(entity).get::<Self>().unwrap().iter().collect();
+        let mut commands = world.commands();
+        for source in sources {
+            commands.add(move |world: &mut World| {
+                if let Some(source_mut) = world.get_entity_mut(source) {
+                    source_mut.despawn();
+                }
+            });
+        }
+    }
+}
+
+/// Adds `source` to the [`RelationshipTarget`] of `target`, inserting it if needed.
+fn add_source_to_target<R: Relationship>(world: &mut World, source: Entity, target: Entity) {
+    // The source may have been unlinked or retargeted before this command was applied.
+    if world.get::<R>(source).map(R::get) != Some(target) {
+        return;
+    }
+    let Some(mut target_entity_mut) = world.get_entity_mut(target) else {
+        return;
+    };
+    if let Some(mut relationship_target) = target_entity_mut.get_mut::<R::RelationshipTarget>() {
+        if !relationship_target.iter().any(|entity| entity == source) {
+            relationship_target.collection_mut_risky().add(source);
+        }
+    } else {
+        let mut collection =
+            <<R::RelationshipTarget as RelationshipTarget>::Collection>::with_capacity(1);
+        collection.add(source);
+        target_entity_mut.insert(R::RelationshipTarget::from_collection_risky(collection));
+    }
+}
+
+/// The internal [`Entity`] collection used by a [`RelationshipTarget`] component.
+pub trait RelationshipSourceCollection {
+    /// The iterator returned by [`RelationshipSourceCollection::iter`].
+    type SourceIter<'a>: Iterator<Item = Entity>
+    where
+        Self: 'a;
+
+    /// Creates a new empty collection with the given capacity.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Adds the given `entity` to the collection.
+    fn add(&mut self, entity: Entity);
+
+    /// Removes the given `entity` from the collection.
+    fn remove(&mut self, entity: Entity);
+
+    /// Iterates all entities in the collection.
+    fn iter(&self) -> Self::SourceIter<'_>;
+
+    /// Returns the number of entities in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns true if the collection contains no entities.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl RelationshipSourceCollection for Vec<Entity> {
+    type SourceIter<'a> = std::iter::Copied<std::slice::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        self.push(entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            Vec::remove(self, index);
+        }
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl RelationshipSourceCollection for EntityHashSet<Entity> {
+    type SourceIter<'a> = std::iter::Copied<bevy_utils::hashbrown::hash_set::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        EntityHashSet::with_capacity_and_hasher(capacity, Default::default())
+    }
+
+    fn add(&mut self, entity: Entity) {
+        self.insert(entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        EntityHashSet::remove(self, &entity);
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        EntityHashSet::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        EntityHashSet::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::relationship::{Relationship, RelationshipTarget};
+    use bevy_utils::EntityHashSet;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Children)]
+    struct ChildOf(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = ChildOf, linked_spawn)]
+    struct Children(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = TargetedBy)]
+    struct Targeting {
+        target: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Targeting)]
+    struct TargetedBy {
+        sources: EntityHashSet<Entity>,
+    }
+
+    fn children(world: &World, entity: Entity) -> Option<Vec<Entity>> {
+        world
+            .get::<Children>(entity)
+            .map(|children| children.iter().collect())
+    }
+
+    #[test]
+    fn insert_adds_source_to_target() {
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let a = world.spawn(ChildOf(parent)).id();
+        let b = world.spawn(ChildOf(parent)).id();
+        assert_eq!(children(&world, parent), Some(vec![a, b]));
+    }
+
+    #[test]
+    fn insert_batch_adds_all_sources_to_target() {
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let sources: Vec<Entity> = world
+            .spawn_batch([ChildOf(parent), ChildOf(parent), ChildOf(parent)])
+            .collect();
+        assert_eq!(children(&world, parent), Some(sources));
+    }
+
+    #[test]
+    fn replace_moves_source_to_new_target() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(a)).id();
+        let sibling = world.spawn(ChildOf(a)).id();
+
+        world.entity_mut(child).insert(ChildOf(b));
+        assert_eq!(children(&world, a), Some(vec![sibling]));
+        assert_eq!(children(&world, b), Some(vec![child]));
+
+        // Re-inserting the same relationship does not duplicate the source.
+        world.entity_mut(child).insert(ChildOf(b));
+        assert_eq!(children(&world, b), Some(vec![child]));
+    }
+
+    #[test]
+    fn remove_relationship_removes_empty_target() {
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let a = world.spawn(ChildOf(parent)).id();
+        let b = world.spawn(ChildOf(parent)).id();
+
+        world.entity_mut(a).remove::<ChildOf>();
+        assert_eq!(children(&world, parent), Some(vec![b]));
+
+        world.despawn(b);
+        assert!(world.get::<Children>(parent).is_none());
+    }
+
+    #[test]
+    fn remove_target_removes_relationships() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(Targeting { target }).id();
+        let b = world.spawn(Targeting { target }).id();
+        assert_eq!(world.get::<TargetedBy>(target).unwrap().len(), 2);
+
+        world.entity_mut(target).remove::<TargetedBy>();
+        assert!(world.get::<Targeting>(a).is_none());
+        assert!(world.get::<Targeting>(b).is_none());
+        assert!(world.get::<TargetedBy>(target).is_none());
+    }
+
+    #[test]
+    fn despawn_target_without_linked_spawn_keeps_sources() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let source = world.spawn(Targeting { target }).id();
+        assert!(world
+            .get::<TargetedBy>(target)
+            .unwrap()
+            .sources
+            .contains(&source));
+
+        world.despawn(target);
+        assert!(world.get_entity(source).is_some());
+        assert!(world.get::<Targeting>(source).is_none());
+    }
+
+    #[test]
+    fn despawn_target_with_linked_spawn_is_recursive() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(root)).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+        let unrelated = world.spawn_empty().id();
+
+        world.despawn(root);
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get_entity(grandchild).is_none());
+        assert!(world.get_entity(unrelated).is_some());
+    }
+
+    #[test]
+    fn self_relationship_is_removed() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        world.entity_mut(entity).insert(ChildOf(entity));
+        assert!(world.get::<ChildOf>(entity).is_none());
+        assert!(world.get::<Children>(entity).is_none());
+    }
+
+    #[test]
+    fn relationship_to_missing_entity_is_removed() {
+        let mut world = World::new();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let entity = world.spawn(ChildOf(missing)).id();
+        assert!(world.get::<ChildOf>(entity).is_none());
+    }
+
+    #[test]
+    fn relationship_from_and_get() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        assert_eq!(<Targeting as Relationship>::from(target).get(), target);
+        let source = world.spawn(<ChildOf as Relationship>::from(target)).id();
+        assert_eq!(world.get::<ChildOf>(source).unwrap().get(), target);
+        assert_eq!(children(&world, target), Some(vec![source]));
+    }
+}
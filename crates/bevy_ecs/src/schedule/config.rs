@@ -1,13 +1,14 @@
 use bevy_utils::all_tuples;
 
 use crate::{
+    error::BoxedError,
     schedule::{
         condition::{BoxedCondition, Condition},
         graph_utils::{Ambiguity, Dependency, DependencyKind, GraphInfo},
         set::{InternedSystemSet, IntoSystemSet, SystemSet},
         Chain,
     },
-    system::{BoxedSystem, IntoSystem, System},
+    system::{BoxedSystem, IntoFallibleSystem, IntoSystem, System},
 };
 
 fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
@@ -33,7 +34,11 @@ fn ambiguous_with(graph_info: &mut GraphInfo, set: InternedSystemSet) {
     }
 }
 
-impl<Marker, F> IntoSystemConfigs<Marker> for F
+/// A marker type used to distinguish systems returning `()` from systems returning a [`Result`].
+#[doc(hidden)]
+pub struct IsInfallibleSystem;
+
+impl<Marker, F> IntoSystemConfigs<(IsInfallibleSystem, Marker)> for F
 where
     F: IntoSystem<(), (), Marker>,
 {
@@ -42,6 +47,20 @@ where
     }
 }
 
+/// A marker type used to distinguish systems returning a [`Result`] from systems returning `()`.
+#[doc(hidden)]
+pub struct IsFallibleSystem;
+
+impl<Marker, F, E> IntoSystemConfigs<(IsFallibleSystem, E, Marker)> for F
+where
+    F: IntoSystem<(), Result<(), E>, Marker>,
+    E: Into<BoxedError> + 'static,
+{
+    fn into_configs(self) -> SystemConfigs {
+        SystemConfigs::new_system(Box::new(self.fallible()))
+    }
+}
+
 impl IntoSystemConfigs<()> for BoxedSystem<(), ()> {
     fn into_configs(self) -> SystemConfigs {
         SystemConfigs::new_system(self)
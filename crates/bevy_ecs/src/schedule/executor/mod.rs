@@ -1,8 +1,11 @@
 mod multi_threaded;
+mod profiler;
 mod simple;
 mod single_threaded;
 
 pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
+pub(super) use self::profiler::ExecutorProfile;
+pub use self::profiler::{ScheduleProfile, ScheduleProfiler, SystemProfile};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
 
@@ -25,6 +28,10 @@ pub(super) trait SystemExecutor: Send + Sync {
         world: &mut World,
     );
     fn set_apply_final_deferred(&mut self, value: bool);
+    /// Returns the measurements of the last run, if it was profiled by a [`ScheduleProfiler`].
+    fn profile(&self) -> Option<&ExecutorProfile> {
+        None
+    }
 }
 
 /// Specifies how a [`Schedule`](super::Schedule) will be run.
@@ -4,10 +4,10 @@ use std::{
 };
 
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
-use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Instrument, Span};
+use bevy_utils::{default, Duration, Instant};
 use std::panic::AssertUnwindSafe;
 
 use async_channel::{Receiver, Sender};
@@ -17,7 +17,10 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, ExecutorProfile, ScheduleProfiler,
+        SystemExecutor, SystemSchedule,
+    },
     system::BoxedSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -71,6 +74,8 @@ struct SystemTaskMetadata {
 struct SystemResult {
     system_index: usize,
     success: bool,
+    /// How long the system ran, if the executor is profiling.
+    run_time: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -115,6 +120,10 @@ pub struct MultiThreadedExecutor {
     panic_payload: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
     /// When set, stops the executor from running any more systems.
     stop_spawning: bool,
+    /// Returns `true` if a [`ScheduleProfiler`] is measuring the current run.
+    profiling: bool,
+    /// Measurements of the last profiled run.
+    profile: ExecutorProfile,
 }
 
 impl Default for MultiThreadedExecutor {
@@ -161,6 +170,9 @@ impl SystemExecutor for MultiThreadedExecutor {
         }
 
         self.num_dependencies_remaining = Vec::with_capacity(sys_count);
+
+        self.profile.systems.clear();
+        self.profile.systems.resize(sys_count, default());
     }
 
     fn run(
@@ -171,9 +183,14 @@ impl SystemExecutor for MultiThreadedExecutor {
     ) {
         // reset counts
         self.num_systems = schedule.systems.len();
+        self.profiling = self.num_systems > 0 && world.contains_resource::<ScheduleProfiler>();
         if self.num_systems == 0 {
             return;
         }
+        let start = self.profiling.then(|| {
+            self.profile.systems.fill(default());
+            Instant::now()
+        });
         self.num_running_systems = 0;
         self.num_completed_systems = 0;
         self.num_dependencies_remaining.clear();
@@ -274,6 +291,11 @@ impl SystemExecutor for MultiThreadedExecutor {
             debug_assert!(self.unapplied_systems.is_clear());
         }
 
+        if let Some(start) = start {
+            self.profile.run_time = start.elapsed();
+            self.profile.threads = ComputeTaskPool::get().thread_num();
+        }
+
         // check to see if there was a panic
         let mut payload = self.panic_payload.lock().unwrap();
         if let Some(payload) = payload.take() {
@@ -291,6 +313,10 @@ impl SystemExecutor for MultiThreadedExecutor {
     fn set_apply_final_deferred(&mut self, value: bool) {
         self.apply_final_deferred = value;
     }
+
+    fn profile(&self) -> Option<&ExecutorProfile> {
+        self.profiling.then_some(&self.profile)
+    }
 }
 
 impl MultiThreadedExecutor {
@@ -320,6 +346,8 @@ impl MultiThreadedExecutor {
             apply_final_deferred: true,
             panic_payload: Arc::new(Mutex::new(None)),
             stop_spawning: false,
+            profiling: false,
+            profile: default(),
         }
     }
 
@@ -371,6 +399,13 @@ impl MultiThreadedExecutor {
             self.running_systems.insert(system_index);
             self.num_running_systems += 1;
 
+            if self.profiling {
+                let times = &mut self.profile.systems[system_index];
+                if let Some(blocked_at) = times.blocked_at {
+                    times.blocked_time = blocked_at.elapsed();
+                }
+            }
+
             if self.system_task_metadata[system_index].is_exclusive {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
@@ -405,7 +440,7 @@ impl MultiThreadedExecutor {
     ) -> bool {
         let system_meta = &self.system_task_metadata[system_index];
         if system_meta.is_exclusive && self.num_running_systems > 0 {
-            return false;
+            return self.block_on_conflict(system_index);
         }
 
         if !system_meta.is_send && self.local_thread_running {
@@ -422,7 +457,7 @@ impl MultiThreadedExecutor {
                     .archetype_component_access()
                     .is_compatible(&self.active_access)
                 {
-                    return false;
+                    return self.block_on_conflict(system_index);
                 }
             }
         }
@@ -433,7 +468,7 @@ impl MultiThreadedExecutor {
                 .archetype_component_access()
                 .is_compatible(&self.active_access)
             {
-                return false;
+                return self.block_on_conflict(system_index);
             }
         }
 
@@ -443,7 +478,7 @@ impl MultiThreadedExecutor {
                 .archetype_component_access()
                 .is_compatible(&self.active_access)
             {
-                return false;
+                return self.block_on_conflict(system_index);
             }
 
             // PERF: use an optimized clear() + extend() operation
@@ -456,6 +491,17 @@ impl MultiThreadedExecutor {
         true
     }
 
+    /// Starts measuring how long the system is blocked by running systems with conflicting
+    /// access, unless it already was. Always returns `false`.
+    fn block_on_conflict(&mut self, system_index: usize) -> bool {
+        if self.profiling {
+            self.profile.systems[system_index]
+                .blocked_at
+                .get_or_insert_with(Instant::now);
+        }
+        false
+    }
+
     /// # Safety
     /// * `world` must have permission to read any world data required by
     ///   the system's conditions: this includes conditions for the system
@@ -526,7 +572,9 @@ impl MultiThreadedExecutor {
         let system = unsafe { &mut *systems[system_index].get() };
         let sender = self.sender.clone();
         let panic_payload = self.panic_payload.clone();
+        let profiling = self.profiling;
         let task = async move {
+            let start = profiling.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -539,6 +587,7 @@ impl MultiThreadedExecutor {
                 .try_send(SystemResult {
                     system_index,
                     success: res.is_ok(),
+                    run_time: start.map(|start| start.elapsed()),
                 })
                 .unwrap_or_else(|error| unreachable!("{}", error));
             if let Err(payload) = res {
@@ -584,17 +633,20 @@ impl MultiThreadedExecutor {
 
         let sender = self.sender.clone();
         let panic_payload = self.panic_payload.clone();
+        let profiling = self.profiling;
         if is_apply_deferred(system) {
             // TODO: avoid allocation
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
+                let start = profiling.then(Instant::now);
                 let res = apply_deferred(&unapplied_systems, systems, world);
                 // tell the executor that the system finished
                 sender
                     .try_send(SystemResult {
                         system_index,
                         success: res.is_ok(),
+                        run_time: start.map(|start| start.elapsed()),
                     })
                     .unwrap_or_else(|error| unreachable!("{}", error));
                 if let Err(payload) = res {
@@ -613,6 +665,7 @@ impl MultiThreadedExecutor {
             scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                let start = profiling.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     system.run((), world);
                 }));
@@ -621,6 +674,7 @@ impl MultiThreadedExecutor {
                     .try_send(SystemResult {
                         system_index,
                         success: res.is_ok(),
+                        run_time: start.map(|start| start.elapsed()),
                     })
                     .unwrap_or_else(|error| unreachable!("{}", error));
                 if let Err(payload) = res {
@@ -651,8 +705,13 @@ impl MultiThreadedExecutor {
         let SystemResult {
             system_index,
             success,
+            run_time,
         } = result;
 
+        if run_time.is_some() {
+            self.profile.systems[system_index].run_time = run_time;
+        }
+
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
         }
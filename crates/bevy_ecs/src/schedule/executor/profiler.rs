@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+
+use bevy_utils::{Duration, HashMap, Instant};
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, NodeId, ScheduleLabel, SystemSchedule},
+    system::Resource,
+};
+
+/// Opt-in profiling of the systems run by the [`MultiThreadedExecutor`](super::MultiThreadedExecutor).
+///
+/// While this resource is in the world, the multi-threaded executor measures how long each
+/// system runs, and how long it stays blocked by running systems with conflicting access
+/// before it can start. The measurements are aggregated per
+/// schedule, and can be inspected directly or through the `ScheduleProfilerDiagnosticsPlugin`
+/// of `bevy_diagnostic`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ExecutorKind, ScheduleProfiler};
+/// fn system() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<ScheduleProfiler>();
+///
+/// let mut schedule = Schedule::default();
+/// schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+/// schedule.add_systems(system);
+/// schedule.run(&mut world);
+///
+/// let profiler = world.resource::<ScheduleProfiler>();
+/// let profile = profiler.get(schedule.label()).unwrap();
+/// assert_eq!(profile.runs, 1);
+/// assert_eq!(profile.system("system").unwrap().runs, 1);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct ScheduleProfiler {
+    schedules: HashMap<InternedScheduleLabel, ScheduleProfile>,
+}
+
+impl ScheduleProfiler {
+    /// Returns the profile of the schedule with the given `label`, if it has been run.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleProfile> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the profiles of all the schedules. Iteration order is undefined.
+    pub fn iter(&self) -> impl Iterator<Item = (&dyn ScheduleLabel, &ScheduleProfile)> {
+        self.schedules
+            .iter()
+            .map(|(label, profile)| (&**label, profile))
+    }
+
+    /// Removes the profiles of all the schedules.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Adds the measurements of a run of the schedule with the given `label`.
+    pub(crate) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        schedule: &SystemSchedule,
+        run: &ExecutorProfile,
+    ) {
+        let profile = self.schedules.entry(label).or_default();
+
+        // the schedule has been rebuilt since the last run
+        let systems_changed = profile.systems.len() != schedule.system_ids.len()
+            || profile
+                .systems
+                .iter()
+                .zip(&schedule.system_ids)
+                .any(|(system, &id)| system.id != id);
+        if systems_changed {
+            profile.systems = schedule
+                .system_ids
+                .iter()
+                .zip(&schedule.systems)
+                .map(|(&id, system)| SystemProfile::new(id, system.name()))
+                .collect();
+        }
+
+        profile.runs += 1;
+        profile.run_time = run.run_time;
+        profile.total_run_time += run.run_time;
+        profile.threads = run.threads;
+        profile.busy_time = Duration::ZERO;
+        profile.blocked_time = Duration::ZERO;
+        for (system, times) in profile.systems.iter_mut().zip(&run.systems) {
+            system.run_time = times.run_time.unwrap_or_default();
+            system.blocked_time = times.blocked_time;
+            if times.run_time.is_some() {
+                system.runs += 1;
+                system.max_run_time = system.max_run_time.max(system.run_time);
+                system.total_run_time += system.run_time;
+                system.total_blocked_time += system.blocked_time;
+            }
+            profile.busy_time += system.run_time;
+            profile.blocked_time += system.blocked_time;
+        }
+    }
+}
+
+/// The measurements of a schedule, recorded by the [`ScheduleProfiler`].
+///
+/// Unless stated otherwise, the durations are the ones of the last run of the schedule.
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleProfile {
+    /// The number of profiled runs of the schedule.
+    pub runs: u64,
+    /// The time it took to run the schedule.
+    pub run_time: Duration,
+    /// The total time it took to run the schedule, over all the profiled runs.
+    pub total_run_time: Duration,
+    /// The time spent running systems, summed over all the threads.
+    pub busy_time: Duration,
+    /// The time systems were blocked by conflicting access, summed over all the systems.
+    pub blocked_time: Duration,
+    /// The number of threads systems could run on.
+    pub threads: usize,
+    /// The systems of the schedule, in the order they're stored in the executor.
+    pub systems: Vec<SystemProfile>,
+}
+
+impl ScheduleProfile {
+    /// Returns the fraction of the available thread time spent running systems, from 0 to 1.
+    pub fn utilization(&self) -> f64 {
+        let available = self.run_time.as_secs_f64() * self.threads as f64;
+        if available > 0.0 {
+            (self.busy_time.as_secs_f64() / available).min(1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the profile of the first system whose name is or ends with `::{name}`.
+    pub fn system(&self, name: &str) -> Option<&SystemProfile> {
+        self.systems.iter().find(|system| {
+            system.name == name
+                || system
+                    .name
+                    .strip_suffix(name)
+                    .is_some_and(|path| path.ends_with("::"))
+        })
+    }
+}
+
+/// The measurements of a system, recorded by the [`ScheduleProfiler`].
+///
+/// Unless stated otherwise, the durations are the ones of the last run of its schedule,
+/// and are zero if the system was skipped.
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    /// The id of the system in its [`Schedule`](crate::schedule::Schedule).
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The number of times the system ran, not counting the runs where it was skipped.
+    pub runs: u64,
+    /// The time it took to run the system.
+    pub run_time: Duration,
+    /// The time the system was ready to run, but blocked by systems with conflicting access.
+    pub blocked_time: Duration,
+    /// The longest time it took to run the system.
+    pub max_run_time: Duration,
+    /// The total time it took to run the system, over all its runs.
+    pub total_run_time: Duration,
+    /// The total time the system was blocked, over all its runs.
+    pub total_blocked_time: Duration,
+}
+
+impl SystemProfile {
+    fn new(id: NodeId, name: Cow<'static, str>) -> Self {
+        Self {
+            id,
+            name,
+            runs: 0,
+            run_time: Duration::ZERO,
+            blocked_time: Duration::ZERO,
+            max_run_time: Duration::ZERO,
+            total_run_time: Duration::ZERO,
+            total_blocked_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns the average time it took to run the system.
+    pub fn mean_run_time(&self) -> Duration {
+        if self.runs == 0 {
+            Duration::ZERO
+        } else {
+            self.total_run_time.div_f64(self.runs as f64)
+        }
+    }
+}
+
+/// The measurements of the last run of an executor, indexed like its [`SystemSchedule`].
+#[derive(Default)]
+pub(crate) struct ExecutorProfile {
+    pub(crate) run_time: Duration,
+    pub(crate) threads: usize,
+    pub(crate) systems: Vec<SystemTimes>,
+}
+
+/// The measurements of a system during the last run of an executor.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct SystemTimes {
+    /// When a running system with conflicting access first prevented the system from running.
+    pub(crate) blocked_at: Option<Instant>,
+    pub(crate) blocked_time: Duration,
+    /// `None` if the system didn't run.
+    pub(crate) run_time: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use crate::{
+        self as bevy_ecs,
+        prelude::{ResMut, Resource, World},
+        schedule::{ExecutorKind, IntoSystemConfigs, Schedule},
+    };
+
+    use super::ScheduleProfiler;
+
+    #[derive(Resource, Default)]
+    struct R;
+
+    fn slow_a(_: ResMut<R>) {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    fn slow_b(_: ResMut<R>) {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    #[test]
+    fn not_profiled_without_resource() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems(slow_a);
+        schedule.run(&mut world);
+
+        world.init_resource::<ScheduleProfiler>();
+        assert!(world
+            .resource::<ScheduleProfiler>()
+            .get(schedule.label())
+            .is_none());
+    }
+
+    #[test]
+    fn records_run_and_blocked_time() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world.init_resource::<ScheduleProfiler>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems((slow_a, slow_b, (|| {}).run_if(|| false)));
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let profiler = world.resource::<ScheduleProfiler>();
+        let profile = profiler.get(schedule.label()).unwrap();
+        assert_eq!(profile.runs, 2);
+        assert_eq!(profile.systems.len(), 3);
+        assert!(profile.utilization() > 0.0 && profile.utilization() <= 1.0);
+
+        let a = profile.system("slow_a").unwrap();
+        let b = profile.system("slow_b").unwrap();
+        assert_eq!((a.runs, b.runs), (2, 2));
+        assert!(!a.run_time.is_zero() && !b.run_time.is_zero());
+        assert!(a.max_run_time >= a.run_time);
+        assert!(a.total_run_time > a.run_time);
+        assert_eq!(profile.busy_time, a.run_time + b.run_time);
+        // the systems conflict, so they can't overlap
+        assert!(profile.run_time >= profile.busy_time);
+
+        // the system that runs second waits for the other one, and only that one
+        assert_ne!(a.blocked_time.is_zero(), b.blocked_time.is_zero());
+        assert!(profile.blocked_time <= profile.run_time);
+        assert_eq!(profile.blocked_time, a.blocked_time + b.blocked_time);
+
+        let skipped = profile
+            .systems
+            .iter()
+            .find(|system| system.name.contains("{{closure}}"))
+            .unwrap();
+        assert_eq!(skipped.runs, 0);
+        assert_eq!(skipped.run_time, Duration::ZERO);
+    }
+
+    #[test]
+    fn resets_systems_when_rebuilt() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world.init_resource::<ScheduleProfiler>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems(slow_a);
+        schedule.run(&mut world);
+        schedule.add_systems(slow_b.after(slow_a));
+        schedule.run(&mut world);
+
+        let profiler = world.resource::<ScheduleProfiler>();
+        let profile = profiler.get(schedule.label()).unwrap();
+        assert_eq!(profile.runs, 2);
+        assert_eq!(profile.system("slow_a").unwrap().runs, 1);
+        assert_eq!(profile.system("slow_b").unwrap().runs, 1);
+    }
+}
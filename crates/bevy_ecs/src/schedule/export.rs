@@ -0,0 +1,568 @@
+use std::fmt::Write;
+
+use bevy_utils::{get_short_name, petgraph::Direction::Outgoing, HashMap};
+
+use crate::{
+    component::Components,
+    schedule::{
+        apply_deferred, is_apply_deferred, BoxedCondition, NodeId, Schedule, ScheduleGraph,
+    },
+    system::{IntoSystem, System},
+};
+
+/// A snapshot of the graph of a [`Schedule`], for visualization and debugging.
+///
+/// It holds the systems and sets of the schedule with the names of their run conditions,
+/// the hierarchy and ordering edges between them, and the systems having conflicting access.
+/// Once the schedule has been initialized, it also contains the [`apply_deferred`](crate::schedule::apply_deferred)
+/// sync points inserted automatically, and the conflicts found while building it.
+///
+/// Render it with [`ScheduleGraphExport::to_dot`] or [`ScheduleGraphExport::to_json`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// fn a() {}
+/// fn b() {}
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((a, b).chain());
+/// schedule.initialize(&mut world).unwrap();
+///
+/// let export = schedule.export_graph(world.components());
+/// assert_eq!(export.systems.len(), 2);
+/// assert_eq!(export.dependencies.len(), 1);
+/// assert!(export.to_dot().starts_with("digraph"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleGraphExport {
+    /// The label of the schedule.
+    pub label: String,
+    /// The systems of the schedule.
+    pub systems: Vec<SystemExport>,
+    /// The sets of the schedule, including the type sets of its systems.
+    pub sets: Vec<SystemSetExport>,
+    /// The `(set, child)` edges of the hierarchy, where the child is a system or a set.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// The `(before, after)` ordering edges between systems and sets.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// The pairs of systems with conflicting access and no ordering between them.
+    pub conflicts: Vec<ConflictExport>,
+}
+
+/// A system of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemExport {
+    /// The id of the system in the [`ScheduleGraph`](crate::schedule::ScheduleGraph).
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+    /// Whether the system is an [`apply_deferred`](crate::schedule::apply_deferred) sync point.
+    pub sync_point: bool,
+}
+
+/// A system set of a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemSetExport {
+    /// The id of the set in the [`ScheduleGraph`](crate::schedule::ScheduleGraph).
+    pub id: NodeId,
+    /// The name of the set.
+    pub name: String,
+    /// The names of the run conditions of the set.
+    pub conditions: Vec<String>,
+    /// Whether the set is the [`SystemTypeSet`](crate::schedule::SystemTypeSet) of a system.
+    pub system_type: bool,
+}
+
+/// Two systems of a [`ScheduleGraphExport`] with conflicting access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictExport {
+    /// The id of the first system.
+    pub a: NodeId,
+    /// The id of the second system.
+    pub b: NodeId,
+    /// The names of the components and resources both systems access, at least one of them mutably.
+    ///
+    /// It's empty if the systems conflict on [`World`](crate::world::World) access.
+    pub components: Vec<String>,
+}
+
+impl Schedule {
+    /// Exports the graph of the schedule, to visualize it with [`ScheduleGraphExport::to_dot`]
+    /// or [`ScheduleGraphExport::to_json`].
+    ///
+    /// The automatically inserted sync points and the conflicts between systems are only
+    /// exported once the schedule has been initialized, for example by [`Schedule::initialize`].
+    pub fn export_graph(&self, components: &Components) -> ScheduleGraphExport {
+        let graph = self.graph();
+        let executable = self.executable();
+        let short_names = self.get_build_settings().use_shortnames;
+        let name = |name: &str| {
+            if short_names {
+                get_short_name(name)
+            } else {
+                name.to_string()
+            }
+        };
+        let condition_names =
+            |conditions: &[BoxedCondition]| conditions.iter().map(|c| name(&c.name())).collect();
+
+        // systems and conditions are either still in the graph, or moved into the executable schedule
+        let mut systems = HashMap::new();
+        for (id, system, conditions) in graph.systems() {
+            if graph.dependency().graph().contains_node(id) {
+                systems.insert(id, (system, condition_names(conditions)));
+            }
+        }
+        for ((&id, system), conditions) in executable
+            .system_ids
+            .iter()
+            .zip(&executable.systems)
+            .zip(&executable.system_conditions)
+        {
+            // skip the systems removed since the schedule was built
+            if graph.dependency().graph().contains_node(id) || is_apply_deferred(system) {
+                systems.insert(id, (&**system, condition_names(conditions)));
+            }
+        }
+        let mut systems: Vec<_> = systems
+            .into_iter()
+            .map(|(id, (system, conditions))| SystemExport {
+                id,
+                name: name(&system.name()),
+                conditions,
+                sync_point: System::type_id(system) == apply_deferred.system_type_id(),
+            })
+            .collect();
+        systems.sort_by_key(|system| system.id);
+
+        let mut set_conditions: HashMap<_, _> = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .map(|(&id, conditions)| (id, condition_names(conditions)))
+            .collect();
+        let mut sets: Vec<_> = graph
+            .system_sets()
+            .map(|(id, set, conditions)| SystemSetExport {
+                id,
+                name: name(&format!("{set:?}")),
+                conditions: set_conditions
+                    .remove(&id)
+                    .unwrap_or_else(|| condition_names(conditions)),
+                system_type: set.system_type().is_some(),
+            })
+            .collect();
+        sets.sort_by_key(|set| set.id);
+        for index in 0..sets.len() {
+            let id = sets[index].id;
+            if graph.set_at(id).is_anonymous() {
+                sets[index].name = anonymous_set_name(graph, &systems, &sets, id);
+            }
+        }
+
+        let mut hierarchy: Vec<_> = graph
+            .hierarchy()
+            .graph()
+            .all_edges()
+            .map(|(a, b, _)| (a, b))
+            .collect();
+        hierarchy.sort();
+
+        let mut dependencies: Vec<_> = graph
+            .dependency()
+            .graph()
+            .all_edges()
+            .map(|(a, b, _)| (a, b))
+            .collect();
+        // sync points inserted while building the schedule are only ordered in the executable schedule
+        let is_auto_sync_point = |id: NodeId| !graph.dependency().graph().contains_node(id);
+        for (index, dependents) in executable.system_dependents.iter().enumerate() {
+            let a = executable.system_ids[index];
+            for &dependent in dependents {
+                let b = executable.system_ids[dependent];
+                if is_auto_sync_point(a) || is_auto_sync_point(b) {
+                    dependencies.push((a, b));
+                }
+            }
+        }
+        dependencies.sort();
+
+        let conflicts = graph
+            .conflicting_systems()
+            .iter()
+            .map(|(a, b, conflicts)| ConflictExport {
+                a: *a,
+                b: *b,
+                components: conflicts
+                    .iter()
+                    .filter_map(|&id| components.get_name(id))
+                    .map(name)
+                    .collect(),
+            })
+            .collect();
+
+        ScheduleGraphExport {
+            label: format!("{:?}", self.label()),
+            systems,
+            sets,
+            hierarchy,
+            dependencies,
+            conflicts,
+        }
+    }
+}
+
+impl ScheduleGraphExport {
+    /// Renders the graph in the [DOT] language of Graphviz.
+    ///
+    /// Sets are drawn as dashed boxes, with dashed edges to their members, and conflicts between
+    /// systems as red undirected edges. The type set of a system is merged with the system when
+    /// there is a single instance of it.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        let merged_sets = self.merged_system_type_sets();
+        let node = |id: &NodeId| node_name(merged_sets.get(id).unwrap_or(id));
+
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_string(&self.label)).unwrap();
+        writeln!(dot, "\tlabel={};", dot_string(&self.label)).unwrap();
+        writeln!(dot, "\tnode [shape=box, style=rounded];").unwrap();
+        for system in &self.systems {
+            let shape = if system.sync_point {
+                ", shape=diamond, style=\"\""
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "\t{} [label={}{shape}];",
+                node_name(&system.id),
+                dot_string(&node_label(&system.name, &system.conditions)),
+            )
+            .unwrap();
+        }
+        for set in &self.sets {
+            if !merged_sets.contains_key(&set.id) {
+                writeln!(
+                    dot,
+                    "\t{} [label={}, style=dashed];",
+                    node_name(&set.id),
+                    dot_string(&node_label(&set.name, &set.conditions)),
+                )
+                .unwrap();
+            }
+        }
+        for (set, child) in &self.hierarchy {
+            if !merged_sets.contains_key(set) {
+                writeln!(dot, "\t{} -> {} [style=dashed];", node(set), node(child)).unwrap();
+            }
+        }
+        for (before, after) in &self.dependencies {
+            writeln!(dot, "\t{} -> {};", node(before), node(after)).unwrap();
+        }
+        for conflict in &self.conflicts {
+            writeln!(
+                dot,
+                "\t{} -> {} [dir=none, color=red, fontcolor=red, label={}];",
+                node(&conflict.a),
+                node(&conflict.b),
+                dot_string(&conflict.components.join("\n")),
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as JSON.
+    ///
+    /// Systems and sets are identified by strings like `"system_0"` and `"set_0"`:
+    ///
+    /// ```json
+    /// {
+    ///   "label": "Update",
+    ///   "systems": [{ "id": "system_0", "name": "a", "conditions": [], "sync_point": false }],
+    ///   "sets": [{ "id": "set_0", "name": "SystemTypeSet(a)", "conditions": [], "system_type": true }],
+    ///   "hierarchy": [["set_0", "system_0"]],
+    ///   "dependencies": [],
+    ///   "conflicts": [{ "a": "system_0", "b": "system_1", "components": ["R"] }]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"label\":");
+        json_string(&mut json, &self.label);
+
+        json.push_str(",\"systems\":[");
+        for (i, system) in self.systems.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(json, "{{\"id\":\"{}\",\"name\":", node_name(&system.id)).unwrap();
+            json_string(&mut json, &system.name);
+            json.push_str(",\"conditions\":");
+            json_strings(&mut json, &system.conditions);
+            write!(json, ",\"sync_point\":{}}}", system.sync_point).unwrap();
+        }
+
+        json.push_str("],\"sets\":[");
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(json, "{{\"id\":\"{}\",\"name\":", node_name(&set.id)).unwrap();
+            json_string(&mut json, &set.name);
+            json.push_str(",\"conditions\":");
+            json_strings(&mut json, &set.conditions);
+            write!(json, ",\"system_type\":{}}}", set.system_type).unwrap();
+        }
+
+        for (key, edges) in [
+            ("hierarchy", &self.hierarchy),
+            ("dependencies", &self.dependencies),
+        ] {
+            write!(json, "],\"{key}\":[").unwrap();
+            for (i, (a, b)) in edges.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write!(json, "[\"{}\",\"{}\"]", node_name(a), node_name(b)).unwrap();
+            }
+        }
+
+        json.push_str("],\"conflicts\":[");
+        for (i, conflict) in self.conflicts.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"a\":\"{}\",\"b\":\"{}\",\"components\":",
+                node_name(&conflict.a),
+                node_name(&conflict.b),
+            )
+            .unwrap();
+            json_strings(&mut json, &conflict.components);
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Maps the type sets having a single system, without conditions, to that system.
+    fn merged_system_type_sets(&self) -> HashMap<NodeId, NodeId> {
+        let mut members = HashMap::<NodeId, Vec<NodeId>>::new();
+        for &(set, child) in &self.hierarchy {
+            members.entry(set).or_default().push(child);
+        }
+        self.sets
+            .iter()
+            .filter(|set| set.system_type && set.conditions.is_empty())
+            .filter_map(|set| match members.get(&set.id).map(Vec::as_slice) {
+                Some(&[system]) if system.is_system() => Some((set.id, system)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn node_name(id: &NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn node_label(name: &str, conditions: &[String]) -> String {
+    let mut label = name.to_string();
+    for condition in conditions {
+        write!(label, "\nrun_if({condition})").unwrap();
+    }
+    label
+}
+
+fn dot_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len() + 2);
+    escaped.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\\' => escaped.push_str("\\\\"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_string(json: &mut String, string: &str) {
+    json.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+fn json_strings(json: &mut String, strings: &[String]) {
+    json.push('[');
+    for (i, string) in strings.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json_string(json, string);
+    }
+    json.push(']');
+}
+
+fn anonymous_set_name(
+    graph: &ScheduleGraph,
+    systems: &[SystemExport],
+    sets: &[SystemSetExport],
+    id: NodeId,
+) -> String {
+    let members: Vec<_> = graph
+        .hierarchy()
+        .graph()
+        .neighbors_directed(id, Outgoing)
+        .map(|member| match member {
+            NodeId::System(_) => systems
+                .iter()
+                .find(|system| system.id == member)
+                .map(|system| system.name.clone())
+                .unwrap_or_default(),
+            NodeId::Set(_) => match sets.iter().find(|set| set.id == member) {
+                Some(_) if graph.set_at(member).is_anonymous() => {
+                    anonymous_set_name(graph, systems, sets, member)
+                }
+                Some(set) => set.name.clone(),
+                None => String::new(),
+            },
+        })
+        .collect();
+    format!("({})", members.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::{Commands, Res, ResMut, Resource, World},
+        schedule::{IntoSystemConfigs, NodeId, Schedule, SystemSet},
+    };
+
+    #[derive(Resource)]
+    struct R;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Set;
+
+    fn insert(mut commands: Commands) {
+        commands.insert_resource(R);
+    }
+
+    fn read(_: Option<Res<R>>) {}
+
+    fn write_a(_: Option<ResMut<R>>) {}
+
+    fn write_b(_: Option<ResMut<R>>) {}
+
+    fn export() -> super::ScheduleGraphExport {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            (insert, read.run_if(|| true)).chain().in_set(Set),
+            (write_a, write_b),
+        ));
+        schedule.initialize(&mut world).unwrap();
+        schedule.export_graph(world.components())
+    }
+
+    fn system_id(export: &super::ScheduleGraphExport, name: &str) -> NodeId {
+        export
+            .systems
+            .iter()
+            .find(|system| system.name == name)
+            .unwrap()
+            .id
+    }
+
+    #[test]
+    fn exports_systems_sets_and_edges() {
+        let export = export();
+        assert_eq!(export.label, "DefaultSchedule");
+
+        let names: Vec<_> = export.systems.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["insert", "read", "write_a", "write_b", "apply_deferred"]
+        );
+        let read = &export.systems[1];
+        assert_eq!(read.conditions.len(), 1);
+
+        let set = export.sets.iter().find(|set| set.name == "Set").unwrap();
+        assert!(!set.system_type);
+        assert!(export
+            .hierarchy
+            .contains(&(set.id, system_id(&export, "insert"))));
+
+        // the auto-inserted sync point is ordered between the chained systems
+        let sync_point = export.systems.iter().find(|s| s.sync_point).unwrap().id;
+        assert!(export
+            .dependencies
+            .contains(&(system_id(&export, "insert"), sync_point)));
+        assert!(export.dependencies.contains(&(sync_point, read.id)));
+
+        assert_eq!(export.conflicts.len(), 3);
+        let conflict = export
+            .conflicts
+            .iter()
+            .find(|c| {
+                [c.a, c.b] == [system_id(&export, "write_a"), system_id(&export, "write_b")]
+                    || [c.b, c.a] == [system_id(&export, "write_a"), system_id(&export, "write_b")]
+            })
+            .unwrap();
+        assert_eq!(conflict.components, vec!["R"]);
+    }
+
+    #[test]
+    fn to_dot() {
+        let export = export();
+        let dot = export.to_dot();
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("[label=\"read\\nrun_if("));
+        assert!(dot.contains("[label=\"Set\", style=dashed];"));
+        assert!(dot.contains("shape=diamond"));
+        assert!(dot.contains("color=red"));
+        // the type sets of the systems are merged with them
+        assert!(!dot.contains("SystemTypeSet"));
+    }
+
+    #[test]
+    fn to_json() {
+        let export = export();
+        let json = export.to_json();
+        assert!(json.starts_with("{\"label\":\"DefaultSchedule\",\"systems\":[{\"id\":\"system_0\",\"name\":\"insert\",\"conditions\":[],\"sync_point\":false},"));
+        assert!(json.contains("\"name\":\"Set\",\"conditions\":[],\"system_type\":false}"));
+        assert!(json.contains("\"hierarchy\":[["));
+        assert!(json.contains("\"conflicts\":[{\"a\":\"system_"));
+        assert!(json.ends_with("]}"));
+
+        let mut escaped = String::new();
+        super::json_string(&mut escaped, "a\"b\\c\n\u{1}");
+        assert_eq!(escaped, "\"a\\\"b\\\\c\\n\\u0001\"");
+    }
+}
@@ -3,6 +3,7 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
@@ -13,6 +14,7 @@ mod stepping;
 pub use self::condition::*;
 pub use self::config::*;
 pub use self::executor::*;
+pub use self::export::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
 pub use self::set::*;
@@ -1114,7 +1116,7 @@ mod tests {
                 let mut schedule = Schedule::new(TestSchedule);
                 schedule
                     .set_executor_kind($executor)
-                    .add_systems(|| panic!("Executor ignored Stepping"));
+                    .add_systems(|| -> () { panic!("Executor ignored Stepping") });
 
                 // Add our schedule to stepping & and enable stepping; this should
                 // prevent any systems in the schedule from running
@@ -289,6 +289,74 @@ impl Schedule {
         self
     }
 
+    /// Removes the system at the given [`NodeId`], along with its conditions, its ordering
+    /// constraints and its ambiguity data.
+    ///
+    /// The systems and sets ordered before the removed system stay ordered before the ones
+    /// ordered after it.
+    /// The ids of the systems of an initialized schedule are returned by [`Schedule::systems`].
+    /// Returns `false` if there is no such system.
+    pub fn remove_system_at(&mut self, id: NodeId) -> bool {
+        if !self.graph.contains_system(id) {
+            return false;
+        }
+        self.invalidate_executable();
+        self.graph.remove_system_at(id)
+    }
+
+    /// Removes all the systems in `set`, including the systems of its nested sets.
+    ///
+    /// Passing a system removes all its instances. The set itself is kept, unless it's
+    /// the type set of a system.
+    /// Returns the number of removed systems.
+    pub fn remove_systems_in_set<M>(&mut self, set: impl IntoSystemSet<M>) -> usize {
+        self.invalidate_executable();
+        self.graph.remove_systems_in_set(set)
+    }
+
+    /// Removes `set`, along with its conditions, its ordering constraints and its ambiguity data.
+    ///
+    /// The systems and sets in the removed set are kept, and stay in the sets containing it.
+    /// The systems and sets ordered before the removed set stay ordered before the ones
+    /// ordered after it.
+    /// Returns `false` if the set isn't in the schedule.
+    pub fn remove_set<M>(&mut self, set: impl IntoSystemSet<M>) -> bool {
+        match self.graph.set_id(set) {
+            Some(id) => {
+                self.invalidate_executable();
+                self.graph.remove_set_at(id)
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the system at the given [`NodeId`] with `system`, keeping its sets, conditions
+    /// and ordering constraints. Returns the previous system, or `None` if there is no such system.
+    ///
+    /// The new system is initialized the next time the schedule runs.
+    pub fn replace_system_at<M>(
+        &mut self,
+        id: NodeId,
+        system: impl IntoSystem<(), (), M>,
+    ) -> Option<BoxedSystem> {
+        if !self.graph.contains_system(id) {
+            return None;
+        }
+        self.invalidate_executable();
+        self.graph
+            .replace_system_at(id, Box::new(IntoSystem::into_system(system)))
+    }
+
+    /// Moves the systems of the executable schedule back into the graph before it's modified.
+    ///
+    /// The executable schedule is rebuilt the next time the schedule runs, and
+    /// [`Schedule::systems`] returns an error until then.
+    fn invalidate_executable(&mut self) {
+        self.graph.return_systems(&mut self.executable);
+        self.graph.changed = true;
+        self.executor_initialized = false;
+    }
+
     /// Changes miscellaneous build settings.
     pub fn set_build_settings(&mut self, settings: ScheduleBuildSettings) -> &mut Self {
         self.graph.settings = settings;
@@ -342,6 +410,12 @@ impl Schedule {
         };
 
         self.executor.run(&mut self.executable, skip_systems, world);
+
+        if let Some(profile) = self.executor.profile() {
+            if let Some(mut profiler) = world.get_resource_mut::<ScheduleProfiler>() {
+                profiler.record(self.label, &self.executable, profile);
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -449,7 +523,11 @@ impl Schedule {
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
-            self.graph.systems.len()
+            self.graph
+                .systems
+                .iter()
+                .filter(|system| !system.removed)
+                .count()
         } else {
             self.executable.systems.len()
         }
@@ -489,11 +567,16 @@ impl Dag {
 /// A [`SystemSet`] with metadata, stored in a [`ScheduleGraph`].
 struct SystemSetNode {
     inner: InternedSystemSet,
+    /// Whether the set has been removed from the graph.
+    removed: bool,
 }
 
 impl SystemSetNode {
     pub fn new(set: InternedSystemSet) -> Self {
-        Self { inner: set }
+        Self {
+            inner: set,
+            removed: false,
+        }
     }
 
     pub fn name(&self) -> String {
@@ -512,12 +595,18 @@ impl SystemSetNode {
 /// A [`BoxedSystem`] with metadata, stored in a [`ScheduleGraph`].
 struct SystemNode {
     inner: Option<BoxedSystem>,
+    /// Whether the system has been removed from the graph.
+    ///
+    /// The system may still be in the [`SystemSchedule`], in which case it's dropped
+    /// when the schedule is rebuilt.
+    removed: bool,
 }
 
 impl SystemNode {
     pub fn new(system: BoxedSystem) -> Self {
         Self {
             inner: Some(system),
+            removed: false,
         }
     }
 
@@ -599,7 +688,10 @@ impl ScheduleGraph {
         if !id.is_set() {
             return None;
         }
-        self.system_sets.get(id.index()).map(|set| &*set.inner)
+        self.system_sets
+            .get(id.index())
+            .filter(|set| !set.removed)
+            .map(|set| &*set.inner)
     }
 
     /// Returns the set at the given [`NodeId`].
@@ -660,6 +752,200 @@ impl ScheduleGraph {
         &self.conflicting_systems
     }
 
+    /// Returns the [`NodeId`] of the given set, if it's in the graph.
+    ///
+    /// Passing a system returns the id of its system type set.
+    pub fn set_id<M>(&self, set: impl IntoSystemSet<M>) -> Option<NodeId> {
+        self.system_set_ids
+            .get(&set.into_system_set().intern())
+            .copied()
+    }
+
+    /// Returns the ids of the systems in the set at the given [`NodeId`], including the systems
+    /// of its nested sets.
+    pub fn systems_in_set(&self, id: NodeId) -> Vec<NodeId> {
+        let mut systems = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![id];
+        while let Some(node) = stack.pop() {
+            for child in self.hierarchy.graph.neighbors_directed(node, Outgoing) {
+                if visited.insert(child) {
+                    if child.is_system() {
+                        systems.push(child);
+                    }
+                    stack.push(child);
+                }
+            }
+        }
+        systems
+    }
+
+    /// Removes the system at the given [`NodeId`], along with its conditions, its ordering
+    /// constraints and its ambiguity data.
+    ///
+    /// The nodes ordered before the removed system stay ordered before the ones ordered after it.
+    /// Returns `false` if there is no such system.
+    pub fn remove_system_at(&mut self, id: NodeId) -> bool {
+        if !self.contains_system(id) {
+            return false;
+        }
+        self.bridge_dependencies(id);
+        self.remove_node(id);
+        let node = &mut self.systems[id.index()];
+        node.inner = None;
+        node.removed = true;
+        self.system_conditions[id.index()].clear();
+        true
+    }
+
+    /// Removes the set at the given [`NodeId`], along with its conditions, its ordering
+    /// constraints and its ambiguity data.
+    ///
+    /// The systems and sets in the removed set are kept, and stay in the sets containing it.
+    /// The nodes ordered before the removed set stay ordered before the ones ordered after it.
+    /// Returns `false` if there is no such set.
+    pub fn remove_set_at(&mut self, id: NodeId) -> bool {
+        if self.get_set_at(id).is_none() {
+            return false;
+        }
+        let parents: Vec<_> = self
+            .hierarchy
+            .graph
+            .neighbors_directed(id, Incoming)
+            .collect();
+        let children: Vec<_> = self
+            .hierarchy
+            .graph
+            .neighbors_directed(id, Outgoing)
+            .collect();
+        for &parent in &parents {
+            for &child in &children {
+                self.hierarchy.graph.add_edge(parent, child, ());
+            }
+        }
+        self.bridge_dependencies(id);
+        self.remove_node(id);
+        let node = &mut self.system_sets[id.index()];
+        node.removed = true;
+        self.system_set_ids.remove(&node.inner);
+        self.system_set_conditions[id.index()].clear();
+        true
+    }
+
+    /// Removes all the systems in `set`, including the systems of its nested sets.
+    ///
+    /// Passing a system removes all its instances. The set itself is kept, unless it's
+    /// the type set of a system.
+    /// Returns the number of removed systems.
+    pub fn remove_systems_in_set<M>(&mut self, set: impl IntoSystemSet<M>) -> usize {
+        let Some(set_id) = self.set_id(set) else {
+            return 0;
+        };
+        let systems = self.systems_in_set(set_id);
+        for &id in &systems {
+            self.remove_system_at(id);
+        }
+        if self.system_sets[set_id.index()].is_system_type() {
+            self.remove_set_at(set_id);
+        }
+        systems.len()
+    }
+
+    /// Replaces the system at the given [`NodeId`], keeping its sets, conditions and ordering
+    /// constraints. Returns the previous system.
+    ///
+    /// The default sets of the previous system, like its
+    /// [`SystemTypeSet`](crate::schedule::SystemTypeSet), are replaced by the
+    /// default sets of the new one.
+    ///
+    /// The system must not have been moved into a [`SystemSchedule`].
+    fn replace_system_at(&mut self, id: NodeId, system: BoxedSystem) -> Option<BoxedSystem> {
+        if !self.contains_system(id) {
+            return None;
+        }
+        let new_sets = system.default_system_sets();
+        let previous = self.systems[id.index()].inner.replace(system);
+        for set in previous
+            .iter()
+            .flat_map(|previous| previous.default_system_sets())
+        {
+            if let Some(&set_id) = self.system_set_ids.get(&set) {
+                self.hierarchy.graph.remove_edge(set_id, id);
+            }
+        }
+        for set in new_sets {
+            let set_id = match self.system_set_ids.get(&set) {
+                Some(&set_id) => set_id,
+                None => self.add_set(set),
+            };
+            self.hierarchy.graph.add_edge(set_id, id, ());
+            self.dependency.graph.add_node(set_id);
+        }
+        // conditions that are already initialized don't need to be initialized again
+        if !self.uninit.iter().any(|&(node, _)| node == id) {
+            let initialized_conditions = self.system_conditions[id.index()].len();
+            self.uninit.push((id, initialized_conditions));
+        }
+        self.changed = true;
+        previous
+    }
+
+    /// Returns `true` if the system at the given [`NodeId`] was added by the user and
+    /// hasn't been removed.
+    fn contains_system(&self, id: NodeId) -> bool {
+        id.is_system()
+            && self
+                .systems
+                .get(id.index())
+                .is_some_and(|node| !node.removed)
+            && self.dependency.graph.contains_node(id)
+    }
+
+    /// Orders the dependencies of the node before its dependents, so that the ordering
+    /// constraints going through the node are kept once it's removed.
+    ///
+    /// The new edges don't add sync points if neither of the edges they replace did.
+    fn bridge_dependencies(&mut self, id: NodeId) {
+        let before: Vec<_> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Incoming)
+            .collect();
+        let after: Vec<_> = self
+            .dependency
+            .graph
+            .neighbors_directed(id, Outgoing)
+            .collect();
+        for &lhs in &before {
+            for &rhs in &after {
+                if self.dependency.graph.contains_edge(lhs, rhs) {
+                    continue;
+                }
+                self.dependency.graph.add_edge(lhs, rhs, ());
+                if self.no_sync_edges.contains(&(lhs, id))
+                    && self.no_sync_edges.contains(&(id, rhs))
+                {
+                    self.no_sync_edges.insert((lhs, rhs));
+                }
+            }
+        }
+    }
+
+    /// Removes every trace of the node from the graphs and the cached build data.
+    fn remove_node(&mut self, id: NodeId) {
+        self.hierarchy.graph.remove_node(id);
+        self.hierarchy.topsort.retain(|&node| node != id);
+        self.dependency.graph.remove_node(id);
+        self.dependency.topsort.retain(|&node| node != id);
+        self.ambiguous_with.remove_node(id);
+        self.ambiguous_with_all.remove(&id);
+        self.no_sync_edges.retain(|&(a, b)| a != id && b != id);
+        self.conflicting_systems
+            .retain(|&(a, b, _)| a != id && b != id);
+        self.uninit.retain(|&(node, _)| node != id);
+        self.changed = true;
+    }
+
     /// Adds the config nodes to the graph.
     ///
     /// `collect_nodes` controls whether the `NodeId`s of the processed config nodes are stored in the returned [`ProcessConfigsResult`].
@@ -1016,7 +1302,7 @@ impl ScheduleGraph {
             match id {
                 NodeId::System(index) => {
                     self.systems[index].get_mut().unwrap().initialize(world);
-                    for condition in &mut self.system_conditions[index] {
+                    for condition in self.system_conditions[index].iter_mut().skip(i) {
                         condition.initialize(world);
                     }
                 }
@@ -1453,24 +1739,7 @@ impl ScheduleGraph {
             return Err(ScheduleBuildError::Uninitialized);
         }
 
-        // move systems out of old schedule
-        for ((id, system), conditions) in schedule
-            .system_ids
-            .drain(..)
-            .zip(schedule.systems.drain(..))
-            .zip(schedule.system_conditions.drain(..))
-        {
-            self.systems[id.index()].inner = Some(system);
-            self.system_conditions[id.index()] = conditions;
-        }
-
-        for (id, conditions) in schedule
-            .set_ids
-            .drain(..)
-            .zip(schedule.set_conditions.drain(..))
-        {
-            self.system_set_conditions[id.index()] = conditions;
-        }
+        self.return_systems(schedule);
 
         *schedule = self.build_schedule(components, schedule_label, ignored_ambiguities)?;
 
@@ -1489,6 +1758,34 @@ impl ScheduleGraph {
 
         Ok(())
     }
+
+    /// Moves the systems and conditions of `schedule` back into the graph, leaving it empty.
+    ///
+    /// Systems and sets removed from the graph in the meantime are dropped.
+    fn return_systems(&mut self, schedule: &mut SystemSchedule) {
+        for ((id, system), conditions) in schedule
+            .system_ids
+            .drain(..)
+            .zip(schedule.systems.drain(..))
+            .zip(schedule.system_conditions.drain(..))
+        {
+            let node = &mut self.systems[id.index()];
+            if !node.removed {
+                node.inner = Some(system);
+                self.system_conditions[id.index()] = conditions;
+            }
+        }
+
+        for (id, conditions) in schedule
+            .set_ids
+            .drain(..)
+            .zip(schedule.set_conditions.drain(..))
+        {
+            if !self.system_sets[id.index()].removed {
+                self.system_set_conditions[id.index()] = conditions;
+            }
+        }
+    }
 }
 
 /// Values returned by [`ScheduleGraph::process_configs`]
@@ -2026,7 +2323,7 @@ mod tests {
 
         schedule.configure_sets(Set.run_if(|| false));
         schedule.add_systems(
-            (|| panic!("This system must not run"))
+            (|| -> () { panic!("This system must not run") })
                 .ambiguous_with(|| ())
                 .in_set(Set),
         );
@@ -2122,6 +2419,196 @@ mod tests {
         assert_eq!(schedule.executable.systems.len(), 2);
     }
 
+    mod removal {
+        use super::*;
+        use crate::{
+            prelude::ResMut,
+            schedule::{LogLevel, NodeId},
+        };
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<&'static str>);
+
+        fn a(mut log: ResMut<Log>) {
+            log.0.push("a");
+        }
+
+        fn b(mut log: ResMut<Log>) {
+            log.0.push("b");
+        }
+
+        fn c(mut log: ResMut<Log>) {
+            log.0.push("c");
+        }
+
+        fn d(mut log: ResMut<Log>) {
+            log.0.push("d");
+        }
+
+        fn run(schedule: &mut Schedule, world: &mut World) -> Vec<&'static str> {
+            world.insert_resource(Log::default());
+            schedule.run(world);
+            world.remove_resource::<Log>().unwrap().0
+        }
+
+        fn system_id(schedule: &Schedule, name: &str) -> NodeId {
+            schedule
+                .systems()
+                .unwrap()
+                .find(|(_, system)| system.name().ends_with(name))
+                .map(|(id, _)| id)
+                .unwrap()
+        }
+
+        #[test]
+        fn remove_system_at() {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            // c is added first, so that it would run first without its ordering
+            schedule.add_systems((c.after(b), b.after(a), a));
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+
+            let id = system_id(&schedule, "::b");
+            assert!(schedule.remove_system_at(id));
+            assert!(!schedule.remove_system_at(id));
+            assert!(schedule.systems().is_err());
+            // the ordering of a before c went through the removed system
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "c"]);
+            assert!(schedule.systems().unwrap().all(|(system, _)| system != id));
+            assert_eq!(schedule.systems_len(), 2);
+            assert!(schedule.graph().get_system_at(id).is_none());
+            assert!(!schedule.graph().dependency().graph().contains_node(id));
+        }
+
+        #[test]
+        fn remove_systems_by_type() {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.add_systems(((a, b).chain(), (b, c).chain()).chain());
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "b", "c"]);
+
+            assert_eq!(schedule.remove_systems_in_set(b), 2);
+            assert!(schedule.graph().set_id(b).is_none());
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "c"]);
+
+            // the type set is recreated when the system is added again
+            schedule.add_systems(b.before(a).before(c));
+            assert_eq!(run(&mut schedule, &mut world)[0], "b");
+        }
+
+        #[test]
+        fn remove_set_keeps_systems_in_parent_sets() {
+            #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+            enum Set {
+                Outer,
+                Inner,
+            }
+
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.configure_sets((
+                Set::Outer.run_if(|| false),
+                Set::Inner.in_set(Set::Outer).before(c),
+            ));
+            schedule.add_systems((a.in_set(Set::Inner), c));
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+
+            assert!(schedule.remove_set(Set::Inner));
+            assert!(!schedule.remove_set(Set::Inner));
+            assert!(schedule.graph().set_id(Set::Inner).is_none());
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+
+            assert!(schedule.remove_set(Set::Outer));
+            assert_eq!(run(&mut schedule, &mut world).len(), 2);
+        }
+
+        #[test]
+        fn remove_set_keeps_ordering_through_it() {
+            #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+            struct Set;
+
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.configure_sets(Set.after(a).before(c));
+            schedule.add_systems((c, b.in_set(Set), a));
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+
+            assert!(schedule.remove_set(Set));
+            assert!(schedule.systems().is_err());
+            // b isn't ordered anymore, but a still runs before c
+            let log = run(&mut schedule, &mut world);
+            let position = |name| log.iter().position(|&system| system == name).unwrap();
+            assert_eq!(log.len(), 3);
+            assert!(position("a") < position("c"));
+
+            assert_eq!(schedule.remove_systems_in_set(b), 1);
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "c"]);
+        }
+
+        #[test]
+        fn remove_systems_in_set() {
+            #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+            struct Set;
+
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.add_systems(((a, b).in_set(Set), c).chain());
+            assert_eq!(schedule.remove_systems_in_set(Set), 2);
+            assert!(schedule.graph().set_id(Set).is_some());
+            assert_eq!(run(&mut schedule, &mut world), vec!["c"]);
+        }
+
+        #[test]
+        fn replace_system_at() {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.add_systems((a, b.run_if(|| true), c).chain());
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "b", "c"]);
+
+            let id = system_id(&schedule, "::b");
+            let previous = schedule
+                .replace_system_at(id, |mut log: ResMut<Log>| log.0.push("d"))
+                .unwrap();
+            assert!(previous.name().ends_with("::b"));
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "d", "c"]);
+            assert!(schedule.replace_system_at(NodeId::System(42), b).is_none());
+        }
+
+        #[test]
+        fn replace_system_at_moves_type_set() {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            // c is ordered after the type set of d before any d is added
+            schedule.add_systems(((a, b).chain(), c.after(d)));
+            assert_eq!(run(&mut schedule, &mut world).len(), 3);
+
+            let id = system_id(&schedule, "::b");
+            schedule.replace_system_at(id, d);
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "d", "c"]);
+
+            assert_eq!(schedule.remove_systems_in_set(b), 0);
+            assert_eq!(run(&mut schedule, &mut world), vec!["a", "d", "c"]);
+            assert_eq!(schedule.remove_systems_in_set(d), 1);
+            assert_eq!(run(&mut schedule, &mut world).len(), 2);
+        }
+
+        #[test]
+        fn removal_clears_ambiguities() {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..Default::default()
+            });
+            schedule.add_systems((a, b));
+            assert!(schedule.initialize(&mut world).is_err());
+
+            assert_eq!(schedule.remove_systems_in_set(b), 1);
+            assert!(schedule.initialize(&mut world).is_ok());
+            assert!(schedule.graph().conflicting_systems().is_empty());
+        }
+    }
+
     mod no_sync_edges {
         use super::*;
 
@@ -0,0 +1,166 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use bevy_utils::all_tuples;
+
+use crate::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, Schedule};
+use crate::world::World;
+
+use super::{
+    internal_apply_state_transition, register_transition_schedules, ApplyStateTransition,
+    EnterSchedules, ExitSchedules, State, States, TransitionSchedules,
+};
+
+/// A state whose value is automatically computed based on the values of other [`States`].
+///
+/// A computed state is a state that is deterministically derived from a set of `SourceStates`.
+/// The [`StateSet`] is passed into the `compute` method whenever one of them changes, and the
+/// result becomes the state's value. Returning `None` removes the [`State<Self>`] resource, in which
+/// case the state does not exist.
+///
+/// Computed states cannot be set through [`NextState`](super::NextState). Their
+/// [`OnExit`](super::OnExit) schedules run before the ones of their sources, and their
+/// [`OnTransition`](super::OnTransition) and [`OnEnter`](super::OnEnter) schedules run after
+/// the ones of their sources.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum AppState {
+///     #[default]
+///     Menu,
+///     Playing { paused: bool },
+/// }
+///
+/// /// Exists whenever the game is being played, paused or not.
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// struct InGame;
+///
+/// impl ComputedStates for InGame {
+///     type SourceStates = AppState;
+///
+///     fn compute(sources: AppState) -> Option<Self> {
+///         match sources {
+///             AppState::Playing { .. } => Some(InGame),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait ComputedStates: 'static + Send + Sync + Clone + PartialEq + Eq + Hash + Debug {
+    /// The set of states from which `Self` is derived.
+    ///
+    /// This can be a single [`States`] type, an `Option<S>` of one to also compute the state while
+    /// `S` does not exist, or a tuple of them.
+    type SourceStates: StateSet;
+
+    /// Computes the value of the state from its `sources`.
+    /// Returning `None` removes the state.
+    fn compute(sources: Self::SourceStates) -> Option<Self>;
+
+    /// Adds the systems computing this state and running its transition schedules to `schedule`.
+    ///
+    /// This is usually done by `App::add_computed_state`.
+    fn register_computed_state_systems(schedule: &mut Schedule) {
+        Self::SourceStates::configure_dependent_sets::<Self>(schedule);
+        schedule.add_systems(
+            apply_computed_state::<Self>.in_set(ApplyStateTransition::<Self>::apply()),
+        );
+        register_transition_schedules::<Self>(schedule);
+    }
+}
+
+impl<S: ComputedStates> States for S {}
+
+fn apply_computed_state<S: ComputedStates>(world: &mut World) {
+    let computed = S::SourceStates::get_states(world).and_then(S::compute);
+    let current = world
+        .get_resource::<State<S>>()
+        .map(|state| state.get().clone());
+    internal_apply_state_transition(world, current, computed);
+}
+
+mod sealed {
+    /// Sealed trait used to prevent external implementations of [`StateSet`](super::StateSet).
+    pub trait StateSetSealed {}
+}
+
+/// A single source state of a [`StateSet`].
+///
+/// This is implemented for every [`States`] type, which has to exist for the dependent state to
+/// be computed, and for `Option<S>`, which is `None` while `S` does not exist.
+pub trait InnerStateSet: Sized {
+    /// The underlying [`States`] type.
+    type RawState: States;
+
+    /// Converts the current [`State`] resource into the value passed to the dependent state,
+    /// or returns `None` if the dependent state cannot exist.
+    fn convert_to_usable_state(wrapped: Option<&State<Self::RawState>>) -> Option<Self>;
+}
+
+impl<S: States> InnerStateSet for S {
+    type RawState = Self;
+
+    fn convert_to_usable_state(wrapped: Option<&State<Self::RawState>>) -> Option<Self> {
+        wrapped.map(|state| state.get().clone())
+    }
+}
+
+impl<S: States> InnerStateSet for Option<S> {
+    type RawState = S;
+
+    fn convert_to_usable_state(wrapped: Option<&State<Self::RawState>>) -> Option<Self> {
+        Some(wrapped.map(|state| state.get().clone()))
+    }
+}
+
+/// A [`States`] type, or a tuple of them, that [`ComputedStates`] and
+/// [`SubStates`](super::SubStates) can depend on.
+pub trait StateSet: sealed::StateSetSealed + Sized {
+    /// Reads the current value of every source state, or returns `None` if a required one does not exist.
+    fn get_states(world: &World) -> Option<Self>;
+
+    /// Orders the transition systems of the dependent state `T` relative to those of every state in this set.
+    fn configure_dependent_sets<T: States>(schedule: &mut Schedule);
+}
+
+impl<S: InnerStateSet> sealed::StateSetSealed for S {}
+
+impl<S: InnerStateSet> StateSet for S {
+    fn get_states(world: &World) -> Option<Self> {
+        S::convert_to_usable_state(world.get_resource::<State<S::RawState>>())
+    }
+
+    fn configure_dependent_sets<T: States>(schedule: &mut Schedule) {
+        configure_dependent_sets::<T, S::RawState>(schedule);
+    }
+}
+
+/// Orders the transition systems of `T` after those of its source state `S`,
+/// except for [`OnExit`](super::OnExit) schedules which run in reverse order.
+fn configure_dependent_sets<T: States, S: States>(schedule: &mut Schedule) {
+    schedule.configure_sets((
+        ApplyStateTransition::<T>::apply().after(ApplyStateTransition::<S>::apply()),
+        ExitSchedules::<T>::default().before(ExitSchedules::<S>::default()),
+        TransitionSchedules::<T>::default().after(TransitionSchedules::<S>::default()),
+        EnterSchedules::<T>::default().after(EnterSchedules::<S>::default()),
+    ));
+}
+
+macro_rules! impl_state_set_sealed_tuples {
+    ($($param: ident),*) => {
+        impl<$($param: InnerStateSet),*> sealed::StateSetSealed for ($($param,)*) {}
+
+        impl<$($param: InnerStateSet),*> StateSet for ($($param,)*) {
+            fn get_states(world: &World) -> Option<Self> {
+                Some(($($param::convert_to_usable_state(world.get_resource::<State<$param::RawState>>())?,)*))
+            }
+
+            fn configure_dependent_sets<T: States>(schedule: &mut Schedule) {
+                $(configure_dependent_sets::<T, $param::RawState>(schedule);)*
+            }
+        }
+    };
+}
+
+all_tuples!(impl_state_set_sealed_tuples, 1, 15, S);
@@ -0,0 +1,655 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate as bevy_ecs;
+use crate::change_detection::DetectChangesMut;
+use crate::event::{Event, Events, ManualEventReader};
+use crate::prelude::FromWorld;
+#[cfg(feature = "bevy_reflect")]
+use crate::reflect::ReflectResource;
+use crate::schedule::{
+    IntoSystemConfigs, IntoSystemSetConfigs, Schedule, ScheduleLabel, SystemSet,
+};
+use crate::system::{Local, Resource};
+use crate::world::World;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::std_traits::ReflectDefault;
+
+mod computed_states;
+mod state_scoped;
+mod sub_states;
+
+pub use computed_states::*;
+pub use state_scoped::*;
+pub use sub_states::*;
+
+pub use bevy_ecs_macros::{States, SubStates};
+
+/// Types that can define world-wide states in a finite-state machine.
+///
+/// The [`Default`] trait defines the starting state.
+/// Multiple states can be defined for the same world,
+/// allowing you to classify the state of the world across orthogonal dimensions.
+/// You can access the current state of type `T` with the [`State<T>`] resource,
+/// and the queued state with the [`NextState<T>`] resource.
+///
+/// State transitions typically occur in the [`OnEnter<T::Variant>`] and [`OnExit<T::Variant>`] schedules,
+/// which can be run via the [`apply_state_transition::<T>`] system.
+///
+/// Besides freely mutable states, which derive [`States`] and are changed through [`NextState<T>`],
+/// states can be derived from other states with [`ComputedStates`], or only exist while
+/// their source states have a given value with [`SubStates`].
+///
+/// # Example
+///
+/// ```
+/// use bevy_ecs::prelude::States;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///  #[default]
+///   MainMenu,
+///   SettingsMenu,
+///   InGame,
+/// }
+///
+/// ```
+pub trait States: 'static + Send + Sync + Clone + PartialEq + Eq + Hash + Debug {}
+
+/// A [`States`] type whose value can be set directly through [`NextState<S>`].
+///
+/// This is implemented by `#[derive(States)]` and `#[derive(SubStates)]`, but not by
+/// [`ComputedStates`], whose value is always derived from their source states.
+pub trait FreelyMutableState: States {}
+
+/// The label of a [`Schedule`](super::Schedule) that runs whenever [`State<S>`]
+/// enters this state.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnEnter<S: States>(pub S);
+
+/// The label of a [`Schedule`](super::Schedule) that runs whenever [`State<S>`]
+/// exits this state.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnExit<S: States>(pub S);
+
+/// The label of a [`Schedule`](super::Schedule) that **only** runs whenever [`State<S>`]
+/// exits the `from` state, AND enters the `to` state.
+///
+/// Systems added to this schedule are always ran *after* [`OnExit`], and *before* [`OnEnter`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnTransition<S: States> {
+    /// The state being exited.
+    pub from: S,
+    /// The state being entered.
+    pub to: S,
+}
+
+/// A finite-state machine whose transitions have associated schedules
+/// ([`OnEnter(state)`] and [`OnExit(state)`]).
+///
+/// The current state value can be accessed through this resource. To *change* the state,
+/// queue a transition in the [`NextState<S>`] resource, and it will be applied by the next
+/// [`apply_state_transition::<S>`] system.
+///
+/// The starting state is defined via the [`Default`] implementation for `S`.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     SettingsMenu,
+///     InGame,
+/// }
+///
+/// fn game_logic(game_state: Res<State<GameState>>) {
+///     match game_state.get() {
+///         GameState::InGame => {
+///             // Run game logic here...
+///         },
+///         _ => {},
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct State<S: States>(S);
+
+impl<S: States> State<S> {
+    /// Creates a new state with a specific value.
+    ///
+    /// To change the state use [`NextState<S>`] rather than using this to modify the `State<S>`.
+    pub fn new(state: S) -> Self {
+        Self(state)
+    }
+
+    /// Get the current state.
+    pub fn get(&self) -> &S {
+        &self.0
+    }
+}
+
+impl<S: States + FromWorld> FromWorld for State<S> {
+    fn from_world(world: &mut World) -> Self {
+        Self(S::from_world(world))
+    }
+}
+
+impl<S: States> PartialEq<S> for State<S> {
+    fn eq(&self, other: &S) -> bool {
+        self.get() == other
+    }
+}
+
+impl<S: States> Deref for State<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+/// The next state of [`State<S>`].
+///
+/// To queue a transition, just set the contained value to `Some(next_state)`.
+/// Note that these transitions can be overridden by other systems:
+/// only the actual value of this resource at the time of [`apply_state_transition`] matters.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     SettingsMenu,
+///     InGame,
+/// }
+///
+/// fn start_game(mut next_game_state: ResMut<NextState<GameState>>) {
+///     next_game_state.set(GameState::InGame);
+/// }
+/// ```
+#[derive(Resource, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource, Default)
+)]
+pub struct NextState<S: FreelyMutableState>(pub Option<S>);
+
+impl<S: FreelyMutableState> Default for NextState<S> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<S: FreelyMutableState> NextState<S> {
+    /// Tentatively set a planned state transition to `Some(state)`.
+    pub fn set(&mut self, state: S) {
+        self.0 = Some(state);
+    }
+}
+
+/// Event sent when any state transition of `S` happens.
+///
+/// The state is `None` when it does not exist, which is the case before a state is first entered,
+/// and while the sources of a [`ComputedStates`] or [`SubStates`] do not allow it to exist.
+///
+/// If you know exactly what state you want to respond to ahead of time, consider [`OnEnter`], [`OnTransition`], or [`OnExit`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Event)]
+pub struct StateTransitionEvent<S: States> {
+    /// the state we were in before
+    pub before: Option<S>,
+    /// the state we're in now
+    pub after: Option<S>,
+}
+
+/// The steps of a state transition, in the order in which they run.
+///
+/// All the states registered in a schedule with [`register_state_transition_systems`],
+/// [`ComputedStates::register_computed_state_systems`] or [`SubStates::register_sub_state_systems`]
+/// first apply their new values, and only then run their transition schedules. This guarantees
+/// that the schedules of every state observe the new values of all the other states.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StateTransitionSteps {
+    /// The new values of all states are applied, source states before the states depending on them.
+    DependentTransitions,
+    /// [`OnExit`] schedules run, dependent states before their source states.
+    ExitSchedules,
+    /// [`OnTransition`] schedules run, source states before the states depending on them.
+    TransitionSchedules,
+    /// [`OnEnter`] schedules run, source states before the states depending on them.
+    EnterSchedules,
+}
+
+/// The [`SystemSet`] in which the new value of the state `S` is applied,
+/// within [`StateTransitionSteps::DependentTransitions`].
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ApplyStateTransition<S: States>(PhantomData<S>);
+
+impl<S: States> ApplyStateTransition<S> {
+    /// Returns the [`SystemSet`] applying the transitions of the state `S`.
+    pub fn apply() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ExitSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for ExitSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct TransitionSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for TransitionSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct EnterSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for EnterSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Run the enter schedule (if it exists) for the current state.
+pub fn run_enter_schedule<S: States>(world: &mut World) {
+    let Some(state) = world.get_resource::<State<S>>() else {
+        return;
+    };
+    world.try_run_schedule(OnEnter(state.0.clone())).ok();
+}
+
+/// If a new state is queued in [`NextState<S>`], this system:
+/// - Takes the new state value from [`NextState<S>`] and updates [`State<S>`].
+/// - Sends a relevant [`StateTransitionEvent`]
+///
+/// The [`OnExit(exited_state)`], [`OnTransition { from: exited_state, to: entered_state }`](OnTransition)
+/// and [`OnEnter(entered_state)`] schedules are then run by the systems added with
+/// [`register_state_transition_systems`], once all dependent states have been updated.
+pub fn apply_state_transition<S: FreelyMutableState>(world: &mut World) {
+    // We want to take the `NextState` resource,
+    // but only mark it as changed if it wasn't empty.
+    let Some(mut next_state_resource) = world.get_resource_mut::<NextState<S>>() else {
+        return;
+    };
+    let Some(entered) = next_state_resource.bypass_change_detection().0.take() else {
+        return;
+    };
+    next_state_resource.set_changed();
+    let exited = world
+        .get_resource::<State<S>>()
+        .map(|state| state.get().clone());
+    internal_apply_state_transition(world, exited, Some(entered));
+}
+
+/// Updates (or inserts, or removes) the [`State<S>`] resource from `before` to `after`,
+/// and sends the matching [`StateTransitionEvent`] if they differ.
+pub(crate) fn internal_apply_state_transition<S: States>(
+    world: &mut World,
+    before: Option<S>,
+    after: Option<S>,
+) {
+    if before == after {
+        return;
+    }
+    match after.clone() {
+        Some(entered) => match world.get_resource_mut::<State<S>>() {
+            Some(mut state_resource) => state_resource.0 = entered,
+            None => world.insert_resource(State(entered)),
+        },
+        None => {
+            world.remove_resource::<State<S>>();
+        }
+    }
+    world.send_event(StateTransitionEvent { before, after });
+}
+
+/// Adds the systems applying [`NextState<S>`] and running the transition schedules of the
+/// freely mutable state `S` to `schedule`.
+///
+/// This is usually done by `App::init_state` or `App::insert_state`, which also send the
+/// initial [`StateTransitionEvent`] that enters the starting state.
+pub fn register_state_transition_systems<S: FreelyMutableState>(schedule: &mut Schedule) {
+    schedule.add_systems(apply_state_transition::<S>.in_set(ApplyStateTransition::<S>::apply()));
+    register_transition_schedules::<S>(schedule);
+}
+
+/// Configures the [`StateTransitionSteps`] of `schedule` and adds the systems running the
+/// [`OnExit`], [`OnTransition`] and [`OnEnter`] schedules of `S`.
+pub(crate) fn register_transition_schedules<S: States>(schedule: &mut Schedule) {
+    schedule
+        .configure_sets(
+            (
+                StateTransitionSteps::DependentTransitions,
+                StateTransitionSteps::ExitSchedules,
+                StateTransitionSteps::TransitionSchedules,
+                StateTransitionSteps::EnterSchedules,
+            )
+                .chain(),
+        )
+        .configure_sets((
+            ApplyStateTransition::<S>::apply().in_set(StateTransitionSteps::DependentTransitions),
+            ExitSchedules::<S>::default().in_set(StateTransitionSteps::ExitSchedules),
+            TransitionSchedules::<S>::default().in_set(StateTransitionSteps::TransitionSchedules),
+            EnterSchedules::<S>::default().in_set(StateTransitionSteps::EnterSchedules),
+        ))
+        .add_systems((
+            run_exit_schedule::<S>.in_set(ExitSchedules::<S>::default()),
+            run_transition_schedule::<S>.in_set(TransitionSchedules::<S>::default()),
+            run_enter_schedule_on_transition::<S>.in_set(EnterSchedules::<S>::default()),
+        ));
+}
+
+/// Returns the transition of `S` since `reader` was last used, merging all the transitions
+/// that happened in between, or `None` if the state did not change.
+fn last_transition<S: States>(
+    world: &World,
+    reader: &mut ManualEventReader<StateTransitionEvent<S>>,
+) -> Option<StateTransitionEvent<S>> {
+    let events = world.get_resource::<Events<StateTransitionEvent<S>>>()?;
+    let mut transitions = reader.read(events);
+    let first = transitions.next()?.clone();
+    let after = transitions
+        .last()
+        .map_or_else(|| first.after.clone(), |last| last.after.clone());
+    (first.before != after).then_some(StateTransitionEvent {
+        before: first.before,
+        after,
+    })
+}
+
+fn run_exit_schedule<S: States>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<StateTransitionEvent<S>>>,
+) {
+    let Some(StateTransitionEvent {
+        before: Some(exited),
+        ..
+    }) = last_transition(world, &mut reader)
+    else {
+        return;
+    };
+    world.try_run_schedule(OnExit(exited)).ok();
+}
+
+fn run_transition_schedule<S: States>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<StateTransitionEvent<S>>>,
+) {
+    let Some(StateTransitionEvent {
+        before: Some(from),
+        after: Some(to),
+    }) = last_transition(world, &mut reader)
+    else {
+        return;
+    };
+    world.try_run_schedule(OnTransition { from, to }).ok();
+}
+
+fn run_enter_schedule_on_transition<S: States>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<StateTransitionEvent<S>>>,
+) {
+    let Some(StateTransitionEvent {
+        after: Some(entered),
+        ..
+    }) = last_transition(world, &mut reader)
+    else {
+        return;
+    };
+    world.try_run_schedule(OnEnter(entered)).ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::schedule::{
+        register_state_scoped_entities, register_state_transition_systems, FreelyMutableState,
+        ScheduleLabel,
+    };
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    enum SimpleState {
+        #[default]
+        A,
+        B(bool),
+    }
+
+    #[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+    enum TestComputedState {
+        BisTrue,
+        BisFalse,
+    }
+
+    impl ComputedStates for TestComputedState {
+        type SourceStates = Option<SimpleState>;
+
+        fn compute(sources: Option<SimpleState>) -> Option<Self> {
+            sources.and_then(|source| match source {
+                SimpleState::A => None,
+                SimpleState::B(value) => Some(if value { Self::BisTrue } else { Self::BisFalse }),
+            })
+        }
+    }
+
+    #[derive(SubStates, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    #[source(TestComputedState = TestComputedState::BisTrue)]
+    enum SubState {
+        #[default]
+        One,
+        Two,
+    }
+
+    #[derive(Resource, Default)]
+    struct TransitionLog(Vec<&'static str>);
+
+    fn log(entry: &'static str) -> impl FnMut(ResMut<TransitionLog>) {
+        move |mut log: ResMut<TransitionLog>| log.0.push(entry)
+    }
+
+    fn add_state<S: FreelyMutableState>(world: &mut World, schedule: &mut Schedule, state: S) {
+        world.init_resource::<Events<StateTransitionEvent<S>>>();
+        world.init_resource::<NextState<S>>();
+        world.insert_resource(State::new(state.clone()));
+        world.send_event(StateTransitionEvent {
+            before: None,
+            after: Some(state),
+        });
+        register_state_transition_systems::<S>(schedule);
+    }
+
+    fn setup() -> (World, Schedule) {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        add_state(&mut world, &mut schedule, SimpleState::A);
+        world.init_resource::<Events<StateTransitionEvent<TestComputedState>>>();
+        TestComputedState::register_computed_state_systems(&mut schedule);
+        world.init_resource::<Events<StateTransitionEvent<SubState>>>();
+        world.init_resource::<NextState<SubState>>();
+        SubState::register_sub_state_systems(&mut schedule);
+        (world, schedule)
+    }
+
+    #[test]
+    fn computed_state_follows_its_source() {
+        let (mut world, mut schedule) = setup();
+
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<State<TestComputedState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<State<TestComputedState>>().get(),
+            &TestComputedState::BisTrue
+        );
+
+        world.insert_resource(NextState(Some(SimpleState::B(false))));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<State<TestComputedState>>().get(),
+            &TestComputedState::BisFalse
+        );
+
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<State<TestComputedState>>());
+    }
+
+    #[test]
+    fn sub_state_exists_only_while_its_source_matches() {
+        let (mut world, mut schedule) = setup();
+
+        // Queued values are discarded while the sub-state does not exist.
+        world.insert_resource(NextState(Some(SubState::Two)));
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<State<SubState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<State<SubState>>().get(), &SubState::One);
+
+        world.insert_resource(NextState(Some(SubState::Two)));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<State<SubState>>().get(), &SubState::Two);
+
+        // Changing the source without leaving the matching value keeps the sub-state.
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<State<SubState>>().get(), &SubState::Two);
+
+        world.insert_resource(NextState(Some(SimpleState::B(false))));
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<State<SubState>>());
+
+        // The sub-state is re-created with its initial value.
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<State<SubState>>().get(), &SubState::One);
+    }
+
+    #[test]
+    fn transition_schedules_are_ordered_by_dependency() {
+        let (mut world, mut schedule) = setup();
+        world.init_resource::<TransitionLog>();
+
+        let mut add_log_schedule = |label, entry| {
+            let mut log_schedule = Schedule::new(label);
+            log_schedule.add_systems(log(entry));
+            world.add_schedule(log_schedule);
+        };
+        add_log_schedule(OnEnter(SimpleState::A).intern(), "enter A");
+        add_log_schedule(OnExit(SimpleState::A).intern(), "exit A");
+        add_log_schedule(OnEnter(SimpleState::B(true)).intern(), "enter B");
+        add_log_schedule(OnExit(SimpleState::B(true)).intern(), "exit B");
+        add_log_schedule(
+            OnTransition {
+                from: SimpleState::A,
+                to: SimpleState::B(true),
+            }
+            .intern(),
+            "A to B",
+        );
+        add_log_schedule(
+            OnEnter(TestComputedState::BisTrue).intern(),
+            "enter computed",
+        );
+        add_log_schedule(OnExit(TestComputedState::BisTrue).intern(), "exit computed");
+        add_log_schedule(OnEnter(SubState::One).intern(), "enter sub");
+        add_log_schedule(OnExit(SubState::One).intern(), "exit sub");
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<TransitionLog>().0, vec!["enter A"]);
+
+        world.resource_mut::<TransitionLog>().0.clear();
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<TransitionLog>().0,
+            vec!["exit A", "A to B", "enter B", "enter computed", "enter sub"]
+        );
+
+        world.resource_mut::<TransitionLog>().0.clear();
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<TransitionLog>().0,
+            vec!["exit sub", "exit computed", "exit B", "enter A"]
+        );
+    }
+
+    #[test]
+    fn transitions_in_the_same_run_are_merged() {
+        let (mut world, mut schedule) = setup();
+        world.init_resource::<TransitionLog>();
+        let mut enter_a = Schedule::new(OnEnter(SimpleState::A));
+        enter_a.add_systems(log("enter A"));
+        world.add_schedule(enter_a);
+        let mut exit_a = Schedule::new(OnExit(SimpleState::A));
+        exit_a.add_systems(log("exit A"));
+        world.add_schedule(exit_a);
+        let mut enter_b = Schedule::new(OnEnter(SimpleState::B(false)));
+        enter_b.add_systems(log("enter B"));
+        world.add_schedule(enter_b);
+
+        // The initial state is replaced before it was ever entered.
+        world.insert_resource(NextState(Some(SimpleState::B(false))));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<TransitionLog>().0, vec!["enter B"]);
+    }
+
+    #[test]
+    fn state_scoped_entities_are_despawned_on_exit() {
+        let (mut world, mut schedule) = setup();
+        register_state_scoped_entities::<SimpleState>(&mut schedule, |entity| entity.despawn());
+        register_state_scoped_entities::<SubState>(&mut schedule, |entity| entity.despawn());
+
+        #[derive(Resource)]
+        struct SeenOnExit(bool);
+
+        let scoped_a = world.spawn(StateScoped(SimpleState::A)).id();
+        world.add_schedule(Schedule::new(OnExit(SimpleState::A)));
+        world.schedule_scope(OnExit(SimpleState::A), |_, schedule| {
+            schedule.add_systems(move |world: &mut World| {
+                let seen = world.get_entity(scoped_a).is_some();
+                world.insert_resource(SeenOnExit(seen));
+            });
+        });
+        let scoped_b = world.spawn(StateScoped(SimpleState::B(true))).id();
+        let scoped_sub = world.spawn(StateScoped(SubState::One)).id();
+        let unscoped = world.spawn_empty().id();
+
+        schedule.run(&mut world);
+        assert!(world.get_entity(scoped_a).is_some());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        schedule.run(&mut world);
+        assert!(world.resource::<SeenOnExit>().0);
+        assert!(world.get_entity(scoped_a).is_none());
+        assert!(world.get_entity(scoped_b).is_some());
+        assert!(world.get_entity(scoped_sub).is_some());
+
+        // Leaving the source state also exits the sub-state.
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        schedule.run(&mut world);
+        assert!(world.get_entity(scoped_b).is_none());
+        assert!(world.get_entity(scoped_sub).is_none());
+        assert!(world.get_entity(unscoped).is_some());
+    }
+}
@@ -0,0 +1,91 @@
+use crate as bevy_ecs;
+use crate::component::Component;
+use crate::entity::Entity;
+use crate::event::ManualEventReader;
+use crate::schedule::{IntoSystemConfigs, Schedule};
+use crate::system::Local;
+use crate::world::{EntityWorldMut, World};
+
+use super::{last_transition, run_exit_schedule, ExitSchedules, StateTransitionEvent, States};
+
+/// Entities marked with this component will be despawned when the state `S` exits the given value.
+///
+/// The cleanup only happens for state types that opted into it, either with
+/// `App::enable_state_scoped_entities::<S>()` from `bevy_hierarchy`, which also despawns the
+/// descendants of the scoped entities, or with [`register_state_scoped_entities::<S>`]
+/// on the schedule running the transitions of `S`. It runs after the [`OnExit`](super::OnExit)
+/// schedule of the exited state, so its systems can still access the scoped entities.
+///
+/// Scoped entities are despawned together with the entities linked to them through a
+/// [`RelationshipTarget`](crate::relationship::RelationshipTarget) with `linked_spawn`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     InGame,
+/// }
+///
+/// # #[derive(Component)]
+/// # struct Player;
+/// fn spawn_player(mut commands: Commands) {
+///     commands.spawn((
+///         StateScoped(GameState::InGame),
+///         Player,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct StateScoped<S: States>(pub S);
+
+/// Despawns the entities whose [`StateScoped<S>`] matches the value of `S` exited since
+/// `reader` was last used, by calling `despawn` on each of them.
+fn clear_state_scoped_entities<S: States>(
+    world: &mut World,
+    reader: &mut ManualEventReader<StateTransitionEvent<S>>,
+    despawn: fn(EntityWorldMut),
+) {
+    let Some(StateTransitionEvent {
+        before: Some(exited),
+        ..
+    }) = last_transition(world, reader)
+    else {
+        return;
+    };
+    let scoped: Vec<Entity> = world
+        .query::<(Entity, &StateScoped<S>)>()
+        .iter(world)
+        .filter(|(_, scope)| scope.0 == exited)
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in scoped {
+        // An entity may already have been despawned along with a previous one.
+        if let Some(entity) = world.get_entity_mut(entity) {
+            despawn(entity);
+        }
+    }
+}
+
+/// Adds a system despawning the entities scoped to the exited values of `S` to `schedule`,
+/// right after the [`OnExit`](super::OnExit) schedules of `S` are run.
+///
+/// Each scoped entity is passed to `despawn`, which usually calls [`EntityWorldMut::despawn`],
+/// or a recursive despawn when the entities may have a hierarchy.
+///
+/// The transitions of `S` must be registered in the same schedule.
+/// This is usually done by `App::enable_state_scoped_entities` from `bevy_hierarchy`.
+pub fn register_state_scoped_entities<S: States>(
+    schedule: &mut Schedule,
+    despawn: fn(EntityWorldMut),
+) {
+    schedule.add_systems(
+        (move |world: &mut World,
+               mut reader: Local<ManualEventReader<StateTransitionEvent<S>>>| {
+            clear_state_scoped_entities(world, &mut reader, despawn);
+        })
+        .in_set(ExitSchedules::<S>::default())
+        .after(run_exit_schedule::<S>),
+    );
+}
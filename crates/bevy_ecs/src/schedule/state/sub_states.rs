@@ -0,0 +1,73 @@
+use crate::change_detection::DetectChangesMut;
+use crate::schedule::{IntoSystemConfigs, Schedule};
+use crate::world::World;
+
+use super::{
+    internal_apply_state_transition, register_transition_schedules, ApplyStateTransition,
+    FreelyMutableState, NextState, State, StateSet,
+};
+
+/// A [`States`](super::States) type that only exists while its source states have given values.
+///
+/// While it exists, a sub-state can be changed through [`NextState<Self>`] like any other
+/// [`FreelyMutableState`]. When its sources stop matching, the [`State<Self>`] resource is removed
+/// (and any queued [`NextState<Self>`] is discarded). When they match again, the state is
+/// re-created with the value returned by [`SubStates::should_exist`].
+///
+/// The simplest way to implement this trait is with the derive macro, which uses the `Default`
+/// value of the state as its initial value:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum AppState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, SubStates)]
+/// #[source(AppState = AppState::InGame)]
+/// enum GamePhase {
+///     #[default]
+///     Setup,
+///     Battle,
+/// }
+/// ```
+pub trait SubStates: FreelyMutableState {
+    /// The set of states that determine whether this state exists.
+    ///
+    /// This can be a single [`States`](super::States) type, an `Option<S>` of one, or a tuple of them.
+    type SourceStates: StateSet;
+
+    /// Returns the initial value of the state if it should exist with the given `sources`,
+    /// or `None` if it should not exist.
+    fn should_exist(sources: Self::SourceStates) -> Option<Self>;
+
+    /// Adds the systems updating this state and running its transition schedules to `schedule`.
+    ///
+    /// This is usually done by `App::add_sub_state`.
+    fn register_sub_state_systems(schedule: &mut Schedule) {
+        Self::SourceStates::configure_dependent_sets::<Self>(schedule);
+        schedule.add_systems(apply_sub_state::<Self>.in_set(ApplyStateTransition::<Self>::apply()));
+        register_transition_schedules::<Self>(schedule);
+    }
+}
+
+fn apply_sub_state<S: SubStates>(world: &mut World) {
+    let initial = S::SourceStates::get_states(world).and_then(S::should_exist);
+    let next = world
+        .get_resource_mut::<NextState<S>>()
+        .and_then(|mut next_state_resource| {
+            let next = next_state_resource.bypass_change_detection().0.take();
+            if next.is_some() {
+                next_state_resource.set_changed();
+            }
+            next
+        });
+    let current = world
+        .get_resource::<State<S>>()
+        .map(|state| state.get().clone());
+    let new = initial.map(|initial| next.or_else(|| current.clone()).unwrap_or(initial));
+    internal_apply_state_transition(world, current, new);
+}
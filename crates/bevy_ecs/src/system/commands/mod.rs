@@ -5,8 +5,11 @@ use crate::{
     self as bevy_ecs,
     bundle::Bundle,
     entity::{Entities, Entity},
-    system::{RunSystemWithInput, SystemId},
-    world::{EntityWorldMut, FromWorld, World},
+    error::{BoxedError, ErrorContext},
+    event::Event,
+    observer::{Observer, TriggerEvent, TriggerTargets},
+    system::{IntoObserverSystem, RunSystemWithInput, SystemId},
+    world::{DeferredWorld, EntityWorldMut, FromWorld, World},
 };
 use bevy_ecs_macros::SystemParam;
 use bevy_utils::tracing::{error, info};
@@ -117,10 +120,18 @@ pub struct Commands<'w, 's> {
 
 impl SystemBuffer for CommandQueue {
     #[inline]
-    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+    fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
         #[cfg(feature = "trace")]
-        let _span_guard = _system_meta.commands_span.enter();
+        let _span_guard = system_meta.commands_span.enter();
+        // attribute the errors of the commands to the system that queued them
+        let command_system = world.command_system.replace(system_meta.name.clone());
         self.apply(world);
+        world.command_system = command_system;
+    }
+
+    #[inline]
+    fn queue(&mut self, _system_meta: &SystemMeta, mut world: DeferredWorld) {
+        world.commands().append(self);
     }
 }
 
@@ -610,6 +621,67 @@ impl<'w, 's> Commands<'w, 's> {
     pub fn add<C: Command>(&mut self, command: C) {
         self.queue.push(command);
     }
+
+    /// Pushes a fallible command to the queue, for later application to the [`World`].
+    ///
+    /// If the command returns an error, it is passed to the
+    /// [`ErrorHandler`](crate::error::ErrorHandler) of the world, along with the type name of
+    /// the command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::error::BoxedError;
+    ///
+    /// #[derive(Resource)]
+    /// struct Counter(u64);
+    ///
+    /// fn increment_counter(mut commands: Commands) {
+    ///     commands.add_fallible(|world: &mut World| -> Result<(), BoxedError> {
+    ///         let mut counter = world
+    ///             .get_resource_mut::<Counter>()
+    ///             .ok_or("the counter doesn't exist")?;
+    ///         counter.0 += 1;
+    ///         Ok(())
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(increment_counter);
+    /// ```
+    pub fn add_fallible<C, E>(&mut self, command: C)
+    where
+        C: FnOnce(&mut World) -> Result<(), E> + Send + 'static,
+        E: Into<BoxedError>,
+    {
+        self.add(move |world: &mut World| {
+            if let Err(error) = command(world) {
+                world.handle_error(
+                    error.into(),
+                    ErrorContext::command(std::any::type_name::<C>()),
+                );
+            }
+        });
+    }
+
+    /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets. This will run any [`Observer`] of the `event` that
+    /// isn't scoped to specific targets.
+    pub fn trigger(&mut self, event: impl Event) {
+        self.add(TriggerEvent { event, targets: () });
+    }
+
+    /// Sends a [`Trigger`](crate::observer::Trigger) for the given targets. This will run any [`Observer`] of the `event` that
+    /// watches those targets.
+    pub fn trigger_targets(&mut self, event: impl Event, targets: impl TriggerTargets) {
+        self.add(TriggerEvent { event, targets });
+    }
+
+    /// Spawn an [`Observer`] and returns the [`EntityCommands`] associated with the entity that stores the observer.
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> EntityCommands<'_> {
+        self.spawn(Observer::new(observer))
+    }
 }
 
 /// A [`Command`] which gets executed for a given [`Entity`].
@@ -736,11 +808,12 @@ impl EntityCommands<'_> {
     ///
     /// This will overwrite any previous value(s) of the same component type.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// If the associated entity does not exist when the command is applied, the error is passed to
+    /// the [`ErrorHandler`](crate::error::ErrorHandler) of the [`World`], which panics by default.
     ///
-    /// To avoid a panic in this case, use the command [`Self::try_insert`] instead.
+    /// To ignore this case, use the command [`Self::try_insert`] instead.
     ///
     /// # Example
     ///
@@ -989,6 +1062,14 @@ impl EntityCommands<'_> {
         self.add(log_components);
     }
 
+    /// Creates an [`Observer`] listening for a trigger of type `E` that targets this entity.
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        system: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.add(observe(system))
+    }
+
     /// Returns the underlying [`Commands`].
     pub fn commands(&mut self) -> Commands {
         self.commands.reborrow()
@@ -1009,7 +1090,14 @@ where
     F: FnOnce(EntityWorldMut) + Send + 'static,
 {
     fn apply(self, id: Entity, world: &mut World) {
-        self(world.entity_mut(id));
+        if let Some(entity) = world.get_entity_mut(id) {
+            self(entity);
+        } else {
+            world.handle_error(
+                format!("Could not apply a command for entity {id:?} because it doesn't exist in this World.").into(),
+                ErrorContext::command(std::any::type_name::<F>()),
+            );
+        }
     }
 }
 
@@ -1072,7 +1160,10 @@ fn insert<T: Bundle>(bundle: T) -> impl EntityCommand {
         if let Some(mut entity) = world.get_entity_mut(entity) {
             entity.insert(bundle);
         } else {
-            panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World.", std::any::type_name::<T>(), entity);
+            world.handle_error(
+                format!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World.", std::any::type_name::<T>(), entity).into(),
+                ErrorContext::command("insert"),
+            );
         }
     }
 }
@@ -1104,6 +1195,17 @@ fn retain<T: Bundle>(entity: Entity, world: &mut World) {
     }
 }
 
+/// An [`EntityCommand`] that creates an [`Observer`] listening for a trigger of type `E` that targets an entity.
+fn observe<E: Event, B: Bundle, M>(
+    observer: impl IntoObserverSystem<E, B, M>,
+) -> impl EntityCommand {
+    move |entity, world: &mut World| {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.observe(observer);
+        }
+    }
+}
+
 /// A [`Command`] that inserts a [`Resource`] into the world using a value
 /// created with the [`FromWorld`] trait.
 fn init_resource<R: Resource + FromWorld>(world: &mut World) {
@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+
+use super::{IntoSystem, ReadOnlySystem, System};
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    error::{BoxedError, ErrorContext},
+    query::Access,
+    schedule::InternedSystemSet,
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
+};
+
+/// Extension trait to handle the errors returned by a system with the
+/// [`ErrorHandler`](crate::error::ErrorHandler) of the [`World`].
+///
+/// This is implemented for every system returning [`Result<(), E>`](Result). Such systems can
+/// also be added to schedules directly, so this is only needed to get a [`FallibleSystem`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::error::BoxedError;
+///
+/// #[derive(Resource)]
+/// struct Counter(u64);
+///
+/// fn increment_counter(mut counter: Option<ResMut<Counter>>) -> Result<(), BoxedError> {
+///     counter.as_mut().ok_or("the counter doesn't exist")?.0 += 1;
+///     Ok(())
+/// }
+///
+/// let mut world = World::new();
+/// world.set_error_handler(bevy_ecs::error::ignore);
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(increment_counter.fallible());
+/// schedule.run(&mut world);
+/// ```
+pub trait IntoFallibleSystem<In, E: Into<BoxedError>, Marker>:
+    IntoSystem<In, Result<(), E>, Marker>
+{
+    /// Turns this system into a [`FallibleSystem`], which returns `()` and can be added to a
+    /// [`Schedule`](crate::schedule::Schedule).
+    fn fallible(self) -> FallibleSystem<Self::System> {
+        FallibleSystem::new(IntoSystem::into_system(self))
+    }
+}
+
+impl<In, E, Marker, F> IntoFallibleSystem<In, E, Marker> for F
+where
+    F: IntoSystem<In, Result<(), E>, Marker>,
+    E: Into<BoxedError>,
+{
+}
+
+/// A [`System`] that passes the errors returned by `S` to the
+/// [`ErrorHandler`](crate::error::ErrorHandler) of the [`World`], along with the name of `S`.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::error::BoxedError;
+/// use bevy_ecs::system::FallibleSystem;
+///
+/// fn fallible_system() -> Result<(), BoxedError> {
+///     Err("something went wrong".into())
+/// }
+///
+/// let mut world = World::new();
+/// world.set_error_handler(bevy_ecs::error::ignore);
+///
+/// let mut system = FallibleSystem::new(IntoSystem::into_system(fallible_system));
+/// system.initialize(&mut world);
+/// system.run((), &mut world);
+/// ```
+#[derive(Clone)]
+pub struct FallibleSystem<S> {
+    system: S,
+}
+
+impl<S, E> FallibleSystem<S>
+where
+    S: System<Out = Result<(), E>>,
+    E: Into<BoxedError>,
+{
+    /// Creates a new [`System`] that handles the errors returned by `system`.
+    pub const fn new(system: S) -> Self {
+        Self { system }
+    }
+
+    fn handle(&self, result: Result<(), E>, handler: impl FnOnce(BoxedError, ErrorContext)) {
+        if let Err(error) = result {
+            handler(error.into(), ErrorContext::system(self.system.name()));
+        }
+    }
+}
+
+impl<S, E> System for FallibleSystem<S>
+where
+    S: System<Out = Result<(), E>>,
+    E: Into<BoxedError>,
+{
+    type In = S::In;
+    type Out = ();
+
+    fn name(&self) -> Cow<'static, str> {
+        self.system.name()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system.component_access()
+    }
+
+    #[inline]
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.system.archetype_component_access()
+    }
+
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    fn has_deferred(&self) -> bool {
+        self.system.has_deferred()
+    }
+
+    #[inline]
+    unsafe fn run_unsafe(&mut self, input: Self::In, world: UnsafeWorldCell) {
+        // SAFETY: `system.run_unsafe` has the same invariants as `self.run_unsafe`.
+        let result = self.system.run_unsafe(input, world);
+        self.handle(result, world.error_handler());
+    }
+
+    #[inline]
+    fn run(&mut self, input: Self::In, world: &mut World) {
+        let result = self.system.run(input, world);
+        self.handle(result, world.error_handler());
+    }
+
+    #[inline]
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    #[inline]
+    fn queue_deferred(&mut self, world: DeferredWorld) {
+        self.system.queue_deferred(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.system.initialize(world);
+    }
+
+    #[inline]
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
+
+// SAFETY: The inner system is read-only.
+unsafe impl<S, E> ReadOnlySystem for FallibleSystem<S>
+where
+    S: ReadOnlySystem<Out = Result<(), E>>,
+    E: Into<BoxedError>,
+{
+}
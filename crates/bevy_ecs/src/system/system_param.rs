@@ -10,7 +10,7 @@ use crate::{
         ReadOnlyQueryData,
     },
     system::{Query, SystemMeta},
-    world::{unsafe_world_cell::UnsafeWorldCell, FromWorld, World},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, FromWorld, World},
 };
 use bevy_ecs_macros::impl_param_set;
 pub use bevy_ecs_macros::Resource;
@@ -154,6 +154,14 @@ pub unsafe trait SystemParam: Sized {
     #[allow(unused_variables)]
     fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {}
 
+    /// Queues any deferred mutations to be applied at the next [`apply_deferred`](crate::prelude::apply_deferred).
+    ///
+    /// This is used by [observers](crate::observer::Observer), which run from within a [`DeferredWorld`]
+    /// and therefore cannot apply their deferred mutations directly.
+    #[inline]
+    #[allow(unused_variables)]
+    fn queue(state: &mut Self::State, system_meta: &SystemMeta, world: DeferredWorld) {}
+
     /// Creates a parameter to be passed into a [`SystemParamFunction`].
     ///
     /// [`SystemParamFunction`]: super::SystemParamFunction
@@ -780,6 +788,22 @@ unsafe impl<'a, T: FromWorld + Send + 'static> SystemParam for Local<'a, T> {
 pub trait SystemBuffer: FromWorld + Send + 'static {
     /// Applies any deferred mutations to the [`World`].
     fn apply(&mut self, system_meta: &SystemMeta, world: &mut World);
+
+    /// Queues any deferred mutations to be applied at the next [`apply_deferred`](crate::prelude::apply_deferred).
+    ///
+    /// This is called instead of [`apply`](SystemBuffer::apply) by systems that run without
+    /// exclusive world access, such as [`Observer`](crate::observer::Observer)s.
+    ///
+    /// The default implementation can't apply the mutations, so it drops them and logs a warning.
+    /// Buffers used in observers should override it, usually by moving their mutations into
+    /// [`DeferredWorld::commands`].
+    fn queue(&mut self, system_meta: &SystemMeta, _world: DeferredWorld) {
+        bevy_utils::tracing::warn!(
+            "{} doesn't implement `SystemBuffer::queue`, so the deferred mutations of system `{}` were dropped",
+            std::any::type_name::<Self>(),
+            system_meta.name(),
+        );
+    }
 }
 
 /// A [`SystemParam`] that stores a buffer which gets applied to the [`World`] during
@@ -820,6 +844,7 @@ pub trait SystemBuffer: FromWorld + Send + 'static {
 /// # impl Criminal { pub fn is_threat(&self, _: &Settlement) -> bool { true } }
 ///
 /// use bevy_ecs::system::{Deferred, SystemBuffer, SystemMeta};
+/// use bevy_ecs::world::DeferredWorld;
 ///
 /// // Uses deferred mutations to allow signalling the alarm from multiple systems in parallel.
 /// #[derive(Resource, Default)]
@@ -836,6 +861,11 @@ pub trait SystemBuffer: FromWorld + Send + 'static {
 ///     // When `AlarmFlag` is used in a system, this function will get
 ///     // called the next time buffers are applied via apply_deferred.
 ///     fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
+///         self.queue(system_meta, world.into());
+///     }
+///
+///     // When `AlarmFlag` is used in an observer, this function will get called instead.
+///     fn queue(&mut self, system_meta: &SystemMeta, mut world: DeferredWorld) {
 ///         if self.0 {
 ///             world.resource_mut::<Alarm>().0 = true;
 ///             self.0 = false;
@@ -944,6 +974,10 @@ unsafe impl<T: SystemBuffer> SystemParam for Deferred<'_, T> {
         state.get().apply(system_meta, world);
     }
 
+    fn queue(state: &mut Self::State, system_meta: &SystemMeta, world: DeferredWorld) {
+        state.get().queue(system_meta, world);
+    }
+
     unsafe fn get_param<'w, 's>(
         state: &'s mut Self::State,
         _system_meta: &SystemMeta,
@@ -1350,6 +1384,11 @@ macro_rules! impl_system_param_tuple {
                 $($param::apply($param, _system_meta, _world);)*
             }
 
+            #[inline]
+            fn queue(($($param,)*): &mut Self::State, _system_meta: &SystemMeta, mut _world: DeferredWorld) {
+                $($param::queue($param, _system_meta, _world.reborrow());)*
+            }
+
             #[inline]
             #[allow(clippy::unused_unit)]
             unsafe fn get_param<'w, 's>(
@@ -1493,6 +1532,10 @@ unsafe impl<P: SystemParam + 'static> SystemParam for StaticSystemParam<'_, '_,
         P::apply(state, system_meta, world);
     }
 
+    fn queue(state: &mut Self::State, system_meta: &SystemMeta, world: DeferredWorld) {
+        P::queue(state, system_meta, world);
+    }
+
     unsafe fn get_param<'world, 'state>(
         state: &'state mut Self::State,
         system_meta: &SystemMeta,
@@ -0,0 +1,24 @@
+//! A trait for traversing entities.
+
+use crate::{entity::Entity, query::ReadOnlyQueryData};
+
+/// A component that can point to another entity, and which can be used to define a path through an entity graph.
+///
+/// The traversal trait is used to specify the path taken by [`Event`](crate::event::Event)s
+/// that [propagate](crate::observer::Trigger::propagate) when triggered at an entity.
+///
+/// Infinite loops are possible, and are not checked for. While looping can be desirable in some contexts
+/// (for example, an observer that triggers itself multiple times before stopping), following an infinite
+/// traversal loop without an eventual exit will cause your application to hang. Implementers of `Traversal`
+/// are responsible for documenting possible looping behavior, and consumers of those implementations are
+/// responsible for avoiding infinite loops in their code.
+pub trait Traversal: ReadOnlyQueryData {
+    /// Returns the next entity to visit.
+    fn traverse(item: Self::Item<'_>) -> Option<Entity>;
+}
+
+impl Traversal for () {
+    fn traverse(_: Self::Item<'_>) -> Option<Entity> {
+        None
+    }
+}
@@ -0,0 +1,25 @@
+//! Internal components used by bevy with a fixed component id.
+//! Constants are used to skip [`TypeId`](std::any::TypeId) lookups in hot paths.
+
+use crate::{self as bevy_ecs, component::ComponentId, event::Event};
+
+/// [`ComponentId`] for [`OnAdd`]
+pub const ON_ADD: ComponentId = ComponentId::new(0);
+/// [`ComponentId`] for [`OnInsert`]
+pub const ON_INSERT: ComponentId = ComponentId::new(1);
+/// [`ComponentId`] for [`OnRemove`]
+pub const ON_REMOVE: ComponentId = ComponentId::new(2);
+
+/// Trigger emitted when a component is added to an entity.
+#[derive(Event)]
+pub struct OnAdd;
+
+/// Trigger emitted when a component is inserted on to an entity,
+/// regardless of whether it was already present.
+#[derive(Event)]
+pub struct OnInsert;
+
+/// Trigger emitted when a component is removed from an entity,
+/// including when the entity is despawned.
+#[derive(Event)]
+pub struct OnRemove;
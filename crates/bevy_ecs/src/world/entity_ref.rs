@@ -1,13 +1,16 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
-    bundle::{Bundle, BundleInfo, BundleInserter, DynamicBundle},
+    bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle},
     change_detection::MutUntyped,
     component::{Component, ComponentId, ComponentTicks, Components, StorageType},
     entity::{Entities, Entity, EntityLocation},
-    query::{Access, DebugCheckedUnwrap},
+    event::Event,
+    observer::{Observer, Observers},
+    query::{Access, DebugCheckedUnwrap, ReadOnlyQueryData},
     removal_detection::RemovedComponentEvents,
     storage::Storages,
-    world::{Mut, World},
+    system::IntoObserverSystem,
+    world::{Mut, World, WorldId, ON_REMOVE},
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use bevy_utils::tracing::debug;
@@ -120,6 +123,14 @@ impl<'w> EntityRef<'w> {
         unsafe { self.0.get_ref::<T>() }
     }
 
+    /// Returns read-only components for the current entity that match the query `Q`.
+    ///
+    /// Returns `None` if the entity does not have the components required by the query `Q`.
+    pub fn get_components<Q: ReadOnlyQueryData>(&self) -> Option<Q::Item<'w>> {
+        // SAFETY: We have read-only access to all components of this entity.
+        unsafe { self.0.get_components::<Q>() }
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     #[inline]
@@ -392,21 +403,45 @@ pub struct EntityWorldMut<'w> {
 }
 
 impl<'w> EntityWorldMut<'w> {
+    #[track_caller]
+    #[inline(never)]
+    #[cold]
+    fn panic_despawned(&self) -> ! {
+        panic!(
+            "Entity {:?} does not exist; it was despawned by a command queued during a component hook",
+            self.entity
+        );
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn assert_not_despawned(&self) {
+        if self.location.archetype_id == ArchetypeId::INVALID {
+            self.panic_despawned();
+        }
+    }
+
+    #[track_caller]
     fn as_unsafe_entity_cell_readonly(&self) -> UnsafeEntityCell<'_> {
+        self.assert_not_despawned();
         UnsafeEntityCell::new(
             self.world.as_unsafe_world_cell_readonly(),
             self.entity,
             self.location,
         )
     }
+    #[track_caller]
     fn as_unsafe_entity_cell(&mut self) -> UnsafeEntityCell<'_> {
+        self.assert_not_despawned();
         UnsafeEntityCell::new(
             self.world.as_unsafe_world_cell(),
             self.entity,
             self.location,
         )
     }
+    #[track_caller]
     fn into_unsafe_entity_cell(self) -> UnsafeEntityCell<'w> {
+        self.assert_not_despawned();
         UnsafeEntityCell::new(
             self.world.as_unsafe_world_cell(),
             self.entity,
@@ -444,14 +479,26 @@ impl<'w> EntityWorldMut<'w> {
     }
 
     /// Gets metadata indicating the location where the current entity is stored.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
     #[inline]
+    #[track_caller]
     pub fn location(&self) -> EntityLocation {
+        self.assert_not_despawned();
         self.location
     }
 
     /// Returns the archetype that the current entity belongs to.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
     #[inline]
+    #[track_caller]
     pub fn archetype(&self) -> &Archetype {
+        self.assert_not_despawned();
         &self.world.archetypes[self.location.archetype_id]
     }
 
@@ -568,25 +615,20 @@ impl<'w> EntityWorldMut<'w> {
     /// Adds a [`Bundle`] of components to the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    #[track_caller]
     pub fn insert<T: Bundle>(&mut self, bundle: T) -> &mut Self {
+        self.assert_not_despawned();
         let change_tick = self.world.change_tick();
-        let bundle_info = self
-            .world
-            .bundles
-            .init_info::<T>(&mut self.world.components, &mut self.world.storages);
-        let mut bundle_inserter = bundle_info.get_bundle_inserter(
-            &mut self.world.entities,
-            &mut self.world.archetypes,
-            &self.world.components,
-            &mut self.world.storages,
-            self.location.archetype_id,
-            change_tick,
-        );
+        let mut bundle_inserter =
+            BundleInserter::new::<T>(self.world, self.location.archetype_id, change_tick);
         // SAFETY: location matches current entity. `T` matches `bundle_info`
-        unsafe {
-            self.location = bundle_inserter.insert(self.entity, self.location, bundle);
-        }
-
+        self.location = unsafe { bundle_inserter.insert(self.entity, self.location, bundle) };
+        self.world.flush_commands();
+        self.update_location();
         self
     }
 
@@ -600,23 +642,24 @@ impl<'w> EntityWorldMut<'w> {
     ///
     /// - [`ComponentId`] must be from the same world as [`EntityWorldMut`]
     /// - [`OwningPtr`] must be a valid reference to the type represented by [`ComponentId`]
+    #[track_caller]
     pub unsafe fn insert_by_id(
         &mut self,
         component_id: ComponentId,
         component: OwningPtr<'_>,
     ) -> &mut Self {
+        self.assert_not_despawned();
         let change_tick = self.world.change_tick();
+        let bundle_id = self
+            .world
+            .bundles
+            .init_component_info(&self.world.components, component_id);
+        let storage_type = self.world.bundles.get_storage_unchecked(bundle_id);
 
-        let bundles = &mut self.world.bundles;
-        let components = &mut self.world.components;
-
-        let (bundle_info, storage_type) = bundles.init_component_info(components, component_id);
-        let bundle_inserter = bundle_info.get_bundle_inserter(
-            &mut self.world.entities,
-            &mut self.world.archetypes,
-            &self.world.components,
-            &mut self.world.storages,
+        let bundle_inserter = BundleInserter::new_with_id(
+            self.world,
             self.location.archetype_id,
+            bundle_id,
             change_tick,
         );
 
@@ -627,7 +670,8 @@ impl<'w> EntityWorldMut<'w> {
             Some(component).into_iter(),
             Some(storage_type).into_iter(),
         );
-
+        self.world.flush_commands();
+        self.update_location();
         self
     }
 
@@ -643,23 +687,24 @@ impl<'w> EntityWorldMut<'w> {
     /// # Safety
     /// - Each [`ComponentId`] must be from the same world as [`EntityWorldMut`]
     /// - Each [`OwningPtr`] must be a valid reference to the type represented by [`ComponentId`]
+    #[track_caller]
     pub unsafe fn insert_by_ids<'a, I: Iterator<Item = OwningPtr<'a>>>(
         &mut self,
         component_ids: &[ComponentId],
         iter_components: I,
     ) -> &mut Self {
+        self.assert_not_despawned();
         let change_tick = self.world.change_tick();
-
-        let bundles = &mut self.world.bundles;
-        let components = &mut self.world.components;
-
-        let (bundle_info, storage_types) = bundles.init_dynamic_info(components, component_ids);
-        let bundle_inserter = bundle_info.get_bundle_inserter(
-            &mut self.world.entities,
-            &mut self.world.archetypes,
-            &self.world.components,
-            &mut self.world.storages,
+        let bundle_id = self
+            .world
+            .bundles
+            .init_dynamic_info(&self.world.components, component_ids);
+        let mut storage_types =
+            std::mem::take(self.world.bundles.get_storages_unchecked(bundle_id));
+        let bundle_inserter = BundleInserter::new_with_id(
+            self.world,
             self.location.archetype_id,
+            bundle_id,
             change_tick,
         );
 
@@ -668,9 +713,11 @@ impl<'w> EntityWorldMut<'w> {
             self.entity,
             self.location,
             iter_components,
-            storage_types.iter().cloned(),
+            (*storage_types).iter().cloned(),
         );
-
+        *self.world.bundles.get_storages_unchecked(bundle_id) = std::mem::take(&mut storage_types);
+        self.world.flush_commands();
+        self.update_location();
         self
     }
 
@@ -680,22 +727,24 @@ impl<'w> EntityWorldMut<'w> {
     /// remove any of them.
     // TODO: BundleRemover?
     #[must_use]
+    #[track_caller]
     pub fn take<T: Bundle>(&mut self) -> Option<T> {
-        let archetypes = &mut self.world.archetypes;
-        let storages = &mut self.world.storages;
-        let components = &mut self.world.components;
-        let entities = &mut self.world.entities;
-        let removed_components = &mut self.world.removed_components;
-
-        let bundle_info = self.world.bundles.init_info::<T>(components, storages);
+        self.assert_not_despawned();
+        let world = &mut self.world;
+        let storages = &mut world.storages;
+        let components = &mut world.components;
+        let bundle_id = world.bundles.init_info::<T>(components, storages).id();
+        // SAFETY: We just ensured this bundle exists
+        let bundle_info = unsafe { world.bundles.get_unchecked(bundle_id) };
         let old_location = self.location;
         // SAFETY: `archetype_id` exists because it is referenced in the old `EntityLocation` which is valid,
         // components exist in `bundle_info` because `Bundles::init_info` initializes a `BundleInfo` containing all components of the bundle type `T`
         let new_archetype_id = unsafe {
             remove_bundle_from_archetype(
-                archetypes,
+                &mut world.archetypes,
                 storages,
                 components,
+                &world.observers,
                 old_location.archetype_id,
                 bundle_info,
                 false,
@@ -706,8 +755,40 @@ impl<'w> EntityWorldMut<'w> {
             return None;
         }
 
-        let mut bundle_components = bundle_info.components().iter().cloned();
         let entity = self.entity;
+        // SAFETY: Archetypes and Bundles cannot be mutably aliased through DeferredWorld
+        let (old_archetype, bundle_info, mut deferred_world) = unsafe {
+            let bundle_info: *const BundleInfo = bundle_info;
+            let world = world.as_unsafe_world_cell();
+            (
+                &world.archetypes()[old_location.archetype_id],
+                &*bundle_info,
+                world.into_deferred(),
+            )
+        };
+
+        if old_archetype.has_on_replace() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe { deferred_world.trigger_on_replace(entity, bundle_info.iter_components()) };
+        }
+        if old_archetype.has_on_remove() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe { deferred_world.trigger_on_remove(entity, bundle_info.iter_components()) };
+        }
+        if old_archetype.has_remove_observer() {
+            // SAFETY: Lifecycle events are zero-sized, so any pointer is valid for their observers
+            unsafe {
+                deferred_world.trigger_observers(ON_REMOVE, entity, bundle_info.iter_components());
+            }
+        }
+
+        let archetypes = &mut world.archetypes;
+        let storages = &mut world.storages;
+        let components = &mut world.components;
+        let entities = &mut world.entities;
+        let removed_components = &mut world.removed_components;
+
+        let mut bundle_components = bundle_info.iter_components();
         // SAFETY: bundle components are iterated in order, which guarantees that the component type
         // matches
         let result = unsafe {
@@ -741,7 +822,8 @@ impl<'w> EntityWorldMut<'w> {
                 new_archetype_id,
             );
         }
-
+        self.world.flush_commands();
+        self.update_location();
         Some(result)
     }
 
@@ -826,47 +908,91 @@ impl<'w> EntityWorldMut<'w> {
         entities.set(entity.index(), new_location);
     }
 
-    /// Remove the components of `bundle_info` from `entity`, where `self_location` and `old_location`
-    /// are the location of this entity, and `self_location` is updated to the new location.
+    /// Remove the components of `bundle` from `entity`.
     ///
-    /// SAFETY: `old_location` must be valid and the components in `bundle_info` must exist.
-    #[allow(clippy::too_many_arguments)]
-    unsafe fn remove_bundle_info(
-        entity: Entity,
-        self_location: &mut EntityLocation,
-        old_location: EntityLocation,
-        bundle_info: &BundleInfo,
-        archetypes: &mut Archetypes,
-        storages: &mut Storages,
-        components: &Components,
-        entities: &mut Entities,
-        removed_components: &mut RemovedComponentEvents,
-    ) {
-        // SAFETY: `archetype_id` exists because it is referenced in `old_location` which is valid
-        // and components in `bundle_info` must exist due to this functions safety invariants.
+    /// # Safety
+    /// - A `BundleInfo` with the corresponding `BundleId` must have been initialized.
+    unsafe fn remove_bundle(&mut self, bundle: BundleId) -> EntityLocation {
+        let entity = self.entity;
+        let world = &mut self.world;
+        let location = self.location;
+        // SAFETY: the caller guarantees that the BundleInfo for this id has been initialized.
+        let bundle_info = world.bundles.get_unchecked(bundle);
+
+        // SAFETY: `archetype_id` exists because it is referenced in `location` which is valid
+        // and components in `bundle_info` must exist due to this function's safety invariants.
         let new_archetype_id = remove_bundle_from_archetype(
-            archetypes,
-            storages,
-            components,
-            old_location.archetype_id,
+            &mut world.archetypes,
+            &mut world.storages,
+            &world.components,
+            &world.observers,
+            location.archetype_id,
             bundle_info,
+            // components from the bundle that are not present on the entity are ignored
             true,
         )
         .expect("intersections should always return a result");
 
-        if new_archetype_id == old_location.archetype_id {
-            return;
+        if new_archetype_id == location.archetype_id {
+            return location;
         }
 
-        let old_archetype = &mut archetypes[old_location.archetype_id];
-        for component_id in bundle_info.components().iter().cloned() {
+        // SAFETY: Archetypes and Bundles cannot be mutably aliased through DeferredWorld
+        let (old_archetype, bundle_info, mut deferred_world) = unsafe {
+            let bundle_info: *const BundleInfo = bundle_info;
+            let world = world.as_unsafe_world_cell();
+            (
+                &world.archetypes()[location.archetype_id],
+                &*bundle_info,
+                world.into_deferred(),
+            )
+        };
+
+        if old_archetype.has_on_replace() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe {
+                deferred_world.trigger_on_replace(
+                    entity,
+                    bundle_info
+                        .iter_components()
+                        .filter(|id| old_archetype.contains(*id)),
+                );
+            }
+        }
+        if old_archetype.has_on_remove() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe {
+                deferred_world.trigger_on_remove(
+                    entity,
+                    bundle_info
+                        .iter_components()
+                        .filter(|id| old_archetype.contains(*id)),
+                );
+            }
+        }
+        if old_archetype.has_remove_observer() {
+            // SAFETY: Lifecycle events are zero-sized, so any pointer is valid for their observers
+            unsafe {
+                deferred_world.trigger_observers(
+                    ON_REMOVE,
+                    entity,
+                    bundle_info
+                        .iter_components()
+                        .filter(|id| old_archetype.contains(*id)),
+                );
+            }
+        }
+
+        let old_archetype = &world.archetypes[location.archetype_id];
+        for component_id in bundle_info.iter_components() {
             if old_archetype.contains(component_id) {
-                removed_components.send(component_id, entity);
+                world.removed_components.send(component_id, entity);
 
                 // Make sure to drop components stored in sparse sets.
                 // Dense components are dropped later in `move_to_and_drop_missing_unchecked`.
                 if let Some(StorageType::SparseSet) = old_archetype.get_storage_type(component_id) {
-                    storages
+                    world
+                        .storages
                         .sparse_sets
                         .get_mut(component_id)
                         .unwrap()
@@ -877,62 +1003,52 @@ impl<'w> EntityWorldMut<'w> {
 
         // SAFETY: `new_archetype_id` is a subset of the components in `old_location.archetype_id`
         // because it is created by removing a bundle from these components.
+        let mut new_location = location;
         Self::move_entity_from_remove::<true>(
             entity,
-            self_location,
-            old_location.archetype_id,
-            old_location,
-            entities,
-            archetypes,
-            storages,
+            &mut new_location,
+            location.archetype_id,
+            location,
+            &mut world.entities,
+            &mut world.archetypes,
+            &mut world.storages,
             new_archetype_id,
         );
+
+        new_location
     }
 
     /// Removes any components in the [`Bundle`] from the entity.
     ///
     /// See [`EntityCommands::remove`](crate::system::EntityCommands::remove) for more details.
     // TODO: BundleRemover?
+    #[track_caller]
     pub fn remove<T: Bundle>(&mut self) -> &mut Self {
-        let archetypes = &mut self.world.archetypes;
+        self.assert_not_despawned();
         let storages = &mut self.world.storages;
         let components = &mut self.world.components;
-        let entities = &mut self.world.entities;
-        let removed_components = &mut self.world.removed_components;
-
-        let bundle_info = self.world.bundles.init_info::<T>(components, storages);
-        let old_location = self.location;
-
-        // SAFETY: Components exist in `bundle_info` because `Bundles::init_info`
-        // initializes a `BundleInfo` containing all components of the bundle type `T`.
-        unsafe {
-            Self::remove_bundle_info(
-                self.entity,
-                &mut self.location,
-                old_location,
-                bundle_info,
-                archetypes,
-                storages,
-                components,
-                entities,
-                removed_components,
-            );
-        }
+        let bundle_id = self.world.bundles.init_info::<T>(components, storages).id();
 
+        // SAFETY: the `BundleInfo` is initialized above
+        self.location = unsafe { self.remove_bundle(bundle_id) };
+        self.world.flush_commands();
+        self.update_location();
         self
     }
 
     /// Removes any components except those in the [`Bundle`] from the entity.
     ///
     /// See [`EntityCommands::retain`](crate::system::EntityCommands::retain) for more details.
+    #[track_caller]
     pub fn retain<T: Bundle>(&mut self) -> &mut Self {
+        self.assert_not_despawned();
         let archetypes = &mut self.world.archetypes;
         let storages = &mut self.world.storages;
         let components = &mut self.world.components;
-        let entities = &mut self.world.entities;
-        let removed_components = &mut self.world.removed_components;
 
-        let retained_bundle_info = self.world.bundles.init_info::<T>(components, storages);
+        let retained_bundle = self.world.bundles.init_info::<T>(components, storages).id();
+        // SAFETY: `retained_bundle` exists as we just initialized it.
+        let retained_bundle_info = unsafe { self.world.bundles.get_unchecked(retained_bundle) };
         let old_location = self.location;
         let old_archetype = &mut archetypes[old_location.archetype_id];
 
@@ -940,38 +1056,53 @@ impl<'w> EntityWorldMut<'w> {
             .components()
             .filter(|c| !retained_bundle_info.components().contains(c))
             .collect::<Vec<_>>();
-        let remove_bundle_info = self
-            .world
-            .bundles
-            .init_dynamic_info(components, to_remove)
-            .0;
-
-        // SAFETY: Components exist in `remove_bundle_info` because `Bundles::init_dynamic_info`
-        // initializes a `BundleInfo` containing all components in the to_remove Bundle.
-        unsafe {
-            Self::remove_bundle_info(
-                self.entity,
-                &mut self.location,
-                old_location,
-                remove_bundle_info,
-                archetypes,
-                storages,
-                components,
-                entities,
-                removed_components,
-            );
-        }
+        let remove_bundle = self.world.bundles.init_dynamic_info(components, to_remove);
 
+        // SAFETY: the `BundleInfo` for the components to remove is initialized above
+        self.location = unsafe { self.remove_bundle(remove_bundle) };
+        self.world.flush_commands();
+        self.update_location();
         self
     }
 
     /// Despawns the current entity.
     ///
     /// See [`World::despawn`] for more details.
+    #[track_caller]
     pub fn despawn(self) {
+        self.assert_not_despawned();
         debug!("Despawning entity {:?}", self.entity);
         let world = self.world;
         world.flush();
+
+        // SAFETY: Archetypes cannot be mutably aliased through DeferredWorld
+        let (archetype, mut deferred_world) = unsafe {
+            let archetype: *const Archetype = &world.archetypes[self.location.archetype_id];
+            let world = world.as_unsafe_world_cell();
+            (&*archetype, world.into_deferred())
+        };
+        if archetype.has_on_despawn() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe { deferred_world.trigger_on_despawn(self.entity, archetype.components()) };
+        }
+        if archetype.has_on_replace() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe { deferred_world.trigger_on_replace(self.entity, archetype.components()) };
+        }
+        if archetype.has_on_remove() {
+            // SAFETY: All components in the archetype exist in world
+            unsafe { deferred_world.trigger_on_remove(self.entity, archetype.components()) };
+        }
+        if archetype.has_remove_observer() {
+            // SAFETY: Lifecycle events are zero-sized, so any pointer is valid for their observers
+            unsafe {
+                deferred_world.trigger_observers(ON_REMOVE, self.entity, archetype.components());
+            }
+        }
+
+        // Hooks may have reserved entities by queueing commands, which must be flushed
+        // before this entity can be freed.
+        world.flush();
         let location = world
             .entities
             .free(self.entity)
@@ -1031,6 +1162,7 @@ impl<'w> EntityWorldMut<'w> {
             world.archetypes[moved_location.archetype_id]
                 .set_entity_table_row(moved_location.archetype_row, table_row);
         }
+        world.flush_commands();
     }
 
     /// Gets read-only access to the world that the current entity belongs to.
@@ -1106,8 +1238,31 @@ impl<'w> EntityWorldMut<'w> {
     ///
     /// This is *only* required when using the unsafe function [`EntityWorldMut::world_mut`],
     /// which enables the location to change.
+    ///
+    /// If the entity has been despawned, any further use of this [`EntityWorldMut`] that
+    /// requires the entity to exist will panic.
     pub fn update_location(&mut self) {
-        self.location = self.world.entities().get(self.entity).unwrap();
+        self.location = self
+            .world
+            .entities()
+            .get(self.entity)
+            .unwrap_or(EntityLocation::INVALID);
+    }
+
+    /// Creates an [`Observer`] listening for events of type `E` targeting this entity.
+    ///
+    /// The observer is despawned along with this entity.
+    #[track_caller]
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.assert_not_despawned();
+        self.world
+            .spawn(Observer::new(observer).with_entity(self.entity));
+        self.world.flush_commands();
+        self.update_location();
+        self
     }
 
     /// Gets an Entry into the world for this entity and component for in-place manipulation.
@@ -1532,6 +1687,12 @@ impl<'w> FilteredEntityRef<'w> {
         self.entity.archetype()
     }
 
+    /// Returns the id of the [`World`] the entity belongs to.
+    #[inline]
+    pub(crate) fn world_id(&self) -> WorldId {
+        self.entity.world().id()
+    }
+
     /// Returns an iterator over the component ids that are accessed by self.
     #[inline]
     pub fn components(&self) -> impl Iterator<Item = ComponentId> + '_ {
@@ -1720,6 +1881,12 @@ impl<'w> FilteredEntityMut<'w> {
         self.entity.archetype()
     }
 
+    /// Returns the id of the [`World`] the entity belongs to.
+    #[inline]
+    pub(crate) fn world_id(&self) -> WorldId {
+        self.entity.world().id()
+    }
+
     /// Returns an iterator over the component ids that are accessed by self.
     #[inline]
     pub fn components(&self) -> impl Iterator<Item = ComponentId> + '_ {
@@ -1860,7 +2027,7 @@ unsafe fn insert_dynamic_bundle<
     I: Iterator<Item = OwningPtr<'a>>,
     S: Iterator<Item = StorageType>,
 >(
-    mut bundle_inserter: BundleInserter<'_, '_>,
+    mut bundle_inserter: BundleInserter<'_>,
     entity: Entity,
     location: EntityLocation,
     components: I,
@@ -1899,6 +2066,7 @@ unsafe fn remove_bundle_from_archetype(
     archetypes: &mut Archetypes,
     storages: &mut Storages,
     components: &Components,
+    observers: &Observers,
     archetype_id: ArchetypeId,
     bundle_info: &BundleInfo,
     intersection: bool,
@@ -1966,6 +2134,8 @@ unsafe fn remove_bundle_from_archetype(
         }
 
         let new_archetype_id = archetypes.get_id_or_insert(
+            components,
+            observers,
             next_table_id,
             next_table_components,
             next_sparse_set_components,
@@ -1,5 +1,7 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod component_constants;
+mod deferred_world;
 mod entity_ref;
 pub mod error;
 mod spawn_batch;
@@ -7,6 +9,8 @@ pub mod unsafe_world_cell;
 mod world_cell;
 
 pub use crate::change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD};
+pub use component_constants::*;
+pub use deferred_world::DeferredWorld;
 pub use entity_ref::{
     EntityMut, EntityRef, EntityWorldMut, Entry, FilteredEntityMut, FilteredEntityRef,
     OccupiedEntry, VacantEntry,
@@ -19,22 +23,25 @@ use crate::{
     bundle::{Bundle, BundleInserter, BundleSpawner, Bundles},
     change_detection::{MutUntyped, TicksMut},
     component::{
-        Component, ComponentDescriptor, ComponentId, ComponentInfo, ComponentTicks, Components,
-        Tick,
+        Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
+        Components, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    error::{BoxedError, ErrorContext, ErrorHandler, ErrorSource},
     event::{Event, EventId, Events, SendBatchIds},
+    observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
     removal_detection::RemovedComponentEvents,
     schedule::{Schedule, ScheduleLabel, Schedules},
     storage::{ResourceData, Storages},
-    system::{Res, Resource},
+    system::{CommandQueue, Commands, Res, Resource},
     world::error::TryRunScheduleError,
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use bevy_utils::tracing::warn;
 use std::{
     any::TypeId,
+    borrow::Cow,
     fmt,
     mem::MaybeUninit,
     sync::atomic::{AtomicU32, Ordering},
@@ -72,16 +79,22 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) removed_components: RemovedComponentEvents,
+    pub(crate) observers: Observers,
     /// Access cache used by [`WorldCell`]. Is only accessed in the `Drop` impl of `WorldCell`.
     pub(crate) archetype_component_access: ArchetypeComponentAccess,
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) last_trigger_id: u32,
+    pub(crate) command_queue: CommandQueue,
+    pub(crate) error_handler: ErrorHandler,
+    /// The name of the system whose commands are being applied, if any.
+    pub(crate) command_system: Option<Cow<'static, str>>,
 }
 
 impl Default for World {
     fn default() -> Self {
-        Self {
+        let mut world = Self {
             id: WorldId::new().expect("More `bevy` `World`s have been created than is supported"),
             entities: Entities::new(),
             components: Default::default(),
@@ -89,13 +102,20 @@ impl Default for World {
             storages: Default::default(),
             bundles: Default::default(),
             removed_components: Default::default(),
+            observers: Observers::default(),
             archetype_component_access: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
-        }
+            last_trigger_id: 0,
+            command_queue: CommandQueue::default(),
+            error_handler: crate::error::panic,
+            command_system: None,
+        };
+        world.bootstrap();
+        world
     }
 }
 
@@ -112,12 +132,45 @@ impl World {
         World::default()
     }
 
+    /// Initializes all of the core components that have a fixed [`ComponentId`].
+    fn bootstrap(&mut self) {
+        assert_eq!(ON_ADD, self.init_component::<OnAdd>());
+        assert_eq!(ON_INSERT, self.init_component::<OnInsert>());
+        assert_eq!(ON_REMOVE, self.init_component::<OnRemove>());
+    }
+
     /// Retrieves this [`World`]'s unique ID
     #[inline]
     pub fn id(&self) -> WorldId {
         self.id
     }
 
+    /// Returns the [`ErrorHandler`] called with the errors of fallible systems and commands.
+    #[inline]
+    pub fn error_handler(&self) -> ErrorHandler {
+        self.error_handler
+    }
+
+    /// Sets the [`ErrorHandler`] called with the errors of fallible systems and commands.
+    ///
+    /// Defaults to [`panic`](crate::error::panic). See the [`error`](crate::error) module for the
+    /// other handlers.
+    pub fn set_error_handler(&mut self, handler: ErrorHandler) {
+        self.error_handler = handler;
+    }
+
+    /// Passes `error` to the [`ErrorHandler`] of this world, along with its `context`.
+    ///
+    /// If the error was returned by a command queued by a system, and the `context` doesn't
+    /// name a system, the name of that system is added to it.
+    #[inline]
+    pub fn handle_error(&self, error: BoxedError, mut context: ErrorContext) {
+        if context.source == ErrorSource::Command && context.system.is_none() {
+            context.system.clone_from(&self.command_system);
+        }
+        (self.error_handler)(error, context);
+    }
+
     /// Creates a new [`UnsafeWorldCell`] view with complete read+write access.
     #[inline]
     pub fn as_unsafe_world_cell(&mut self) -> UnsafeWorldCell<'_> {
@@ -188,6 +241,27 @@ impl World {
         self.components.init_component::<T>(&mut self.storages)
     }
 
+    /// Returns a mutable reference to the [`ComponentHooks`] for a [`Component`] type.
+    ///
+    /// Will panic if `T` exists in any archetypes.
+    pub fn register_component_hooks<T: Component>(&mut self) -> &mut ComponentHooks {
+        let index = self.init_component::<T>();
+        assert!(!self.archetypes.iter().any(|a| a.contains(index)), "Components hooks cannot be modified if the component already exists in an archetype, use init_component if {} may already be in use", std::any::type_name::<T>());
+        // SAFETY: We just created this component
+        unsafe { self.components.get_hooks_mut(index).debug_checked_unwrap() }
+    }
+
+    /// Returns a mutable reference to the [`ComponentHooks`] for a [`Component`] with the given id if it exists.
+    ///
+    /// Will panic if `id` exists in any archetypes.
+    pub fn register_component_hooks_by_id(
+        &mut self,
+        id: ComponentId,
+    ) -> Option<&mut ComponentHooks> {
+        assert!(!self.archetypes.iter().any(|a| a.contains(id)), "Components hooks cannot be modified if the component already exists in an archetype, use init_component if the component with id {:?} may already be in use", id);
+        self.components.get_hooks_mut(id)
+    }
+
     /// Initializes a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::init_component`] in that it uses a [`ComponentDescriptor`]
@@ -746,23 +820,16 @@ impl World {
         let change_tick = self.change_tick();
         let entity = self.entities.alloc();
         let entity_location = {
-            let bundle_info = self
-                .bundles
-                .init_info::<B>(&mut self.components, &mut self.storages);
-            let mut spawner = bundle_info.get_bundle_spawner(
-                &mut self.entities,
-                &mut self.archetypes,
-                &self.components,
-                &mut self.storages,
-                change_tick,
-            );
-
+            let mut spawner = BundleSpawner::new::<B>(self, change_tick);
             // SAFETY: bundle's type matches `bundle_info`, entity is allocated but non-existent
             unsafe { spawner.spawn_non_existent(entity, bundle) }
         };
 
         // SAFETY: entity and location are valid, as they were just created above
-        unsafe { EntityWorldMut::new(self, entity, entity_location) }
+        let mut entity = unsafe { EntityWorldMut::new(self, entity, entity_location) };
+        // Hooks may have queued commands, which can move or even despawn the new entity.
+        entity.world_scope(World::flush_commands);
+        entity
     }
 
     /// # Safety
@@ -1536,29 +1603,27 @@ impl World {
 
         let change_tick = self.change_tick();
 
-        let bundle_info = self
+        let bundle_id = self
             .bundles
-            .init_info::<B>(&mut self.components, &mut self.storages);
-        enum SpawnOrInsert<'a, 'b> {
-            Spawn(BundleSpawner<'a, 'b>),
-            Insert(BundleInserter<'a, 'b>, ArchetypeId),
+            .init_info::<B>(&mut self.components, &mut self.storages)
+            .id();
+        enum SpawnOrInsert<'w> {
+            Spawn(BundleSpawner<'w>),
+            Insert(BundleInserter<'w>, ArchetypeId),
         }
 
-        impl<'a, 'b> SpawnOrInsert<'a, 'b> {
+        impl<'w> SpawnOrInsert<'w> {
             fn entities(&mut self) -> &mut Entities {
                 match self {
-                    SpawnOrInsert::Spawn(spawner) => spawner.entities,
-                    SpawnOrInsert::Insert(inserter, _) => inserter.entities,
+                    SpawnOrInsert::Spawn(spawner) => spawner.entities(),
+                    SpawnOrInsert::Insert(inserter, _) => inserter.entities(),
                 }
             }
         }
-        let mut spawn_or_insert = SpawnOrInsert::Spawn(bundle_info.get_bundle_spawner(
-            &mut self.entities,
-            &mut self.archetypes,
-            &self.components,
-            &mut self.storages,
-            change_tick,
-        ));
+        // SAFETY: we initialized this bundle_id in `init_info`
+        let mut spawn_or_insert = SpawnOrInsert::Spawn(unsafe {
+            BundleSpawner::new_with_id(self, bundle_id, change_tick)
+        });
 
         let mut invalid_entities = Vec::new();
         for (entity, bundle) in iter {
@@ -1575,14 +1640,15 @@ impl World {
                             unsafe { inserter.insert(entity, location, bundle) };
                         }
                         _ => {
-                            let mut inserter = bundle_info.get_bundle_inserter(
-                                &mut self.entities,
-                                &mut self.archetypes,
-                                &self.components,
-                                &mut self.storages,
-                                location.archetype_id,
-                                change_tick,
-                            );
+                            // SAFETY: we initialized this bundle_id in `init_info`
+                            let mut inserter = unsafe {
+                                BundleInserter::new_with_id(
+                                    self,
+                                    location.archetype_id,
+                                    bundle_id,
+                                    change_tick,
+                                )
+                            };
                             // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
                             unsafe { inserter.insert(entity, location, bundle) };
                             spawn_or_insert =
@@ -1595,13 +1661,9 @@ impl World {
                         // SAFETY: `entity` is allocated (but non existent), bundle matches inserter
                         unsafe { spawner.spawn_non_existent(entity, bundle) };
                     } else {
-                        let mut spawner = bundle_info.get_bundle_spawner(
-                            &mut self.entities,
-                            &mut self.archetypes,
-                            &self.components,
-                            &mut self.storages,
-                            change_tick,
-                        );
+                        // SAFETY: we initialized this bundle_id in `init_info`
+                        let mut spawner =
+                            unsafe { BundleSpawner::new_with_id(self, bundle_id, change_tick) };
                         // SAFETY: `entity` is valid, `location` matches entity, bundle matches inserter
                         unsafe { spawner.spawn_non_existent(entity, bundle) };
                         spawn_or_insert = SpawnOrInsert::Spawn(spawner);
@@ -1613,6 +1675,8 @@ impl World {
             }
         }
 
+        self.flush_commands();
+
         if invalid_entities.is_empty() {
             Ok(())
         } else {
@@ -1832,6 +1896,28 @@ impl World {
         }
     }
 
+    /// Creates a new [`Commands`] instance that writes to the world's internal [`CommandQueue`].
+    ///
+    /// Use [`World::flush_commands`] to apply all queued commands.
+    #[inline]
+    pub fn commands(&mut self) -> Commands<'_, '_> {
+        Commands::new_from_entities(&mut self.command_queue, &self.entities)
+    }
+
+    /// Applies any commands in the world's internal [`CommandQueue`].
+    /// This does not apply commands from any systems, only those stored in the world.
+    ///
+    /// Commands are pushed to this queue by [`DeferredWorld::commands`], for example from
+    /// [component hooks](crate::component::ComponentHooks).
+    pub fn flush_commands(&mut self) {
+        // Applying commands may trigger hooks that queue further commands, so keep going
+        // until the queue is drained.
+        while !self.command_queue.is_empty() {
+            let mut commands = std::mem::take(&mut self.command_queue);
+            commands.apply(self);
+        }
+    }
+
     /// Increments the world's current change tick and returns the old value.
     #[inline]
     pub fn increment_change_tick(&self) -> Tick {
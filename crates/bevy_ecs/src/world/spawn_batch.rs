@@ -15,7 +15,7 @@ where
     I::Item: Bundle,
 {
     inner: I,
-    spawner: BundleSpawner<'w, 'w>,
+    spawner: BundleSpawner<'w>,
 }
 
 impl<'w, I> SpawnBatchIter<'w, I>
@@ -34,17 +34,9 @@ where
         let (lower, upper) = iter.size_hint();
         let length = upper.unwrap_or(lower);
 
-        let bundle_info = world
-            .bundles
-            .init_info::<I::Item>(&mut world.components, &mut world.storages);
         world.entities.reserve(length as u32);
-        let mut spawner = bundle_info.get_bundle_spawner(
-            &mut world.entities,
-            &mut world.archetypes,
-            &world.components,
-            &mut world.storages,
-            change_tick,
-        );
+
+        let mut spawner = BundleSpawner::new::<I::Item>(world, change_tick);
         spawner.reserve_storage(length);
 
         Self {
@@ -60,7 +52,11 @@ where
     I::Item: Bundle,
 {
     fn drop(&mut self) {
-        for _ in self {}
+        // Iterate through self in order to spawn remaining bundles.
+        for _ in &mut *self {}
+        // Apply any commands from those operations.
+        // SAFETY: `self.spawner` will be dropped immediately after this call.
+        unsafe { self.spawner.flush_commands() };
     }
 }
 
@@ -11,10 +11,13 @@ use crate::{
         ComponentId, ComponentStorage, ComponentTicks, Components, StorageType, Tick, TickCells,
     },
     entity::{Entities, Entity, EntityLocation},
+    error::ErrorHandler,
+    observer::Observers,
     prelude::Component,
+    query::{DebugCheckedUnwrap, ReadOnlyQueryData},
     removal_detection::RemovedComponentEvents,
     storage::{Column, ComponentSparseSet, Storages},
-    system::{Res, Resource},
+    system::{CommandQueue, Res, Resource},
 };
 use bevy_ptr::Ptr;
 use std::{any::TypeId, cell::UnsafeCell, fmt::Debug, marker::PhantomData, ptr};
@@ -225,6 +228,14 @@ impl<'w> UnsafeWorldCell<'w> {
         &unsafe { self.world_metadata() }.components
     }
 
+    /// Retrieves this world's [`Observers`] collection.
+    #[inline]
+    pub(crate) fn observers(self) -> &'w Observers {
+        // SAFETY:
+        // - we only access world metadata
+        &unsafe { self.world_metadata() }.observers
+    }
+
     /// Retrieves this world's collection of [removed components](RemovedComponentEvents).
     pub fn removed_components(self) -> &'w RemovedComponentEvents {
         // SAFETY:
@@ -240,6 +251,14 @@ impl<'w> UnsafeWorldCell<'w> {
         &unsafe { self.world_metadata() }.bundles
     }
 
+    /// Retrieves this world's [`ErrorHandler`].
+    #[inline]
+    pub fn error_handler(self) -> ErrorHandler {
+        // SAFETY:
+        // - we only access world metadata
+        unsafe { self.world_metadata() }.error_handler
+    }
+
     /// Gets the current change tick of this world.
     #[inline]
     pub fn change_tick(self) -> Tick {
@@ -272,6 +291,38 @@ impl<'w> UnsafeWorldCell<'w> {
         unsafe { self.world_metadata() }.increment_change_tick()
     }
 
+    /// Retrieves this world's [`CommandQueue`].
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that no mutable references exist to the command queue at the same time
+    #[inline]
+    pub(crate) unsafe fn get_command_queue(self) -> &'w mut CommandQueue {
+        // SAFETY:
+        // - caller ensures there are no existing mutable references
+        // - caller ensures that we have permission to access the queue
+        unsafe { &mut *ptr::addr_of_mut!((*self.0).command_queue) }
+    }
+
+    /// Returns the id of the last trigger that was fired in this world.
+    #[inline]
+    pub(crate) fn last_trigger_id(self) -> u32 {
+        // SAFETY:
+        // - we only access world metadata
+        unsafe { self.world_metadata() }.last_trigger_id
+    }
+
+    /// Increments the world's trigger id, used to prevent an observer from running
+    /// more than once for the same trigger.
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that no other references to the trigger id exist
+    #[inline]
+    pub(crate) unsafe fn increment_trigger_id(self) {
+        // SAFETY:
+        // - caller ensures there are no existing references to the trigger id
+        unsafe { (*self.0).last_trigger_id += 1 };
+    }
+
     /// Provides unchecked access to the internal data stores of the [`World`].
     ///
     /// # Safety
@@ -710,6 +761,52 @@ impl<'w> UnsafeEntityCell<'w> {
         }
     }
 
+    /// Returns read-only components for the current entity that match the query `Q`,
+    /// or `None` if the entity does not have the components required by the query `Q`.
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the queried data immutably
+    /// - no mutable references to the queried data exist at the same time
+    pub(crate) unsafe fn get_components<Q: ReadOnlyQueryData>(&self) -> Option<Q::Item<'w>> {
+        // SAFETY: World is only used to access query data and initialize query state
+        let state = unsafe { Q::get_state(self.world.world_metadata())? };
+        let location = self.location();
+        // SAFETY: Location is guaranteed to exist
+        let archetype = unsafe {
+            self.world
+                .archetypes()
+                .get(location.archetype_id)
+                .debug_checked_unwrap()
+        };
+        if Q::matches_component_set(&state, &|id| archetype.contains(id)) {
+            // SAFETY: state was initialized above using the world passed into this function
+            let mut fetch = unsafe {
+                Q::init_fetch(
+                    self.world,
+                    &state,
+                    self.world.last_change_tick(),
+                    self.world.change_tick(),
+                )
+            };
+            // SAFETY: Table is guaranteed to exist
+            let table = unsafe {
+                self.world
+                    .storages()
+                    .tables
+                    .get(location.table_id)
+                    .debug_checked_unwrap()
+            };
+            // SAFETY: Archetype and table are from the same world used to initialize state and fetch.
+            // Table corresponds to archetype. State is the same state used to init fetch above.
+            unsafe { Q::set_archetype(&mut fetch, &state, archetype, table) }
+            // SAFETY: Called after set_archetype above. Entity and location are guaranteed to exist.
+            unsafe { Some(Q::fetch(&mut fetch, self.id(), location.table_row)) }
+        } else {
+            None
+        }
+    }
+
     /// # Safety
     /// It is the callers responsibility to ensure that
     /// - the [`UnsafeEntityCell`] has permission to access the component
@@ -6,7 +6,7 @@ use crate::circles::DEFAULT_CIRCLE_SEGMENTS;
 use bevy_ecs::{
     component::Tick,
     system::{Deferred, ReadOnlySystemParam, Res, Resource, SystemBuffer, SystemMeta, SystemParam},
-    world::{unsafe_world_cell::UnsafeWorldCell, World},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
 use bevy_math::{primitives::Direction3d, Mat2, Quat, Vec2, Vec3};
 use bevy_render::color::Color;
@@ -111,7 +111,11 @@ struct GizmoBuffer<T: GizmoConfigGroup> {
 }
 
 impl<T: GizmoConfigGroup> SystemBuffer for GizmoBuffer<T> {
-    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+    fn apply(&mut self, system_meta: &SystemMeta, world: &mut World) {
+        self.queue(system_meta, world.into());
+    }
+
+    fn queue(&mut self, _system_meta: &SystemMeta, mut world: DeferredWorld) {
         let mut storage = world.resource_mut::<GizmoStorage<T>>();
         storage.list_positions.append(&mut self.list_positions);
         storage.list_colors.append(&mut self.list_colors);
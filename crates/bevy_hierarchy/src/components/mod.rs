@@ -3,3 +3,96 @@ mod parent;
 
 pub use children::Children;
 pub use parent::Parent;
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        relationship::{Relationship, RelationshipTarget},
+        world::World,
+    };
+
+    use super::{Children, Parent};
+    use crate::{BuildWorldChildren, DespawnRecursiveExt};
+
+    // The same hierarchy as `Parent` and `Children`, maintained by relationship hooks.
+    #[derive(Component)]
+    #[relationship(relationship_target = ChildrenOf)]
+    struct ChildOf(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = ChildOf, linked_spawn)]
+    struct ChildrenOf(Vec<Entity>);
+
+    type Links = Vec<Option<(Option<Entity>, Vec<Entity>)>>;
+
+    fn hierarchy(world: &World, entities: &[Entity]) -> Links {
+        entities
+            .iter()
+            .map(|&entity| {
+                let entity = world.get_entity(entity)?;
+                let parent = entity.get::<Parent>().map(Parent::get);
+                let children = entity.get::<Children>().map(|c| c.to_vec());
+                Some((parent, children.unwrap_or_default()))
+            })
+            .collect()
+    }
+
+    fn relationships(world: &World, entities: &[Entity]) -> Links {
+        entities
+            .iter()
+            .map(|&entity| {
+                let entity = world.get_entity(entity)?;
+                let parent = entity.get::<ChildOf>().map(Relationship::get);
+                let children = entity.get::<ChildrenOf>().map(|c| c.iter().collect());
+                Some((parent, children.unwrap_or_default()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hierarchy_is_expressible_as_a_relationship() {
+        let mut hierarchy_world = World::new();
+        let mut relationship_world = World::new();
+        let entities: Vec<Entity> = (0..6).map(|_| hierarchy_world.spawn_empty().id()).collect();
+        for &entity in &entities {
+            assert_eq!(relationship_world.spawn_empty().id(), entity);
+        }
+        let [root, other_root, a, b, c, grandchild] = entities[..] else {
+            unreachable!();
+        };
+
+        hierarchy_world.entity_mut(root).push_children(&[a, b, c]);
+        hierarchy_world.entity_mut(a).add_child(grandchild);
+        for child in [a, b, c] {
+            relationship_world.entity_mut(child).insert(ChildOf(root));
+        }
+        relationship_world.entity_mut(grandchild).insert(ChildOf(a));
+        let expected = hierarchy(&hierarchy_world, &entities);
+        assert_eq!(expected[0], Some((None, vec![a, b, c])));
+        assert_eq!(relationships(&relationship_world, &entities), expected);
+
+        // reparenting
+        hierarchy_world.entity_mut(b).set_parent(other_root);
+        relationship_world.entity_mut(b).insert(ChildOf(other_root));
+        let expected = hierarchy(&hierarchy_world, &entities);
+        assert_eq!(expected[1], Some((None, vec![b])));
+        assert_eq!(relationships(&relationship_world, &entities), expected);
+
+        // removing the parent
+        hierarchy_world.entity_mut(c).remove_parent();
+        relationship_world.entity_mut(c).remove::<ChildOf>();
+        let expected = hierarchy(&hierarchy_world, &entities);
+        assert_eq!(expected[0], Some((None, vec![a])));
+        assert_eq!(relationships(&relationship_world, &entities), expected);
+
+        // recursive despawning
+        hierarchy_world.entity_mut(root).despawn_recursive();
+        relationship_world.despawn(root);
+        let expected = hierarchy(&hierarchy_world, &entities);
+        assert_eq!(expected[0], None);
+        assert_eq!(expected[5], None);
+        assert_eq!(relationships(&relationship_world, &entities), expected);
+    }
+}
@@ -63,6 +63,11 @@ pub use valid_parent_check_plugin::*;
 mod query_extension;
 pub use query_extension::*;
 
+#[cfg(feature = "bevy_app")]
+mod state_scoped;
+#[cfg(feature = "bevy_app")]
+pub use state_scoped::*;
+
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
@@ -70,7 +75,7 @@ pub mod prelude {
 
     #[doc(hidden)]
     #[cfg(feature = "bevy_app")]
-    pub use crate::{HierarchyPlugin, ValidParentCheckPlugin};
+    pub use crate::{HierarchyPlugin, StateScopedAppExt, ValidParentCheckPlugin};
 }
 
 #[cfg(feature = "bevy_app")]
@@ -0,0 +1,114 @@
+use bevy_app::{App, StateTransition};
+use bevy_ecs::{
+    event::Events,
+    schedule::{register_state_scoped_entities, StateTransitionEvent, States},
+};
+use bevy_log::warn;
+
+use crate::DespawnRecursiveExt;
+
+/// Extension trait for [`App`] to despawn the entities marked with
+/// [`StateScoped<S>`](bevy_ecs::schedule::StateScoped) when `S` exits their state.
+pub trait StateScopedAppExt {
+    /// Enables the despawning of entities marked with [`StateScoped<S>`](bevy_ecs::schedule::StateScoped)
+    /// when `S` exits their state. The scoped entities are despawned along with their descendants.
+    ///
+    /// The state must already be added to the app, with [`App::init_state`], [`App::insert_state`],
+    /// [`App::add_computed_state`] or [`App::add_sub_state`]. The entities are despawned during
+    /// [`StateTransition`], after the [`OnExit`](bevy_ecs::schedule::OnExit) schedule of the
+    /// exited state has run.
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
+}
+
+impl StateScopedAppExt for App {
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            warn!(
+                "State scoped entities are enabled for state `{}`, but the state isn't installed in the app!",
+                std::any::type_name::<S>()
+            );
+        }
+        self.edit_schedule(StateTransition, |schedule| {
+            register_state_scoped_entities::<S>(schedule, |entity| entity.despawn_recursive());
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{
+        self as bevy_ecs,
+        schedule::{NextState, StateScoped, States},
+    };
+
+    use super::StateScopedAppExt;
+    use crate::BuildWorldChildren;
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    enum Screen {
+        #[default]
+        Title,
+        Level,
+    }
+
+    #[test]
+    fn state_scoped_entities_are_despawned_on_exit() {
+        let mut app = App::new();
+        app.init_state::<Screen>()
+            .enable_state_scoped_entities::<Screen>();
+        app.update();
+
+        let title = app.world.spawn(StateScoped(Screen::Title)).id();
+        let level = app.world.spawn(StateScoped(Screen::Level)).id();
+
+        app.world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Level);
+        app.update();
+        assert!(app.world.get_entity(title).is_none());
+        assert!(app.world.get_entity(level).is_some());
+
+        app.world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Title);
+        app.update();
+        assert!(app.world.get_entity(level).is_none());
+    }
+
+    #[test]
+    fn state_scoped_entities_are_despawned_with_their_children() {
+        let mut app = App::new();
+        app.init_state::<Screen>()
+            .enable_state_scoped_entities::<Screen>();
+        app.update();
+
+        let mut child = None;
+        let mut grandchild = None;
+        let parent = app
+            .world
+            .spawn(StateScoped(Screen::Title))
+            .with_children(|parent| {
+                child = Some(
+                    parent
+                        .spawn_empty()
+                        .with_children(|child| {
+                            grandchild = Some(child.spawn_empty().id());
+                        })
+                        .id(),
+                );
+            })
+            .id();
+
+        app.world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Level);
+        app.update();
+        assert!(app.world.get_entity(parent).is_none());
+        assert!(app.world.get_entity(child.unwrap()).is_none());
+        assert!(app.world.get_entity(grandchild.unwrap()).is_none());
+    }
+}
@@ -62,6 +62,12 @@ pub mod reflect {
     pub use bevy_reflect::*;
 }
 
+#[cfg(feature = "bevy_remote")]
+pub mod remote {
+    //! Inspect and edit the World from other processes over a JSON-RPC protocol.
+    pub use bevy_remote::*;
+}
+
 #[cfg(feature = "bevy_scene")]
 pub mod scene {
     //! Save/load collections of entities and components to/from file.
@@ -14,7 +14,7 @@ use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Expr, LitBool, Meta, MetaList, Path, WhereClause};
+use syn::{Expr, LitBool, Meta, MetaList, Path, Token, WhereClause};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
@@ -218,6 +218,27 @@ pub(crate) struct ReflectTraits {
     custom_where: Option<WhereClause>,
     no_field_bounds: bool,
     idents: Vec<Ident>,
+    custom_attributes: Vec<Expr>,
+}
+
+/// An item of a `#[reflect(...)]` container attribute.
+enum ContainerAttr {
+    Meta(Meta),
+    /// A custom attribute, like `@Tooltip("...")`.
+    Custom(Expr),
+}
+
+impl ContainerAttr {
+    fn parse_terminated(input: ParseStream) -> syn::Result<Punctuated<Self, Comma>> {
+        Punctuated::parse_terminated_with(input, |input| {
+            if input.peek(Token![@]) {
+                input.parse::<Token![@]>()?;
+                Ok(Self::Custom(input.parse()?))
+            } else {
+                Ok(Self::Meta(input.parse()?))
+            }
+        })
+    }
 }
 
 impl ReflectTraits {
@@ -229,19 +250,27 @@ impl ReflectTraits {
                 ..Self::default()
             }),
             _ => Self::from_metas(
-                meta.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?,
+                meta.parse_args_with(ContainerAttr::parse_terminated)?,
                 trait_,
             ),
         }
     }
 
     fn from_metas(
-        metas: Punctuated<Meta, Comma>,
+        metas: Punctuated<ContainerAttr, Comma>,
         trait_: ReflectTraitToImpl,
     ) -> Result<Self, syn::Error> {
         let mut traits = ReflectTraits::default();
-        for meta in &metas {
-            match meta {
+        for meta in metas {
+            let meta = match meta {
+                // Handles `#[reflect( @Tooltip("...") )]`
+                ContainerAttr::Custom(expr) => {
+                    traits.custom_attributes.push(expr);
+                    continue;
+                }
+                ContainerAttr::Meta(meta) => meta,
+            };
+            match &meta {
                 // Handles `#[reflect( Debug, PartialEq, Hash, SomeTrait )]`
                 Meta::Path(path) => {
                     let Some(ident) = path.get_ident() else {
@@ -333,7 +362,7 @@ impl ReflectTraits {
     }
 
     pub fn parse(input: ParseStream, trait_: ReflectTraitToImpl) -> syn::Result<Self> {
-        ReflectTraits::from_metas(Punctuated::parse_terminated(input)?, trait_)
+        ReflectTraits::from_metas(ContainerAttr::parse_terminated(input)?, trait_)
     }
 
     /// Returns true if the given reflected trait name (i.e. `ReflectDefault` for `Default`)
@@ -431,6 +460,11 @@ impl ReflectTraits {
         self.custom_where.as_ref()
     }
 
+    /// The custom attributes of the type, set with `#[reflect(@...)]`.
+    pub fn custom_attributes(&self) -> &[Expr] {
+        &self.custom_attributes
+    }
+
     pub fn no_field_bounds(&self) -> bool {
         self.no_field_bounds
     }
@@ -452,6 +486,7 @@ impl ReflectTraits {
         for ident in other.idents {
             add_unique_ident(&mut self.idents, ident)?;
         }
+        self.custom_attributes.extend(other.custom_attributes);
         Ok(())
     }
 
@@ -3,10 +3,15 @@
 //! A field attribute is an attribute which applies to particular field or variant
 //! as opposed to an entire struct or enum. An example of such an attribute is
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
+//!
+//! Custom attributes, which are any expression prefixed with `@` like `#[reflect(@0.0..=1.0)]`,
+//! can be mixed with the other attributes.
 
 use crate::REFLECT_ATTRIBUTE_NAME;
+use proc_macro2::{TokenStream, TokenTree};
 use syn::meta::ParseNestedMeta;
-use syn::{Attribute, LitStr, Token};
+use syn::parse::{ParseStream, Parser};
+use syn::{Attribute, Expr, LitStr, Token};
 
 pub(crate) static IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) static IGNORE_ALL_ATTR: &str = "ignore";
@@ -51,6 +56,8 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Custom attributes, set with `#[reflect(@...)]`.
+    pub custom_attributes: Vec<Expr>,
 }
 
 /// Controls how the default value is determined for a field.
@@ -77,7 +84,7 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path().is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
-        let result = attr.parse_nested_meta(|meta| parse_meta(&mut args, meta));
+        let result = attr.parse_args_with(|input: ParseStream| parse_attr(&mut args, input));
         if let Err(err) = result {
             if let Some(ref mut error) = errors {
                 error.combine(err);
@@ -94,6 +101,30 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
     }
 }
 
+/// Parses the comma-separated contents of a `#[reflect(...)]` attribute.
+fn parse_attr(args: &mut ReflectFieldAttr, input: ParseStream) -> Result<(), syn::Error> {
+    while !input.is_empty() {
+        if input.peek(Token![@]) {
+            // Allow:
+            // - `#[reflect(@expr)]`
+            input.parse::<Token![@]>()?;
+            args.custom_attributes.push(input.parse::<Expr>()?);
+        } else {
+            // Other attributes never contain top-level commas.
+            let mut tokens = TokenStream::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                tokens.extend([input.parse::<TokenTree>()?]);
+            }
+            syn::meta::parser(|meta| parse_meta(args, meta)).parse2(tokens)?;
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(())
+}
+
 fn parse_meta(args: &mut ReflectFieldAttr, meta: ParseNestedMeta) -> Result<(), syn::Error> {
     if meta.path.is_ident(DEFAULT_ATTR) {
         // Allow:
@@ -1,6 +1,7 @@
 use crate::derive_data::{EnumVariant, EnumVariantFields, ReflectEnum, StructField};
 use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
 use crate::impls::{impl_type_path, impl_typed};
+use crate::utility::with_custom_attributes;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQOption, FQResult};
 use proc_macro2::{Ident, Span};
 use quote::quote;
@@ -57,18 +58,23 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
         });
 
+    let custom_attributes = with_custom_attributes(
+        reflect_enum.meta().traits().custom_attributes(),
+        bevy_reflect_path,
+    );
+
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_enum.meta().doc();
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(&variants).with_docs(#doc)
+            #bevy_reflect_path::EnumInfo::new::<Self>(&variants) #custom_attributes .with_docs(#doc)
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(&variants)
+            #bevy_reflect_path::EnumInfo::new::<Self>(&variants) #custom_attributes
         }
     };
 
@@ -369,9 +375,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                 #[cfg(not(feature = "documentation"))]
                 let with_docs: Option<proc_macro2::TokenStream> = None;
 
+                let custom_attributes =
+                    with_custom_attributes(&_variant.attrs.custom_attributes, bevy_reflect_path);
+
                 variant_info.push(quote! {
                     #bevy_reflect_path::VariantInfo::#variant_type_ident(
                         #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                        #custom_attributes
                         #with_docs
                     )
                 });
@@ -403,8 +413,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
                     let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        with_custom_attributes(&field.attrs.custom_attributes, bevy_reflect_path);
                     quote! {
                         #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
+                        #custom_attributes
                         #with_docs
                     }
                 });
@@ -438,8 +451,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
                     let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        with_custom_attributes(&field.attrs.custom_attributes, bevy_reflect_path);
                     quote! {
                         #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                        #custom_attributes
                         #with_docs
                     }
                 });
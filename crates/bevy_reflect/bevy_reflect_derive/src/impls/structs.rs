@@ -1,5 +1,5 @@
 use crate::impls::{impl_type_path, impl_typed};
-use crate::utility::ident_or_index;
+use crate::utility::{ident_or_index, with_custom_attributes};
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
 use quote::{quote, ToTokens};
@@ -46,20 +46,29 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             }
         });
 
+    let field_attributes = reflect_struct
+        .active_fields()
+        .map(|field| with_custom_attributes(&field.attrs.custom_attributes, bevy_reflect_path))
+        .collect::<Vec<_>>();
+    let custom_attributes = with_custom_attributes(
+        reflect_struct.meta().traits().custom_attributes(),
+        bevy_reflect_path,
+    );
+
     #[cfg(feature = "documentation")]
     let field_generator = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_attributes .with_docs(#docs) ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_attributes ,)*
         }
     };
 
@@ -67,14 +76,14 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
     let info_generator = {
         let doc = reflect_struct.meta().doc();
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(&fields).with_docs(#doc)
+            #bevy_reflect_path::StructInfo::new::<Self>(&fields) #custom_attributes .with_docs(#doc)
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(&fields)
+            #bevy_reflect_path::StructInfo::new::<Self>(&fields) #custom_attributes
         }
     };
 
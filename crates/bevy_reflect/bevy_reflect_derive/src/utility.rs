@@ -8,7 +8,7 @@ use bevy_macro_utils::{
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::punctuated::Punctuated;
-use syn::{spanned::Spanned, LitStr, Member, Path, Token, Type, WhereClause};
+use syn::{spanned::Spanned, Expr, LitStr, Member, Path, Token, Type, WhereClause};
 
 /// Returns the correct path for `bevy_reflect`.
 pub(crate) fn get_bevy_reflect_path() -> Path {
@@ -291,6 +291,24 @@ impl<T> ResultSifter<T> {
     }
 }
 
+/// Returns the `.with_custom_attributes(...)` call setting the given custom attributes
+/// (`#[reflect(@...)]`) on a type, field or variant info, if there are any.
+pub(crate) fn with_custom_attributes(
+    custom_attributes: &[Expr],
+    bevy_reflect_path: &Path,
+) -> Option<TokenStream> {
+    if custom_attributes.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        .with_custom_attributes(
+            #bevy_reflect_path::CustomAttributes::default()
+                #(.with_attribute(#custom_attributes))*
+        )
+    })
+}
+
 /// Turns an `Option<TokenStream>` into a `TokenStream` for an `Option`.
 pub(crate) fn wrap_in_option(tokens: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
     match tokens {
@@ -0,0 +1,226 @@
+use crate::Reflect;
+use bevy_utils::TypeIdMap;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+
+/// A collection of custom attributes of a type, field, or variant, keyed by their type.
+///
+/// Custom attributes are set in the [`Reflect`] derive with `#[reflect(@...)]`, `...` being any
+/// expression whose type implements [`Reflect`]. They can hold any metadata, like validation
+/// rules, editor hints or replication settings, without maintaining separate tables.
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// # use std::ops::RangeInclusive;
+/// #[derive(Reflect)]
+/// struct Tooltip(String);
+///
+/// #[derive(Reflect)]
+/// #[reflect(@Tooltip("A slider".to_string()))]
+/// struct Slider {
+///     #[reflect(@0.0..=1.0)]
+///     value: f64,
+/// }
+///
+/// let TypeInfo::Struct(info) = Slider::type_info() else {
+///     unreachable!()
+/// };
+/// assert_eq!(info.get_attribute::<Tooltip>().unwrap().0, "A slider");
+///
+/// let range = info
+///     .field("value")
+///     .unwrap()
+///     .get_attribute::<RangeInclusive<f64>>()
+///     .unwrap();
+/// assert_eq!(*range, 0.0..=1.0);
+/// ```
+#[derive(Default)]
+pub struct CustomAttributes {
+    attributes: TypeIdMap<Box<dyn Reflect>>,
+}
+
+impl CustomAttributes {
+    /// Adds `value` as an attribute, replacing any attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.attributes.insert(TypeId::of::<T>(), Box::new(value));
+        self
+    }
+
+    /// Returns `true` if there is an attribute of type `T`.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.attributes.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns `true` if there is an attribute of the type with the given [`TypeId`].
+    pub fn contains_by_id(&self, id: TypeId) -> bool {
+        self.attributes.contains_key(&id)
+    }
+
+    /// Returns the attribute of type `T`, if any.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.attributes.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the attribute of the type with the given [`TypeId`], if any.
+    pub fn get_by_id(&self, id: TypeId) -> Option<&dyn Reflect> {
+        self.attributes.get(&id).map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the attributes and the [`TypeId`] of their types.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (TypeId, &dyn Reflect)> {
+        self.attributes
+            .iter()
+            .map(|(id, attribute)| (*id, attribute.as_ref()))
+    }
+
+    /// Returns the number of attributes.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.attributes.values().map(|attribute| {
+                struct DebugAttribute<'a>(&'a dyn Reflect);
+
+                impl Debug for DebugAttribute<'_> {
+                    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                        self.0.debug(f)
+                    }
+                }
+
+                DebugAttribute(attribute.as_ref())
+            }))
+            .finish()
+    }
+}
+
+/// Implements the methods accessing the custom attributes of a type info, field info or variant
+/// info, which stores them in an `Arc<CustomAttributes>` field named `custom_attributes`.
+macro_rules! impl_custom_attribute_methods {
+    ($item:literal) => {
+        #[doc = concat!("Sets the [custom attributes](crate::CustomAttributes) of this ", $item, ".")]
+        pub fn with_custom_attributes(self, custom_attributes: $crate::CustomAttributes) -> Self {
+            Self {
+                custom_attributes: ::std::sync::Arc::new(custom_attributes),
+                ..self
+            }
+        }
+
+        #[doc = concat!("The [custom attributes](crate::CustomAttributes) of this ", $item, ".")]
+        pub fn custom_attributes(&self) -> &$crate::CustomAttributes {
+            &self.custom_attributes
+        }
+
+        #[doc = concat!("Returns the custom attribute of type `T` of this ", $item, ", if any.")]
+        pub fn get_attribute<T: $crate::Reflect>(&self) -> Option<&T> {
+            self.custom_attributes.get::<T>()
+        }
+
+        #[doc = concat!("Returns `true` if this ", $item, " has a custom attribute of type `T`.")]
+        pub fn has_attribute<T: $crate::Reflect>(&self) -> bool {
+            self.custom_attributes.contains::<T>()
+        }
+    };
+}
+
+pub(crate) use impl_custom_attribute_methods;
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeInfo, Typed, VariantInfo};
+    use std::ops::RangeInclusive;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Tooltip(&'static str);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Replicated;
+
+    #[test]
+    fn should_get_custom_attributes() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip("a slider"), @Replicated)]
+        struct Slider {
+            #[reflect(@0.0..=1.0, @Tooltip("the value"))]
+            value: f64,
+            #[reflect(default, @Tooltip("the number of steps"))]
+            steps: u32,
+            hint: String,
+        }
+
+        let TypeInfo::Struct(info) = Slider::type_info() else {
+            panic!("expected a struct");
+        };
+        assert_eq!(info.custom_attributes().len(), 2);
+        assert_eq!(info.get_attribute::<Tooltip>(), Some(&Tooltip("a slider")));
+        assert!(info.has_attribute::<Replicated>());
+
+        let value = info.field("value").unwrap();
+        assert_eq!(
+            value.get_attribute::<RangeInclusive<f64>>(),
+            Some(&(0.0..=1.0))
+        );
+        assert_eq!(
+            value.get_attribute::<Tooltip>(),
+            Some(&Tooltip("the value"))
+        );
+        assert_eq!(
+            info.field("steps").unwrap().get_attribute::<Tooltip>(),
+            Some(&Tooltip("the number of steps"))
+        );
+        assert!(info.field("hint").unwrap().custom_attributes().is_empty());
+    }
+
+    #[test]
+    fn should_get_custom_attributes_of_enums() {
+        #[derive(Reflect)]
+        #[reflect(@Replicated)]
+        enum Shape {
+            #[reflect(@Tooltip("a point"))]
+            Point,
+            Circle(#[reflect(@0.0_f32..=10.0)] f32),
+        }
+
+        #[derive(Reflect)]
+        #[reflect(@Tooltip("a tuple struct"))]
+        struct Wrapper(#[reflect(@Replicated)] u8);
+
+        let TypeInfo::Enum(info) = Shape::type_info() else {
+            panic!("expected an enum");
+        };
+        assert!(info.has_attribute::<Replicated>());
+        let Some(VariantInfo::Unit(point)) = info.variant("Point") else {
+            panic!("expected a unit variant");
+        };
+        assert_eq!(point.get_attribute::<Tooltip>(), Some(&Tooltip("a point")));
+        let Some(VariantInfo::Tuple(circle)) = info.variant("Circle") else {
+            panic!("expected a tuple variant");
+        };
+        assert!(circle.custom_attributes().is_empty());
+        assert_eq!(
+            circle
+                .field_at(0)
+                .unwrap()
+                .get_attribute::<RangeInclusive<f32>>(),
+            Some(&(0.0..=10.0))
+        );
+
+        let TypeInfo::TupleStruct(info) = Wrapper::type_info() else {
+            panic!("expected a tuple struct");
+        };
+        assert_eq!(
+            info.get_attribute::<Tooltip>(),
+            Some(&Tooltip("a tuple struct"))
+        );
+        assert!(info.field_at(0).unwrap().has_attribute::<Replicated>());
+    }
+}
@@ -0,0 +1,701 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::{
+    Access, Enum, List, Map, ParsedPath, Reflect, ReflectKind, ReflectMut, ReflectPath,
+    ReflectPathError, ReflectRef, Struct, VariantType,
+};
+
+/// A single change of a [`ReflectDiff`], applied to the element at its `path`.
+///
+/// Paths are relative to the value the diff is applied to, an empty path being the value itself.
+pub enum DiffOp {
+    /// Replaces the element at `path` with `value`.
+    ///
+    /// This is used for changed [value types](ReflectKind::Value), enums changing variant, and
+    /// values whose structure changed, like dynamic structs with different fields.
+    Replace {
+        path: ParsedPath,
+        value: Box<dyn Reflect>,
+    },
+    /// Inserts `value` at `index` in the [`List`] at `path`.
+    ListInsert {
+        path: ParsedPath,
+        index: usize,
+        value: Box<dyn Reflect>,
+    },
+    /// Removes the element at `index` from the [`List`] at `path`.
+    ListRemove { path: ParsedPath, index: usize },
+    /// Inserts `value` at `key` in the [`Map`] at `path`, replacing any previous value.
+    MapInsert {
+        path: ParsedPath,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    },
+    /// Removes the entry at `key` from the [`Map`] at `path`.
+    MapRemove {
+        path: ParsedPath,
+        key: Box<dyn Reflect>,
+    },
+}
+
+impl DiffOp {
+    /// The path to the element changed by this operation.
+    pub fn path(&self) -> &ParsedPath {
+        match self {
+            DiffOp::Replace { path, .. }
+            | DiffOp::ListInsert { path, .. }
+            | DiffOp::ListRemove { path, .. }
+            | DiffOp::MapInsert { path, .. }
+            | DiffOp::MapRemove { path, .. } => path,
+        }
+    }
+
+    /// Applies this operation to `target`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value of this operation can't be converted to the type of the element it's
+    /// applied to, like [`Reflect::apply`].
+    pub fn apply<'a>(&'a self, target: &mut dyn Reflect) -> Result<(), DiffError<'a>> {
+        let path = self.path();
+        let element = path
+            .reflect_element_mut(target)
+            .map_err(|error| DiffError::InvalidPath { path, error })?;
+        let incompatible = |expected, element: &dyn Reflect| DiffError::IncompatibleKind {
+            path,
+            expected,
+            actual: element.reflect_kind(),
+        };
+        match self {
+            DiffOp::Replace { value, .. } => {
+                if let Err(value) = element.set(value.clone_value()) {
+                    element.apply(&*value);
+                }
+            }
+            DiffOp::ListInsert { index, value, .. } => {
+                let ReflectMut::List(list) = element.reflect_mut() else {
+                    return Err(incompatible(ReflectKind::List, element));
+                };
+                if *index > list.len() {
+                    return Err(DiffError::IndexOutOfBounds {
+                        path,
+                        index: *index,
+                        len: list.len(),
+                    });
+                }
+                list.insert(*index, value.clone_value());
+            }
+            DiffOp::ListRemove { index, .. } => {
+                let ReflectMut::List(list) = element.reflect_mut() else {
+                    return Err(incompatible(ReflectKind::List, element));
+                };
+                if *index >= list.len() {
+                    return Err(DiffError::IndexOutOfBounds {
+                        path,
+                        index: *index,
+                        len: list.len(),
+                    });
+                }
+                list.remove(*index);
+            }
+            DiffOp::MapInsert { key, value, .. } => {
+                let ReflectMut::Map(map) = element.reflect_mut() else {
+                    return Err(incompatible(ReflectKind::Map, element));
+                };
+                map.insert_boxed(key.clone_value(), value.clone_value());
+            }
+            DiffOp::MapRemove { key, .. } => {
+                let ReflectMut::Map(map) = element.reflect_mut() else {
+                    return Err(incompatible(ReflectKind::Map, element));
+                };
+                map.remove(&**key);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clone for DiffOp {
+    fn clone(&self) -> Self {
+        match self {
+            DiffOp::Replace { path, value } => DiffOp::Replace {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            DiffOp::ListInsert { path, index, value } => DiffOp::ListInsert {
+                path: path.clone(),
+                index: *index,
+                value: value.clone_value(),
+            },
+            DiffOp::ListRemove { path, index } => DiffOp::ListRemove {
+                path: path.clone(),
+                index: *index,
+            },
+            DiffOp::MapInsert { path, key, value } => DiffOp::MapInsert {
+                path: path.clone(),
+                key: key.clone_value(),
+                value: value.clone_value(),
+            },
+            DiffOp::MapRemove { path, key } => DiffOp::MapRemove {
+                path: path.clone(),
+                key: key.clone_value(),
+            },
+        }
+    }
+}
+
+impl fmt::Debug for DiffOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffOp::Replace { path, value } => f
+                .debug_struct("Replace")
+                .field("path", &path.to_string())
+                .field("value", value)
+                .finish(),
+            DiffOp::ListInsert { path, index, value } => f
+                .debug_struct("ListInsert")
+                .field("path", &path.to_string())
+                .field("index", index)
+                .field("value", value)
+                .finish(),
+            DiffOp::ListRemove { path, index } => f
+                .debug_struct("ListRemove")
+                .field("path", &path.to_string())
+                .field("index", index)
+                .finish(),
+            DiffOp::MapInsert { path, key, value } => f
+                .debug_struct("MapInsert")
+                .field("path", &path.to_string())
+                .field("key", key)
+                .field("value", value)
+                .finish(),
+            DiffOp::MapRemove { path, key } => f
+                .debug_struct("MapRemove")
+                .field("path", &path.to_string())
+                .field("key", key)
+                .finish(),
+        }
+    }
+}
+
+/// An error returned when applying a [`ReflectDiff`] to a value it doesn't fit.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DiffError<'a> {
+    /// The path of an operation doesn't lead to an element of the value.
+    #[error("cannot access `{path}`: {error}")]
+    InvalidPath {
+        path: &'a ParsedPath,
+        error: ReflectPathError<'a>,
+    },
+    /// The element at the path of an operation isn't of the kind the operation changes.
+    #[error("expected a {expected} at `{path}`, found a {actual}")]
+    IncompatibleKind {
+        path: &'a ParsedPath,
+        expected: ReflectKind,
+        actual: ReflectKind,
+    },
+    /// An operation inserts or removes a list element past the end of the list.
+    #[error("index {index} is out of bounds of the list at `{path}` of length {len}")]
+    IndexOutOfBounds {
+        path: &'a ParsedPath,
+        index: usize,
+        len: usize,
+    },
+}
+
+/// A structural difference between two reflected values, which can be applied to a value to
+/// change it the same way.
+///
+/// A diff is made of [`DiffOp`]s changing the elements of the value through [`ParsedPath`]s:
+/// - Fields of [structs](crate::Struct), [tuple structs](crate::TupleStruct),
+///   [tuples](crate::Tuple) and enum variants, and elements of [arrays](crate::Array), are
+///   compared recursively.
+/// - [`List`] elements are matched to find the ones that were inserted and removed, and the
+///   remaining ones are compared recursively.
+/// - [`Map`] entries are inserted, replaced or removed.
+/// - [Sets](crate::Set) are replaced when they aren't equal, as their values can't be modified in
+///   place.
+/// - Other values, and enums that changed variant, are replaced when they aren't equal according
+///   to [`Reflect::reflect_partial_eq`]. [Value types](ReflectKind::Value) that don't implement
+///   it are always replaced.
+///
+/// Since diffs don't hold any reference to the values they were computed from, they can be used
+/// to undo and redo edits, by computing the diff in both directions. They can be serialized with
+/// [`ReflectDiffSerializer`](crate::serde::ReflectDiffSerializer).
+///
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Inventory {
+///     gold: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let old = Inventory {
+///     gold: 10,
+///     items: vec!["sword".to_string(), "shield".to_string()],
+/// };
+/// let new = Inventory {
+///     gold: 5,
+///     items: vec!["shield".to_string(), "potion".to_string()],
+/// };
+///
+/// let diff = ReflectDiff::new(&old, &new);
+/// // `gold` is replaced, "sword" is removed and "potion" is inserted.
+/// assert_eq!(diff.ops().len(), 3);
+///
+/// let mut value = old.clone();
+/// diff.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+///
+/// let undo = ReflectDiff::new(&new, &old);
+/// undo.apply(&mut value).unwrap();
+/// assert_eq!(value, old);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReflectDiff {
+    ops: Vec<DiffOp>,
+}
+
+impl ReflectDiff {
+    /// Computes the changes turning `old` into `new`.
+    ///
+    /// Both values are expected to be of the same type, or to be dynamic representations of it.
+    pub fn new(old: &dyn Reflect, new: &dyn Reflect) -> Self {
+        let mut differ = Differ::default();
+        differ.diff(old, new);
+        Self { ops: differ.ops }
+    }
+
+    /// The operations of this diff, in the order they are applied.
+    pub fn ops(&self) -> &[DiffOp] {
+        &self.ops
+    }
+
+    /// Takes the operations of this diff.
+    pub fn into_ops(self) -> Vec<DiffOp> {
+        self.ops
+    }
+
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Applies the operations of this diff to `target`, in order.
+    ///
+    /// Returns an error at the first operation that doesn't fit `target`, leaving the previous
+    /// operations applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value of this diff can't be converted to the type of the element it's applied
+    /// to, see [`DiffOp::apply`].
+    pub fn apply<'a>(&'a self, target: &mut dyn Reflect) -> Result<(), DiffError<'a>> {
+        self.ops.iter().try_for_each(|op| op.apply(target))
+    }
+}
+
+impl From<Vec<DiffOp>> for ReflectDiff {
+    fn from(ops: Vec<DiffOp>) -> Self {
+        Self { ops }
+    }
+}
+
+#[derive(Default)]
+struct Differ {
+    path: Vec<Access<'static>>,
+    ops: Vec<DiffOp>,
+}
+
+impl Differ {
+    fn path(&self) -> ParsedPath {
+        self.path.clone().into()
+    }
+
+    fn replace(&mut self, new: &dyn Reflect) {
+        let path = self.path();
+        self.ops.push(DiffOp::Replace {
+            path,
+            value: new.clone_value(),
+        });
+    }
+
+    fn diff_element(&mut self, access: Access<'static>, old: &dyn Reflect, new: &dyn Reflect) {
+        self.path.push(access);
+        self.diff(old, new);
+        self.path.pop();
+    }
+
+    fn diff(&mut self, old: &dyn Reflect, new: &dyn Reflect) {
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) if same_fields(old, new) => {
+                for (index, old_field) in old.iter_fields().enumerate() {
+                    let name = old.name_at(index).unwrap().to_string();
+                    let new_field = new.field_at(index).unwrap();
+                    self.diff_element(Access::Field(Cow::Owned(name)), old_field, new_field);
+                }
+            }
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+                if old.field_len() == new.field_len() =>
+            {
+                for (index, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                    self.diff_element(Access::TupleIndex(index), old, new);
+                }
+            }
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new))
+                if old.field_len() == new.field_len() =>
+            {
+                for (index, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                    self.diff_element(Access::TupleIndex(index), old, new);
+                }
+            }
+            (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+                for (index, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                    self.diff_element(Access::ListIndex(index), old, new);
+                }
+            }
+            (ReflectRef::List(old), ReflectRef::List(new)) => self.diff_list(old, new),
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => self.diff_map(old, new),
+            (ReflectRef::Enum(old), ReflectRef::Enum(new)) if same_variant(old, new) => {
+                for (index, (old_field, new_field)) in
+                    old.iter_fields().zip(new.iter_fields()).enumerate()
+                {
+                    let access = match old_field.name() {
+                        Some(name) => Access::Field(Cow::Owned(name.to_string())),
+                        None => Access::TupleIndex(index),
+                    };
+                    self.diff_element(access, old_field.value(), new_field.value());
+                }
+            }
+            // Set values can't be modified in place, so changed sets are replaced.
+            (ReflectRef::Value(_), ReflectRef::Value(_))
+            | (ReflectRef::Set(_), ReflectRef::Set(_))
+                if old.reflect_partial_eq(new) == Some(true) => {}
+            _ => self.replace(new),
+        }
+    }
+
+    fn diff_list(&mut self, old: &dyn List, new: &dyn List) {
+        let matches = matching_elements(old, new);
+        // The index in the list being patched, which changes as elements are inserted and removed.
+        let mut index = 0;
+        let (mut old_next, mut new_next) = (0, 0);
+        for (old_match, new_match) in matches.into_iter().chain([(old.len(), new.len())]) {
+            // Unmatched elements are compared with each other, and the remaining ones of the old
+            // list are removed while the remaining ones of the new list are inserted.
+            let old_gap = old_next..old_match;
+            let new_gap = new_next..new_match;
+            let paired = old_gap.len().min(new_gap.len());
+            for offset in 0..paired {
+                let old = old.get(old_gap.start + offset).unwrap();
+                let new = new.get(new_gap.start + offset).unwrap();
+                self.diff_element(Access::ListIndex(index), old, new);
+                index += 1;
+            }
+            for _ in paired..old_gap.len() {
+                let path = self.path();
+                self.ops.push(DiffOp::ListRemove { path, index });
+            }
+            for new_index in new_gap.start + paired..new_gap.end {
+                let path = self.path();
+                let value = new.get(new_index).unwrap().clone_value();
+                self.ops.push(DiffOp::ListInsert { path, index, value });
+                index += 1;
+            }
+            index += 1;
+            (old_next, new_next) = (old_match + 1, new_match + 1);
+        }
+    }
+
+    fn diff_map(&mut self, old: &dyn Map, new: &dyn Map) {
+        for (key, old_value) in old.iter() {
+            let path = self.path();
+            match new.get(key) {
+                None => self.ops.push(DiffOp::MapRemove {
+                    path,
+                    key: key.clone_value(),
+                }),
+                // Map values can't be accessed with a path, so changed values are replaced.
+                Some(new_value) if old_value.reflect_partial_eq(new_value) != Some(true) => {
+                    self.ops.push(DiffOp::MapInsert {
+                        path,
+                        key: key.clone_value(),
+                        value: new_value.clone_value(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, new_value) in new.iter() {
+            if old.get(key).is_none() {
+                let path = self.path();
+                self.ops.push(DiffOp::MapInsert {
+                    path,
+                    key: key.clone_value(),
+                    value: new_value.clone_value(),
+                });
+            }
+        }
+    }
+}
+
+fn same_fields(old: &dyn Struct, new: &dyn Struct) -> bool {
+    old.field_len() == new.field_len()
+        && (0..old.field_len()).all(|index| old.name_at(index) == new.name_at(index))
+}
+
+fn same_variant(old: &dyn Enum, new: &dyn Enum) -> bool {
+    old.variant_name() == new.variant_name()
+        && old.field_len() == new.field_len()
+        && (old.variant_type() != VariantType::Struct
+            || (0..old.field_len()).all(|index| old.name_at(index) == new.name_at(index)))
+}
+
+/// Finds the longest sequence of equal elements of `old` and `new` appearing in the same order,
+/// returning the pairs of indices of its elements.
+fn matching_elements(old: &dyn List, new: &dyn List) -> Vec<(usize, usize)> {
+    let eq = |old_index, new_index| {
+        let old = old.get(old_index).unwrap();
+        old.reflect_partial_eq(new.get(new_index).unwrap()) == Some(true)
+    };
+    let (old_len, new_len) = (old.len(), new.len());
+    // Equal elements at the start and end are matched directly, which avoids building the
+    // table for the common case of small edits.
+    let prefix = (0..old_len.min(new_len))
+        .take_while(|&index| eq(index, index))
+        .count();
+    let suffix = (0..old_len.min(new_len) - prefix)
+        .take_while(|&offset| eq(old_len - 1 - offset, new_len - 1 - offset))
+        .count();
+    let old_middle = prefix..old_len - suffix;
+    let new_middle = prefix..new_len - suffix;
+
+    // `lengths[i][j]` is the length of the longest common sequence of the elements of the
+    // middles starting at `i` and `j`.
+    let columns = new_middle.len() + 1;
+    let mut lengths = vec![0; (old_middle.len() + 1) * columns];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lengths[i * columns + j] = if eq(old_middle.start + i, new_middle.start + j) {
+                lengths[(i + 1) * columns + j + 1] + 1
+            } else {
+                lengths[(i + 1) * columns + j].max(lengths[i * columns + j + 1])
+            };
+        }
+    }
+
+    let mut matches: Vec<_> = (0..prefix).map(|index| (index, index)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() && j < new_middle.len() {
+        if eq(old_middle.start + i, new_middle.start + j) {
+            matches.push((old_middle.start + i, new_middle.start + j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * columns + j] >= lengths[i * columns + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches.extend(
+        (0..suffix)
+            .rev()
+            .map(|offset| (old_len - 1 - offset, new_len - 1 - offset)),
+    );
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, FromReflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Character {
+        name: String,
+        position: (f32, f32),
+        state: State,
+        items: Vec<Item>,
+        stats: HashMap<String, u32>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        count: u32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Walking { speed: f32 },
+        Carrying(Item),
+    }
+
+    fn character() -> Character {
+        Character {
+            name: "Ferris".to_string(),
+            position: (0.0, 0.0),
+            state: State::Walking { speed: 1.0 },
+            items: (0..4).map(|id| Item { id, count: 1 }).collect(),
+            stats: HashMap::from_iter([("strength".to_string(), 2), ("speed".to_string(), 3)]),
+        }
+    }
+
+    fn assert_round_trip(old: &Character, new: &Character) -> ReflectDiff {
+        let diff = ReflectDiff::new(old, new);
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(&value, new);
+        diff
+    }
+
+    #[test]
+    fn should_diff_nothing_for_equal_values() {
+        assert!(ReflectDiff::new(&character(), &character()).is_empty());
+    }
+
+    #[test]
+    fn should_diff_fields() {
+        let old = character();
+        let mut new = character();
+        new.position.1 = 2.0;
+        new.state = State::Walking { speed: 3.0 };
+        let diff = assert_round_trip(&old, &new);
+
+        let paths: Vec<_> = diff.ops().iter().map(|op| op.path().to_string()).collect();
+        assert_eq!(paths, [".position.1", ".state.speed"]);
+        assert!(diff
+            .ops()
+            .iter()
+            .all(|op| matches!(op, DiffOp::Replace { .. })));
+    }
+
+    #[test]
+    fn should_replace_changed_variants() {
+        let old = character();
+        let mut new = character();
+        new.state = State::Carrying(Item { id: 7, count: 1 });
+        let diff = assert_round_trip(&old, &new);
+        assert_eq!(diff.ops().len(), 1);
+        assert_eq!(diff.ops()[0].path().to_string(), ".state");
+
+        let diff = assert_round_trip(&new, &old);
+        assert_eq!(diff.ops().len(), 1);
+
+        new.state = State::Idle;
+        assert_round_trip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_list_insertions_and_removals() {
+        let old = character();
+        let mut new = character();
+        new.items.remove(1);
+        new.items.insert(2, Item { id: 9, count: 5 });
+        new.items.push(Item { id: 10, count: 1 });
+        let diff = assert_round_trip(&old, &new);
+
+        assert!(matches!(diff.ops()[0], DiffOp::ListRemove { index: 1, .. }));
+        assert!(matches!(diff.ops()[1], DiffOp::ListInsert { index: 2, .. }));
+        assert!(matches!(diff.ops()[2], DiffOp::ListInsert { index: 4, .. }));
+        assert_eq!(diff.ops().len(), 3);
+
+        assert_round_trip(&new, &old);
+    }
+
+    #[test]
+    fn should_diff_changed_list_elements() {
+        let old = character();
+        let mut new = character();
+        new.items[2].count = 4;
+        new.items.truncate(3);
+        let diff = assert_round_trip(&old, &new);
+        let paths: Vec<_> = diff.ops().iter().map(|op| op.path().to_string()).collect();
+        assert_eq!(paths, [".items[2].count", ".items"]);
+
+        let mut new = character();
+        new.items.clear();
+        assert_round_trip(&old, &new);
+        assert_round_trip(&new, &old);
+    }
+
+    #[test]
+    fn should_diff_map_entries() {
+        let old = character();
+        let mut new = character();
+        new.stats.remove("speed");
+        new.stats.insert("strength".to_string(), 5);
+        new.stats.insert("luck".to_string(), 1);
+        let diff = assert_round_trip(&old, &new);
+        assert_eq!(diff.ops().len(), 3);
+        assert!(diff
+            .ops()
+            .iter()
+            .any(|op| matches!(op, DiffOp::MapRemove { .. })));
+    }
+
+    #[test]
+    fn should_apply_to_other_values() {
+        let old = character();
+        let mut new = character();
+        new.name = "Corro".to_string();
+        new.items.remove(0);
+        let diff = ReflectDiff::new(&old, &new);
+
+        let mut other = character();
+        other.position = (5.0, 5.0);
+        diff.apply(&mut other).unwrap();
+        assert_eq!(other.name, "Corro");
+        assert_eq!(other.items, new.items);
+        assert_eq!(other.position, (5.0, 5.0));
+
+        // Diffs can also be computed against dynamic values.
+        let dynamic = new.clone_value();
+        let diff = ReflectDiff::new(&old, &*dynamic);
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(Character::from_reflect(&*dynamic).unwrap(), value);
+    }
+
+    #[test]
+    fn should_replace_structs_with_different_fields() {
+        let mut old = DynamicStruct::default();
+        old.insert("a", 1_u32);
+        let mut new = DynamicStruct::default();
+        new.insert("b", 2_u32);
+        let diff = ReflectDiff::new(&old, &new);
+        assert!(matches!(&diff.ops()[0], DiffOp::Replace { path, .. } if path.0.is_empty()));
+    }
+
+    #[test]
+    fn should_fail_on_incompatible_values() {
+        let old = character();
+        let mut new = character();
+        new.items.clear();
+        let diff = ReflectDiff::new(&old, &new);
+
+        // All 4 items are removed from the front, but there are only 2.
+        let mut other = character();
+        other.items.truncate(2);
+        let error = diff.apply(&mut other).unwrap_err();
+        assert!(matches!(
+            error,
+            DiffError::IndexOutOfBounds {
+                index: 0,
+                len: 0,
+                ..
+            }
+        ));
+
+        let mut item = Item { id: 0, count: 0 };
+        let error = diff.apply(&mut item).unwrap_err();
+        assert!(matches!(error, DiffError::InvalidPath { .. }));
+    }
+}
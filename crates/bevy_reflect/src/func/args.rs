@@ -0,0 +1,172 @@
+use crate::func::{FunctionError, Ownership};
+use crate::{FromReflect, Reflect, TypePath};
+
+/// An argument passed to a [`DynamicFunction`](crate::func::DynamicFunction).
+#[derive(Debug)]
+pub enum Arg<'a> {
+    /// An owned value.
+    Owned(Box<dyn Reflect>),
+    /// A shared reference to a value.
+    Ref(&'a dyn Reflect),
+    /// A mutable reference to a value.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// How the value of this argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Arg::Owned(_) => Ownership::Owned,
+            Arg::Ref(_) => Ownership::Ref,
+            Arg::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// A reference to the value of this argument.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Arg::Owned(value) => value.as_ref(),
+            Arg::Ref(value) => *value,
+            Arg::Mut(value) => &**value,
+        }
+    }
+
+    /// Converts this argument into an owned `T`, at position `index` in the argument list.
+    ///
+    /// References are converted with [`FromReflect`], which clones the value.
+    pub(crate) fn take_owned<T: FromReflect + TypePath>(
+        self,
+        index: usize,
+    ) -> Result<T, FunctionError> {
+        let from_reflect = |value: &dyn Reflect| {
+            T::from_reflect(value).ok_or_else(|| FunctionError::InvalidArgType {
+                index,
+                expected: T::type_path().into(),
+                received: value.reflect_type_path().to_string().into(),
+            })
+        };
+        match self {
+            Arg::Owned(value) => value.take::<T>().or_else(|value| from_reflect(&*value)),
+            Arg::Ref(value) => from_reflect(value),
+            Arg::Mut(value) => from_reflect(value),
+        }
+    }
+
+    /// Borrows this argument as a `&T`, at position `index` in the argument list.
+    pub(crate) fn as_ref<T: Reflect + TypePath>(&self, index: usize) -> Result<&T, FunctionError> {
+        let value = self.value();
+        value
+            .downcast_ref::<T>()
+            .ok_or_else(|| FunctionError::InvalidArgType {
+                index,
+                expected: T::type_path().into(),
+                received: value.reflect_type_path().to_string().into(),
+            })
+    }
+
+    /// Borrows this argument as a `&mut T`, at position `index` in the argument list.
+    ///
+    /// Owned values can be borrowed mutably, but shared references can't.
+    pub(crate) fn as_mut<T: Reflect + TypePath>(
+        &mut self,
+        index: usize,
+    ) -> Result<&mut T, FunctionError> {
+        let value: &mut dyn Reflect = match self {
+            Arg::Owned(value) => value.as_mut(),
+            Arg::Mut(value) => &mut **value,
+            Arg::Ref(_) => {
+                return Err(FunctionError::InvalidOwnership {
+                    index,
+                    expected: Ownership::Mut,
+                    received: Ownership::Ref,
+                })
+            }
+        };
+        let received = value.reflect_type_path().to_string();
+        value
+            .downcast_mut::<T>()
+            .ok_or_else(|| FunctionError::InvalidArgType {
+                index,
+                expected: T::type_path().into(),
+                received: received.into(),
+            })
+    }
+}
+
+/// The list of arguments passed to a [`DynamicFunction`](crate::func::DynamicFunction).
+///
+/// ```
+/// # use bevy_reflect::func::ArgList;
+/// let mut value = 123_u32;
+/// let args = ArgList::new()
+///     .push_owned(String::from("owned"))
+///     .push_mut(&mut value);
+/// assert_eq!(args.len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct ArgList<'a>(Vec<Arg<'a>>);
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty list of arguments.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Appends an [`Arg`] to the list.
+    pub fn push_arg(mut self, arg: Arg<'a>) -> Self {
+        self.0.push(arg);
+        self
+    }
+
+    /// Appends an owned value to the list.
+    pub fn push_owned<T: Reflect>(self, value: T) -> Self {
+        self.push_arg(Arg::Owned(Box::new(value)))
+    }
+
+    /// Appends a boxed value to the list.
+    pub fn push_boxed(self, value: Box<dyn Reflect>) -> Self {
+        self.push_arg(Arg::Owned(value))
+    }
+
+    /// Appends a shared reference to the list.
+    pub fn push_ref(self, value: &'a dyn Reflect) -> Self {
+        self.push_arg(Arg::Ref(value))
+    }
+
+    /// Appends a mutable reference to the list.
+    pub fn push_mut(self, value: &'a mut dyn Reflect) -> Self {
+        self.push_arg(Arg::Mut(value))
+    }
+
+    /// The number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list has no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the arguments of the list.
+    pub fn iter(&self) -> impl Iterator<Item = &Arg<'a>> {
+        self.0.iter()
+    }
+
+    /// Takes the arguments out of the list.
+    pub fn take(self) -> Vec<Arg<'a>> {
+        self.0
+    }
+}
+
+impl From<Vec<Box<dyn Reflect>>> for ArgList<'static> {
+    fn from(values: Vec<Box<dyn Reflect>>) -> Self {
+        Self(values.into_iter().map(Arg::Owned).collect())
+    }
+}
+
+impl<'a> FromIterator<Arg<'a>> for ArgList<'a> {
+    fn from_iter<I: IntoIterator<Item = Arg<'a>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::func::Ownership;
+use std::borrow::Cow;
+use thiserror::Error;
+
+/// An error returned when calling a [`DynamicFunction`](crate::func::DynamicFunction) fails.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FunctionError {
+    /// The function was called with the wrong number of arguments.
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount {
+        /// The number of arguments of the function.
+        expected: usize,
+        /// The number of arguments it was called with.
+        received: usize,
+    },
+    /// An argument couldn't be converted to the type the function expects.
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidArgType {
+        /// The position of the argument.
+        index: usize,
+        /// The type path of the type the function expects.
+        expected: Cow<'static, str>,
+        /// The type path of the value that was passed.
+        received: Cow<'static, str>,
+    },
+    /// An argument was passed with an ownership the function can't accept.
+    #[error("expected argument {index} to be passed as {expected:?} but received {received:?}")]
+    InvalidOwnership {
+        /// The position of the argument.
+        index: usize,
+        /// The ownership the function expects.
+        expected: Ownership,
+        /// The ownership of the argument that was passed.
+        received: Ownership,
+    },
+}
@@ -0,0 +1,98 @@
+use crate::func::{ArgList, FunctionError, FunctionInfo};
+use crate::Reflect;
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The result of calling a [`DynamicFunction`].
+pub type FunctionResult = Result<Box<dyn Reflect>, FunctionError>;
+
+/// A function or closure that can be called with reflected arguments.
+///
+/// This is usually created with [`IntoFunction::into_function`](crate::func::IntoFunction),
+/// but can also be built from a closure taking an [`ArgList`] directly, with
+/// [`DynamicFunction::new`].
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// #[derive(bevy_reflect::Reflect, Default)]
+/// struct Player {
+///     health: f32,
+/// }
+///
+/// impl Player {
+///     fn heal(&mut self, amount: f32) -> f32 {
+///         self.health += amount;
+///         self.health
+///     }
+/// }
+///
+/// let heal = Player::heal
+///     .into_function()
+///     .with_arg_names(["self", "amount"]);
+///
+/// let mut player = Player { health: 10.0 };
+/// let args = ArgList::new().push_mut(&mut player).push_owned(5.0_f32);
+/// let health = heal.call(args).unwrap();
+/// assert_eq!(health.downcast_ref::<f32>(), Some(&15.0));
+/// assert_eq!(player.health, 15.0);
+/// ```
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync>,
+}
+
+impl DynamicFunction {
+    /// Creates a function calling `func` with the [`ArgList`] it is called with.
+    ///
+    /// `info` should describe the arguments that `func` expects.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync + 'static,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Sets the names of the arguments of the function, in order.
+    pub fn with_arg_names(mut self, names: impl IntoIterator<Item = &'static str>) -> Self {
+        for (arg, name) in self.info.args_mut().iter_mut().zip(names) {
+            *arg = arg.clone().with_name(name);
+        }
+        self
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call(&self, args: ArgList) -> FunctionResult {
+        (self.func)(args)
+    }
+
+    /// The signature of the function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// The name of the function, if it has one.
+    ///
+    /// See [`FunctionInfo::name`].
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicFunction")
+            .field("info", &self.info)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,164 @@
+use crate::TypePath;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+
+/// How an argument is passed to a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// The argument is passed by value.
+    Owned,
+    /// The argument is passed as a shared reference.
+    Ref,
+    /// The argument is passed as a mutable reference.
+    Mut,
+}
+
+/// Type information for an argument of a [`DynamicFunction`](crate::func::DynamicFunction).
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    ownership: Ownership,
+    type_path: &'static str,
+    type_id: TypeId,
+}
+
+impl ArgInfo {
+    /// Creates the info of the argument at `index`, of type `T` passed with `ownership`.
+    pub fn new<T: TypePath + Any>(index: usize, ownership: Ownership) -> Self {
+        Self {
+            index,
+            name: None,
+            ownership,
+            type_path: T::type_path(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// Sets the name of the argument.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The position of the argument in the argument list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the argument, if it was given one.
+    ///
+    /// Argument names can't be retrieved from Rust functions, so they are only known
+    /// when set with [`DynamicFunction::with_arg_names`](crate::func::DynamicFunction::with_arg_names).
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// How the argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// The [type path] of the argument, without the reference if it is passed by reference.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of the argument, without the reference if it is passed by reference.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+}
+
+/// Type information for the return value of a [`DynamicFunction`](crate::func::DynamicFunction).
+#[derive(Debug, Clone)]
+pub struct ReturnInfo {
+    type_path: &'static str,
+    type_id: TypeId,
+}
+
+impl ReturnInfo {
+    /// Creates the info of a return value of type `T`.
+    pub fn new<T: TypePath + Any>() -> Self {
+        Self {
+            type_path: T::type_path(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// The [type path] of the return value.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of the return value.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+}
+
+impl Default for ReturnInfo {
+    fn default() -> Self {
+        Self::new::<()>()
+    }
+}
+
+/// The signature of a [`DynamicFunction`](crate::func::DynamicFunction).
+#[derive(Debug, Clone, Default)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Vec<ArgInfo>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Creates the info of a function without arguments, returning `()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the arguments of the function.
+    pub fn with_args(mut self, args: Vec<ArgInfo>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Sets the return value of the function.
+    pub fn with_return_info(mut self, return_info: ReturnInfo) -> Self {
+        self.return_info = return_info;
+        self
+    }
+
+    /// The name of the function.
+    ///
+    /// Functions converted with [`IntoFunction`](crate::func::IntoFunction) are named after their
+    /// full path, like `my_crate::my_module::my_function`, while closures have no name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The arguments of the function.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// Mutable access to the arguments of the function.
+    pub(crate) fn args_mut(&mut self) -> &mut [ArgInfo] {
+        &mut self.args
+    }
+
+    /// The return value of the function.
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
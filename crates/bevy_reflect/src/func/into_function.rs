@@ -0,0 +1,159 @@
+use crate::func::{
+    Arg, ArgInfo, ArgList, DynamicFunction, FunctionError, FunctionInfo, Ownership, ReturnInfo,
+};
+use crate::{FromReflect, Reflect, TypePath};
+use bevy_utils::all_tuples;
+
+/// A trait for types that can be converted into a [`DynamicFunction`].
+///
+/// This is implemented for functions and closures taking up to 12 arguments that implement
+/// [`FromReflect`] and [`TypePath`], and returning a value that implements [`Reflect`] and
+/// [`TypePath`]. The first argument can also be a `&T` or `&mut T` receiver, such as the `self`
+/// of a method, where `T` implements [`Reflect`] and [`TypePath`].
+///
+/// Arguments passed by value can be given as owned values or as references, in which case they
+/// are cloned with [`FromReflect`].
+///
+/// The `Marker` type parameter is used to tell apart the implementations for the different
+/// signatures, and can be ignored.
+pub trait IntoFunction<Marker> {
+    /// Converts this function into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+/// Marker for functions taking all their arguments by value, see [`IntoFunction`].
+#[doc(hidden)]
+pub struct FunctionMarker;
+
+/// Marker for functions taking a `&T` receiver, see [`IntoFunction`].
+#[doc(hidden)]
+pub struct MethodMarker;
+
+/// Marker for functions taking a `&mut T` receiver, see [`IntoFunction`].
+#[doc(hidden)]
+pub struct MutMethodMarker;
+
+impl IntoFunction<()> for DynamicFunction {
+    fn into_function(self) -> DynamicFunction {
+        self
+    }
+}
+
+/// Builds the [`FunctionInfo`] of the function `F`, named after its path unless it is a closure.
+fn function_info<F, R: TypePath>(args: Vec<ArgInfo>) -> FunctionInfo {
+    let info = FunctionInfo::new()
+        .with_args(args)
+        .with_return_info(ReturnInfo::new::<R>());
+    let name = std::any::type_name::<F>();
+    if name.contains("{{closure}}") {
+        info
+    } else {
+        info.with_name(name)
+    }
+}
+
+/// Takes the arguments out of `args`, checking that there are `expected` of them.
+fn take_args(args: ArgList, expected: usize) -> Result<Vec<Arg>, FunctionError> {
+    if args.len() == expected {
+        Ok(args.take())
+    } else {
+        Err(FunctionError::ArgCount {
+            expected,
+            received: args.len(),
+        })
+    }
+}
+
+macro_rules! impl_into_function {
+    ($($arg: ident),*) => {
+        impl<F, R, $($arg),*> IntoFunction<(FunctionMarker, fn($($arg),*) -> R)> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: Reflect + TypePath,
+            $($arg: FromReflect + TypePath,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 0;
+                let args = vec![$({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let arg_count = args.len();
+                DynamicFunction::new(
+                    move |args| {
+                        let mut args = take_args(args, arg_count)?.into_iter().enumerate();
+                        $(
+                            let (index, arg) = args.next().unwrap();
+                            let $arg = arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new(self($($arg),*)))
+                    },
+                    function_info::<F, R>(args),
+                )
+            }
+        }
+
+        impl<F, Receiver, R, $($arg),*> IntoFunction<(MethodMarker, Receiver, fn($($arg),*) -> R)> for F
+        where
+            F: Fn(&Receiver, $($arg),*) -> R + Send + Sync + 'static,
+            Receiver: Reflect + TypePath,
+            R: Reflect + TypePath,
+            $($arg: FromReflect + TypePath,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 1;
+                let args = vec![ArgInfo::new::<Receiver>(0, Ownership::Ref), $({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let arg_count = args.len();
+                DynamicFunction::new(
+                    move |args| {
+                        let mut args = take_args(args, arg_count)?.into_iter().enumerate();
+                        let (_, receiver) = args.next().unwrap();
+                        $(
+                            let (index, arg) = args.next().unwrap();
+                            let $arg = arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new(self(receiver.as_ref::<Receiver>(0)?, $($arg),*)))
+                    },
+                    function_info::<F, R>(args),
+                )
+            }
+        }
+
+        impl<F, Receiver, R, $($arg),*> IntoFunction<(MutMethodMarker, Receiver, fn($($arg),*) -> R)> for F
+        where
+            F: Fn(&mut Receiver, $($arg),*) -> R + Send + Sync + 'static,
+            Receiver: Reflect + TypePath,
+            R: Reflect + TypePath,
+            $($arg: FromReflect + TypePath,)*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 1;
+                let args = vec![ArgInfo::new::<Receiver>(0, Ownership::Mut), $({
+                    index += 1;
+                    ArgInfo::new::<$arg>(index - 1, Ownership::Owned)
+                }),*];
+                let arg_count = args.len();
+                DynamicFunction::new(
+                    move |args| {
+                        let mut args = take_args(args, arg_count)?.into_iter().enumerate();
+                        let (_, mut receiver) = args.next().unwrap();
+                        $(
+                            let (index, arg) = args.next().unwrap();
+                            let $arg = arg.take_owned::<$arg>(index)?;
+                        )*
+                        Ok(Box::new(self(receiver.as_mut::<Receiver>(0)?, $($arg),*)))
+                    },
+                    function_info::<F, R>(args),
+                )
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_function, 0, 12, P);
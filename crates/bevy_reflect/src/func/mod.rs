@@ -0,0 +1,190 @@
+//! Reflection for functions and closures.
+//!
+//! Any function or closure whose arguments implement [`FromReflect`] and [`TypePath`], and
+//! whose return value implements [`Reflect`] and [`TypePath`], can be converted into a
+//! [`DynamicFunction`] with [`IntoFunction::into_function`].
+//! A [`DynamicFunction`] can then be called with an [`ArgList`] of reflected values, without
+//! knowing the concrete types of the arguments at compile time.
+//!
+//! Methods taking `&self` or `&mut self` are supported as well: their receiver must then be
+//! passed by reference, with [`ArgList::push_ref`] or [`ArgList::push_mut`].
+//!
+//! ```
+//! # use bevy_reflect::func::{ArgList, IntoFunction};
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let function = add.into_function();
+//! assert_eq!(function.info().args().len(), 2);
+//!
+//! let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+//! let result = function.call(args).unwrap();
+//! assert_eq!(result.downcast_ref::<i32>(), Some(&100));
+//! ```
+//!
+//! Functions can be registered by name in a [`FunctionRegistry`], to be looked up and called
+//! from scripting languages or consoles.
+//!
+//! [`FromReflect`]: crate::FromReflect
+//! [`TypePath`]: crate::TypePath
+//! [`Reflect`]: crate::Reflect
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+mod registry;
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+pub use registry::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypePath};
+
+    #[derive(Reflect, Debug, PartialEq, Default)]
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        fn get(&self) -> u32 {
+            self.count
+        }
+
+        fn increment(&mut self, amount: u32) {
+            self.count += amount;
+        }
+    }
+
+    fn concat(a: String, b: String) -> String {
+        a + &b
+    }
+
+    #[test]
+    fn should_call_function_with_owned_args() {
+        let function = concat.into_function();
+        let args = ArgList::new()
+            .push_owned(String::from("Hello, "))
+            .push_owned(String::from("world!"));
+        let result = function.call(args).unwrap();
+        assert_eq!(
+            result.downcast_ref::<String>().map(String::as_str),
+            Some("Hello, world!")
+        );
+    }
+
+    #[test]
+    fn should_clone_args_passed_by_reference() {
+        let function = concat.into_function();
+        let a = String::from("a");
+        let args = ArgList::new().push_ref(&a).push_owned(String::from("b"));
+        let result = function.call(args).unwrap();
+        assert_eq!(
+            result.downcast_ref::<String>().map(String::as_str),
+            Some("ab")
+        );
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let offset = 10;
+        let function = (move |value: i32| value + offset).into_function();
+        assert_eq!(function.name(), None);
+        let result = function.call(vec![Box::new(5_i32) as _].into()).unwrap();
+        assert_eq!(result.downcast_ref::<i32>(), Some(&15));
+    }
+
+    #[test]
+    fn should_call_methods() {
+        let mut counter = Counter { count: 1 };
+
+        let increment = Counter::increment.into_function();
+        let result = increment
+            .call(ArgList::new().push_mut(&mut counter).push_owned(2_u32))
+            .unwrap();
+        assert!(result.is::<()>());
+        assert_eq!(counter.count, 3);
+
+        let get = Counter::get.into_function();
+        let result = get.call(ArgList::new().push_ref(&counter)).unwrap();
+        assert_eq!(result.downcast_ref::<u32>(), Some(&3));
+    }
+
+    #[test]
+    fn should_describe_signature() {
+        let function = Counter::increment
+            .into_function()
+            .with_arg_names(["self", "amount"]);
+        let info = function.info();
+        assert!(info.name().unwrap().ends_with("Counter::increment"));
+        assert_eq!(info.args().len(), 2);
+        assert_eq!(info.args()[0].ownership(), Ownership::Mut);
+        assert_eq!(info.args()[0].type_path(), Counter::type_path());
+        assert_eq!(info.args()[1].name(), Some("amount"));
+        assert_eq!(info.args()[1].type_path(), "u32");
+        assert_eq!(info.return_info().type_path(), "()");
+    }
+
+    #[test]
+    fn should_error_on_invalid_args() {
+        let function = concat.into_function();
+        assert_eq!(
+            function
+                .call(ArgList::new().push_owned(String::new()))
+                .unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 2,
+                received: 1
+            }
+        );
+        assert_eq!(
+            function
+                .call(ArgList::new().push_owned(String::new()).push_owned(1_u8))
+                .unwrap_err(),
+            FunctionError::InvalidArgType {
+                index: 1,
+                expected: "alloc::string::String".into(),
+                received: "u8".into(),
+            }
+        );
+
+        let counter = Counter::default();
+        let increment = Counter::increment.into_function();
+        assert_eq!(
+            increment
+                .call(ArgList::new().push_ref(&counter).push_owned(1_u32))
+                .unwrap_err(),
+            FunctionError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Mut,
+                received: Ownership::Ref,
+            }
+        );
+    }
+
+    #[test]
+    fn should_call_registered_functions_by_name() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register("concat", concat)
+            .register("get", Counter::get);
+        assert_eq!(registry.get("concat").unwrap().name(), Some("concat"));
+
+        let counter = Counter { count: 7 };
+        let result = registry
+            .call("get", ArgList::new().push_ref(&counter))
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.downcast_ref::<u32>(), Some(&7));
+        assert!(registry.call("missing", ArgList::new()).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::func::{ArgList, DynamicFunction, FunctionResult, IntoFunction};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A registry of [`DynamicFunction`]s, looked up by name.
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, FunctionRegistry};
+/// fn damage(health: f32, amount: f32) -> f32 {
+///     (health - amount).max(0.0)
+/// }
+///
+/// let mut registry = FunctionRegistry::default();
+/// registry.register("damage", damage);
+///
+/// let args = ArgList::new().push_owned(10.0_f32).push_owned(4.0_f32);
+/// let health = registry.call("damage", args).unwrap().unwrap();
+/// assert_eq!(health.downcast_ref::<f32>(), Some(&6.0));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FunctionRegistry {
+    functions: HashMap<Cow<'static, str>, DynamicFunction>,
+}
+
+impl FunctionRegistry {
+    /// Registers `function` under `name`, replacing any function previously registered with
+    /// this name.
+    ///
+    /// The registered [`DynamicFunction`] is given the name it is registered with.
+    pub fn register<Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: impl IntoFunction<Marker>,
+    ) -> &mut Self {
+        let name = name.into();
+        let function = function.into_function().with_name(name.clone());
+        self.functions.insert(name, function);
+        self
+    }
+
+    /// Removes the function registered under `name`, returning it if there was one.
+    pub fn unregister(&mut self, name: &str) -> Option<DynamicFunction> {
+        self.functions.remove(name)
+    }
+
+    /// Returns the function registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.functions.get(name)
+    }
+
+    /// Returns `true` if a function is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Calls the function registered under `name` with `args`.
+    ///
+    /// Returns `None` if no function is registered under this name.
+    pub fn call(&self, name: &str, args: ArgList) -> Option<FunctionResult> {
+        self.get(name).map(|function| function.call(args))
+    }
+
+    /// Returns an iterator over the registered functions, with their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DynamicFunction)> {
+        self.functions
+            .iter()
+            .map(|(name, function)| (name.as_ref(), function))
+    }
+
+    /// The number of registered functions.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns `true` if no function is registered.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
+
+/// A synchronized wrapper around a [`FunctionRegistry`].
+#[derive(Clone, Default, Debug)]
+pub struct FunctionRegistryArc {
+    pub internal: Arc<RwLock<FunctionRegistry>>,
+}
+
+impl FunctionRegistryArc {
+    /// Takes a read lock on the underlying [`FunctionRegistry`].
+    pub fn read(&self) -> RwLockReadGuard<'_, FunctionRegistry> {
+        self.internal.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Takes a write lock on the underlying [`FunctionRegistry`].
+    pub fn write(&self) -> RwLockWriteGuard<'_, FunctionRegistry> {
+        self.internal
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -1,23 +1,25 @@
 use crate::std_traits::ReflectDefault;
 use crate::{self as bevy_reflect, ReflectFromPtr, ReflectFromReflect, ReflectOwned};
 use crate::{
-    impl_type_path, map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum,
-    DynamicMap, Enum, EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
-    ListIter, Map, MapInfo, MapIter, Reflect, ReflectDeserialize, ReflectKind, ReflectMut,
-    ReflectRef, ReflectSerialize, TupleVariantInfo, TypeInfo, TypePath, TypeRegistration, Typed,
-    UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter, VariantInfo, VariantType,
+    impl_type_path, map_apply, map_partial_eq, set_apply, set_partial_eq, Array, ArrayInfo,
+    ArrayIter, DynamicEnum, DynamicMap, DynamicSet, Enum, EnumInfo, FromReflect, FromType,
+    GetTypeRegistration, List, ListInfo, ListIter, Map, MapInfo, MapIter, Reflect,
+    ReflectDeserialize, ReflectKind, ReflectMut, ReflectRef, ReflectSerialize, Set, SetInfo,
+    TupleVariantInfo, TypeInfo, TypePath, TypeRegistration, Typed, UnitVariantInfo, UnnamedField,
+    ValueInfo, VariantFieldIter, VariantInfo, VariantType,
 };
 
 use crate::utility::{
     reflect_hasher, GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell,
 };
-use bevy_reflect_derive::impl_reflect_value;
+use bevy_reflect_derive::{impl_reflect, impl_reflect_value};
 use std::fmt;
 use std::{
     any::Any,
     borrow::Cow,
-    collections::VecDeque,
+    collections::{BTreeMap, VecDeque},
     hash::{BuildHasher, Hash, Hasher},
+    ops::{Range, RangeFrom, RangeTo, RangeToInclusive},
     path::Path,
 };
 
@@ -101,13 +103,37 @@ impl_reflect_value!(
     ::core::result::Result < T: Clone + Reflect + TypePath,
     E: Clone + Reflect + TypePath > ()
 );
-impl_reflect_value!(::bevy_utils::HashSet<T: Hash + Eq + Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::Range<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::ops::RangeInclusive<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeFrom<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeTo<T: Clone + Send + Sync>());
-impl_reflect_value!(::core::ops::RangeToInclusive<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::ops::RangeFull());
+
+impl_reflect!(
+    #[type_path = "core::ops"]
+    struct Range<T> {
+        start: T,
+        end: T,
+    }
+);
+
+impl_reflect!(
+    #[type_path = "core::ops"]
+    struct RangeFrom<T> {
+        start: T,
+    }
+);
+
+impl_reflect!(
+    #[type_path = "core::ops"]
+    struct RangeTo<T> {
+        end: T,
+    }
+);
+
+impl_reflect!(
+    #[type_path = "core::ops"]
+    struct RangeToInclusive<T> {
+        end: T,
+    }
+);
 impl_reflect_value!(::bevy_utils::Duration(
     Debug,
     Hash,
@@ -204,6 +230,7 @@ impl_reflect_value!(::core::num::NonZeroI8(
 impl_reflect_value!(::core::num::Wrapping<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::num::Saturating<T: Clone + Send + Sync>());
 impl_reflect_value!(::std::sync::Arc<T: Send + Sync>);
+impl_reflect_value!(::alloc::boxed::Box<T: Clone + Send + Sync>);
 
 // `Serialize` and `Deserialize` only for platforms supported by serde:
 // https://github.com/serde-rs/serde/blob/3ffb86fc70efd3d329519e2dddfa306cc04f167c/serde/src/de/impls.rs#L1732
@@ -620,6 +647,420 @@ impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
 impl_type_path!(::bevy_utils::hashbrown::hash_map::DefaultHashBuilder);
 impl_type_path!(::bevy_utils::hashbrown::HashMap<K, V, S>);
 
+impl<K, V> Map for BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + Ord + Hash,
+    V: FromReflect + TypePath,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(|key| Self::get(self, key))
+            .map(|value| value as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(move |key| Self::get_mut(self, key))
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.iter()
+            .nth(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+        self.iter_mut()
+            .nth(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
+    }
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn iter(&self) -> MapIter {
+        MapIter::new(self)
+    }
+
+    fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        self.into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value) as Box<dyn Reflect>,
+                )
+            })
+            .collect()
+    }
+
+    fn clone_dynamic(&self) -> DynamicMap {
+        let mut dynamic_map = DynamicMap::default();
+        dynamic_map.set_represented_type(self.get_represented_type_info());
+        for (k, v) in self {
+            let key = K::from_reflect(k).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to clone invalid key of type {}.",
+                    k.reflect_type_path()
+                )
+            });
+            dynamic_map.insert_boxed(Box::new(key), v.clone_value());
+        }
+        dynamic_map
+    }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
+            panic!(
+                "Attempted to insert invalid key of type {}.",
+                key.reflect_type_path()
+            )
+        });
+        let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+            panic!(
+                "Attempted to insert invalid value of type {}.",
+                value.reflect_type_path()
+            )
+        });
+        self.insert(key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let mut from_reflect = None;
+        key.downcast_ref::<K>()
+            .or_else(|| {
+                from_reflect = K::from_reflect(key);
+                from_reflect.as_ref()
+            })
+            .and_then(|key| self.remove(key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+}
+
+impl<K, V> Reflect for BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + Ord + Hash,
+    V: FromReflect + TypePath,
+{
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        map_apply(self, value);
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Map
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Map(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        map_partial_eq(self, value)
+    }
+}
+
+impl<K, V> Typed for BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + Ord + Hash,
+    V: FromReflect + TypePath,
+{
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+    }
+}
+
+impl<K, V> GetTypeRegistration for BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + Ord + Hash,
+    V: FromReflect + TypePath,
+{
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<K, V> FromReflect for BTreeMap<K, V>
+where
+    K: FromReflect + TypePath + Ord + Hash,
+    V: FromReflect + TypePath,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::Map(ref_map) = reflect.reflect_ref() {
+            let mut new_map = Self::new();
+            for (key, value) in ref_map.iter() {
+                let new_key = K::from_reflect(key)?;
+                let new_value = V::from_reflect(value)?;
+                new_map.insert(new_key, new_value);
+            }
+            Some(new_map)
+        } else {
+            None
+        }
+    }
+}
+
+impl_type_path!(::alloc::collections::BTreeMap<K, V>);
+
+macro_rules! impl_reflect_for_set {
+    ($ty:path, [$($value_bound:tt)*], [$($bound:tt)*], [$($from_bound:tt)*], $new:expr, $ordered:expr) => {
+        impl<V, $($bound)*> Set for $ty
+        where
+            V: FromReflect + TypePath + $($value_bound)*,
+        {
+            fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+                value
+                    .downcast_ref::<V>()
+                    .and_then(|value| Self::get(self, value))
+                    .map(|value| value as &dyn Reflect)
+            }
+
+            fn len(&self) -> usize {
+                Self::len(self)
+            }
+
+            fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+                Box::new(Self::iter(self).map(|value| value as &dyn Reflect))
+            }
+
+            fn is_ordered(&self) -> bool {
+                $ordered
+            }
+
+            fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+                self.into_iter()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+
+            fn clone_dynamic(&self) -> DynamicSet {
+                let mut dynamic_set = DynamicSet::default();
+                dynamic_set.set_represented_type(self.get_represented_type_info());
+                for value in self {
+                    let value = V::from_reflect(value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to clone invalid value of type {}.",
+                            value.reflect_type_path()
+                        )
+                    });
+                    dynamic_set.insert_boxed(Box::new(value));
+                }
+                dynamic_set
+            }
+
+            fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+                let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to insert invalid value of type {}.",
+                        value.reflect_type_path()
+                    )
+                });
+                self.insert(value)
+            }
+
+            fn remove(&mut self, value: &dyn Reflect) -> bool {
+                let mut from_reflect = None;
+                value
+                    .downcast_ref::<V>()
+                    .or_else(|| {
+                        from_reflect = V::from_reflect(value);
+                        from_reflect.as_ref()
+                    })
+                    .is_some_and(|value| self.remove(value))
+            }
+        }
+
+        impl<V, $($bound)*> Reflect for $ty
+        where
+            V: FromReflect + TypePath + $($value_bound)*,
+        {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn apply(&mut self, value: &dyn Reflect) {
+                set_apply(self, value);
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Set
+            }
+
+            fn reflect_ref(&self) -> ReflectRef {
+                ReflectRef::Set(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut {
+                ReflectMut::Set(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Set(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                Box::new(self.clone_dynamic())
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                set_partial_eq(self, value)
+            }
+        }
+
+        impl<V, $($bound)*> Typed for $ty
+        where
+            V: FromReflect + TypePath + $($value_bound)*,
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| TypeInfo::Set(SetInfo::new::<Self, V>()))
+            }
+        }
+
+        impl<V, $($bound)*> GetTypeRegistration for $ty
+        where
+            V: FromReflect + TypePath + $($value_bound)*,
+        {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<Self>();
+                registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration
+            }
+        }
+
+        impl<V, $($from_bound)*> FromReflect for $ty
+        where
+            V: FromReflect + TypePath + $($value_bound)*,
+        {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let ReflectRef::Set(ref_set) = reflect.reflect_ref() {
+                    let mut new_set: Self = $new(ref_set.len());
+                    for value in ref_set.iter() {
+                        new_set.insert(V::from_reflect(value)?);
+                    }
+                    Some(new_set)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_reflect_for_set!(
+    ::std::collections::HashSet<V, S>,
+    [Eq + Hash],
+    [S: TypePath + BuildHasher + Send + Sync],
+    [S: TypePath + BuildHasher + Default + Send + Sync],
+    |len| Self::with_capacity_and_hasher(len, S::default()),
+    false
+);
+impl_type_path!(::std::collections::HashSet<V, S>);
+
+impl_reflect_for_set!(
+    ::bevy_utils::hashbrown::HashSet<V, S>,
+    [Eq + Hash],
+    [S: TypePath + BuildHasher + Send + Sync],
+    [S: TypePath + BuildHasher + Default + Send + Sync],
+    |len| Self::with_capacity_and_hasher(len, S::default()),
+    false
+);
+impl_type_path!(::bevy_utils::hashbrown::HashSet<V, S>);
+
+impl_reflect_for_set!(
+    ::alloc::collections::BTreeSet<V>,
+    [Ord + Hash],
+    [],
+    [],
+    |_| Self::new(),
+    true
+);
+impl_type_path!(::alloc::collections::BTreeSet<V>);
+
 impl<T: Reflect + TypePath, const N: usize> Array for [T; N] {
     #[inline]
     fn get(&self, index: usize) -> Option<&dyn Reflect> {
@@ -1652,13 +2093,15 @@ impl GetTypeRegistration for Cow<'static, Path> {
 mod tests {
     use crate as bevy_reflect;
     use crate::{
-        Enum, FromReflect, Reflect, ReflectSerialize, TypeInfo, TypeRegistry, Typed, VariantInfo,
-        VariantType,
+        DynamicMap, DynamicSet, DynamicStruct, Enum, FromReflect, Map, Reflect, ReflectMut,
+        ReflectSerialize, Set, TypeInfo, TypeRegistry, Typed, VariantInfo, VariantType,
     };
     use bevy_utils::{Duration, Instant};
-    use bevy_utils::{EntityHashMap, HashMap};
+    use bevy_utils::{EntityHashMap, HashMap, HashSet};
     use static_assertions::assert_impl_all;
+    use std::collections::{BTreeMap, BTreeSet};
     use std::f32::consts::{PI, TAU};
+    use std::ops::{Range, RangeFrom, RangeTo, RangeToInclusive};
     use std::path::Path;
 
     // EntityHashMap should implement Reflect
@@ -1735,6 +2178,76 @@ mod tests {
         assert!(!a.reflect_partial_eq(c).unwrap_or_default());
     }
 
+    #[test]
+    fn should_partial_eq_hash_set() {
+        let a = HashSet::from_iter([1_u32, 2, 3]);
+        let b = HashSet::from_iter([3_u32, 2, 1]);
+        let c = HashSet::from_iter([1_u32, 2]);
+
+        let a: &dyn Reflect = &a;
+        let b: &dyn Reflect = &b;
+        let c: &dyn Reflect = &c;
+        assert!(a.reflect_partial_eq(b).unwrap_or_default());
+        assert!(!a.reflect_partial_eq(c).unwrap_or_default());
+    }
+
+    #[test]
+    fn set_should_apply_and_from_reflect() {
+        let mut set = BTreeSet::from_iter([1_u32, 2]);
+        let mut dynamic = DynamicSet::default();
+        dynamic.insert(3_u32);
+        set.apply(&dynamic);
+        assert_eq!(set, BTreeSet::from_iter([1, 2, 3]));
+
+        let set_value: &mut dyn Set = &mut set;
+        assert!(!set_value.insert_boxed(Box::new(2_u32)));
+        assert!(set_value.remove(&1_u32));
+        assert!(set_value.contains(&3_u32));
+
+        let output = HashSet::<u32>::from_reflect(&set).unwrap();
+        assert_eq!(output, HashSet::from_iter([2, 3]));
+        assert!(std::collections::HashSet::<u32>::from_reflect(&*set.clone_value()).is_some());
+    }
+
+    #[test]
+    fn btree_map_should_impl_map() {
+        let mut map = BTreeMap::from_iter([(2_u32, "b".to_string()), (1, "a".to_string())]);
+        let (key, value) = Map::get_at(&map, 0).unwrap();
+        assert_eq!(key.downcast_ref::<u32>(), Some(&1));
+        assert_eq!(value.downcast_ref::<String>(), Some(&"a".to_string()));
+
+        let mut dynamic = DynamicMap::default();
+        dynamic.insert(1_u32, "c".to_string());
+        map.apply(&dynamic);
+        assert_eq!(map[&1], "c");
+
+        let output = HashMap::<u32, String>::from_reflect(&map).unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(BTreeMap::from_reflect(&output), Some(map));
+    }
+
+    #[test]
+    fn ranges_should_impl_struct() {
+        let mut range = 1_u32..5;
+        let ReflectMut::Struct(value) = range.reflect_mut() else {
+            panic!("expected a struct");
+        };
+        *value
+            .field_mut("end")
+            .unwrap()
+            .downcast_mut::<u32>()
+            .unwrap() = 10;
+        assert_eq!(range, 1..10);
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("start", 3_u32);
+        dynamic.insert("end", 4_u32);
+        assert_eq!(Range::<u32>::from_reflect(&dynamic), Some(3..4));
+        assert_eq!(RangeFrom::<u32>::from_reflect(&dynamic), Some(3..));
+        assert_eq!(RangeTo::<u32>::from_reflect(&dynamic), Some(..4));
+        assert_eq!(RangeToInclusive::<u32>::from_reflect(&dynamic), Some(..=4));
+    }
+
     #[test]
     fn should_partial_eq_option() {
         let a: &dyn Reflect = &Some(123);
@@ -1881,4 +2394,11 @@ mod tests {
         let output = <&'static str as FromReflect>::from_reflect(&expected).unwrap();
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn box_should_from_reflect() {
+        let expected = Box::new(123_i32);
+        let output = <Box<i32> as FromReflect>::from_reflect(&expected).unwrap();
+        assert_eq!(expected, output);
+    }
 }
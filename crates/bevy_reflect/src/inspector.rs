@@ -0,0 +1,761 @@
+//! A UI-agnostic model of reflected values, for inspectors and editors.
+//!
+//! A [`PropertyTree`] describes a reflected value as a tree of [`Property`]s, one for the value
+//! itself and one for each of its fields and elements, recursively. Each property has a
+//! [`ParsedPath`] leading to it from the value, its [`PropertyAttributes`], and its current
+//! [`PropertyValue`]. Editors build their widgets from the tree, then apply the changes made by
+//! users with [`PropertyTree::apply`], which enforces the attributes of the edited property.
+//!
+//! Property attributes are read from the [custom attributes] of fields:
+//! - [`DisplayName`]: the name shown to users instead of the field name.
+//! - A [`RangeInclusive`] of any primitive number type, like `@0.0..=1.0`: the inclusive range of
+//!   the numeric values of the field.
+//! - [`Hidden`]: the field should not be shown to users.
+//! - [`ReadOnly`]: the field should not be edited by users.
+//!
+//! ```
+//! # use bevy_reflect::inspector::{DisplayName, PropertyEdit, PropertyTree, PropertyValue, ReadOnly};
+//! # use bevy_reflect::{ParsedPath, Reflect};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     #[reflect(@DisplayName("Health points"), @0.0..=100.0)]
+//!     health: f32,
+//!     #[reflect(@ReadOnly)]
+//!     id: u32,
+//! }
+//!
+//! let mut player = Player { health: 50.0, id: 7 };
+//! let tree = PropertyTree::new(&player);
+//!
+//! let health = ParsedPath::parse(".health").unwrap();
+//! let property = tree.get(&health).unwrap();
+//! assert_eq!(property.display_name(), "Health points");
+//! assert_eq!(property.value, PropertyValue::Float(50.0));
+//!
+//! tree.apply(&mut player, &health, PropertyEdit::Float(75.0)).unwrap();
+//! assert_eq!(player.health, 75.0);
+//! assert!(tree.apply(&mut player, &health, PropertyEdit::Float(150.0)).is_err());
+//!
+//! let id = ParsedPath::parse(".id").unwrap();
+//! assert!(tree.apply(&mut player, &id, PropertyEdit::Integer(8)).is_err());
+//! ```
+//!
+//! [custom attributes]: crate::CustomAttributes
+
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate as bevy_reflect;
+use crate::{
+    Access, CustomAttributes, DynamicEnum, DynamicVariant, ParsedPath, Reflect, ReflectMut,
+    ReflectPath, ReflectPathError, ReflectRef, TypeInfo, VariantInfo, VariantType,
+};
+
+/// A custom attribute giving the name of a field shown to users, if different from its own.
+///
+/// ```
+/// # use bevy_reflect::{inspector::DisplayName, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(@DisplayName("Health points"))]
+///     health: f32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayName(pub &'static str);
+
+/// A custom attribute marking a field that should not be shown to users.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hidden;
+
+/// A custom attribute marking a field that should not be edited by users.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReadOnly;
+
+/// Attributes describing how a field should be shown and edited.
+///
+/// These are read from the [custom attributes] of the [`NamedField`] or [`UnnamedField`] of
+/// the field, see the [module documentation].
+///
+/// [custom attributes]: CustomAttributes
+/// [module documentation]: self
+/// [`NamedField`]: crate::NamedField
+/// [`UnnamedField`]: crate::UnnamedField
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PropertyAttributes {
+    /// The name shown to users, if different from the field name.
+    pub display_name: Option<&'static str>,
+    /// The inclusive range of the numeric values the field should take.
+    pub range: Option<RangeInclusive<f64>>,
+    /// Whether the field should be hidden from users.
+    pub hidden: bool,
+    /// Whether the field should not be edited by users.
+    pub read_only: bool,
+}
+
+impl PropertyAttributes {
+    /// Reads the property attributes from the custom attributes of a field.
+    ///
+    /// The range is taken from the first [`RangeInclusive`] of a primitive number type found.
+    pub fn from_custom_attributes(attributes: &CustomAttributes) -> Self {
+        Self {
+            display_name: attributes.get::<DisplayName>().map(|name| name.0),
+            range: numeric_range(attributes),
+            hidden: attributes.contains::<Hidden>(),
+            read_only: attributes.contains::<ReadOnly>(),
+        }
+    }
+}
+
+fn numeric_range(attributes: &CustomAttributes) -> Option<RangeInclusive<f64>> {
+    macro_rules! range {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(range) = attributes.get::<RangeInclusive<$ty>>() {
+                    return Some(*range.start() as f64..=*range.end() as f64);
+                }
+            )*
+        };
+    }
+
+    range!(f64, f32, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    None
+}
+
+/// The current value of a [`Property`].
+///
+/// Scalar values are given in full, while other values only give a summary, their contents
+/// being the children of the property.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// A `bool`.
+    Bool(bool),
+    /// Any primitive integer type.
+    Integer(i128),
+    /// An `f32` or an `f64`.
+    Float(f64),
+    /// A `char`.
+    Char(char),
+    /// A `String`.
+    String(String),
+    /// An enum, with its current variant and all of its variants, if known.
+    Enum {
+        variant: String,
+        variants: Vec<&'static str>,
+    },
+    /// A struct or tuple struct.
+    Struct,
+    /// A tuple.
+    Tuple,
+    /// A list or array of `len` elements.
+    List { len: usize },
+    /// A map of `len` entries.
+    ///
+    /// Entries can't be reached with a [`ParsedPath`], so maps have no children.
+    Map { len: usize },
+    /// A set of `len` values.
+    ///
+    /// Values can't be modified in place, so the children of a set are read-only. Only the values
+    /// of ordered sets can be reached with a [`ParsedPath`], so unordered sets have no children,
+    /// see [`Set::is_ordered`](crate::Set::is_ordered).
+    Set { len: usize },
+    /// A value with no editable representation.
+    Opaque,
+}
+
+/// A change to the value of a [`Property`], applied with [`PropertyTree::apply`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyEdit {
+    /// Sets a `bool`.
+    Bool(bool),
+    /// Sets any primitive integer or float type.
+    Integer(i128),
+    /// Sets an `f32` or an `f64`.
+    Float(f64),
+    /// Sets a `char`.
+    Char(char),
+    /// Sets a `String`.
+    String(String),
+    /// Changes an enum to the unit variant with the given name.
+    Variant(String),
+}
+
+/// A reflected value, or one of its fields or elements, in a [`PropertyTree`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    /// The name of the field, or the index of the element, this property is.
+    ///
+    /// This is empty for the root of the tree.
+    pub name: String,
+    /// The path leading to this property from the root of the tree.
+    pub path: ParsedPath,
+    /// The type path of the value of this property.
+    pub type_path: String,
+    /// The attributes of this property.
+    ///
+    /// Properties are read-only if a property containing them is. Elements of lists and arrays,
+    /// and fields of enums, take the range of the property containing them if they have none.
+    pub attributes: PropertyAttributes,
+    /// The value of this property.
+    pub value: PropertyValue,
+    /// The fields or elements of the value of this property.
+    pub children: Vec<Property>,
+}
+
+impl Property {
+    /// The name to show to users: the display name of the property if it has one, or its name.
+    pub fn display_name(&self) -> &str {
+        self.attributes.display_name.unwrap_or(&self.name)
+    }
+
+    /// Returns `true` if the value of this property can be changed with a [`PropertyEdit`].
+    pub fn is_editable(&self) -> bool {
+        !self.attributes.read_only
+            && matches!(
+                self.value,
+                PropertyValue::Bool(_)
+                    | PropertyValue::Integer(_)
+                    | PropertyValue::Float(_)
+                    | PropertyValue::Char(_)
+                    | PropertyValue::String(_)
+                    | PropertyValue::Enum { .. }
+            )
+    }
+
+    /// Returns an iterator over this property and all of its descendants, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &Property> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let property = stack.pop()?;
+            stack.extend(property.children.iter().rev());
+            Some(property)
+        })
+    }
+}
+
+/// An error returned when applying a [`PropertyEdit`] to a value.
+#[derive(Debug, PartialEq, Error)]
+pub enum PropertyError<'a> {
+    /// No property of the tree has the given path.
+    #[error("no property at `{path}`")]
+    NotFound { path: &'a ParsedPath },
+    /// The property is read-only.
+    #[error("the property at `{path}` is read-only")]
+    ReadOnly { path: &'a ParsedPath },
+    /// The new value is out of the range of the property.
+    #[error("{value} is out of the range {range:?} of the property at `{path}`")]
+    OutOfRange {
+        path: &'a ParsedPath,
+        value: f64,
+        range: RangeInclusive<f64>,
+    },
+    /// The path of the property doesn't lead to an element of the value, which changed since the
+    /// tree was built.
+    #[error("cannot access `{path}`: {error}")]
+    InvalidPath {
+        path: &'a ParsedPath,
+        error: ReflectPathError<'a>,
+    },
+    /// The edit doesn't fit the type of the property, or the new value can't be represented by it.
+    #[error("cannot apply {edit:?} to the property at `{path}` of type `{type_path}`")]
+    IncompatibleEdit {
+        path: &'a ParsedPath,
+        type_path: String,
+        edit: PropertyEdit,
+    },
+}
+
+/// A tree of the [`Property`]s of a reflected value.
+///
+/// The tree is a snapshot of the value: it should be rebuilt when the value changes,
+/// including after applying edits to it.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyTree {
+    root: Property,
+}
+
+impl PropertyTree {
+    /// Builds the property tree of `value`.
+    pub fn new(value: &dyn Reflect) -> Self {
+        let root = build_property(
+            value,
+            String::new(),
+            Vec::new(),
+            PropertyAttributes::default(),
+        );
+        Self { root }
+    }
+
+    /// The property of the value itself.
+    pub fn root(&self) -> &Property {
+        &self.root
+    }
+
+    /// Returns the property at the given path, if any.
+    pub fn get(&self, path: &ParsedPath) -> Option<&Property> {
+        let mut property = &self.root;
+        for access in &path.0 {
+            let access = &access.access;
+            property = property.children.iter().find(|child| {
+                child
+                    .path
+                    .0
+                    .last()
+                    .is_some_and(|last| &last.access == access)
+            })?;
+        }
+        Some(property)
+    }
+
+    /// Returns an iterator over all the properties of the tree, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &Property> {
+        self.root.iter()
+    }
+
+    /// Applies `edit` to the element of `value` at `path`, after checking that the property at
+    /// `path` isn't read-only and that the new value is within its range.
+    ///
+    /// `value` should be the value the tree was built from.
+    pub fn apply<'a>(
+        &self,
+        value: &mut dyn Reflect,
+        path: &'a ParsedPath,
+        edit: PropertyEdit,
+    ) -> Result<(), PropertyError<'a>> {
+        let property = self.get(path).ok_or(PropertyError::NotFound { path })?;
+        if property.attributes.read_only {
+            return Err(PropertyError::ReadOnly { path });
+        }
+        if let (Some(range), PropertyEdit::Integer(_) | PropertyEdit::Float(_)) =
+            (&property.attributes.range, &edit)
+        {
+            let number = match edit {
+                PropertyEdit::Integer(integer) => integer as f64,
+                PropertyEdit::Float(float) => float,
+                _ => unreachable!(),
+            };
+            if !range.contains(&number) {
+                return Err(PropertyError::OutOfRange {
+                    path,
+                    value: number,
+                    range: range.clone(),
+                });
+            }
+        }
+
+        let element = path
+            .reflect_element_mut(value)
+            .map_err(|error| PropertyError::InvalidPath { path, error })?;
+        if set_element(element, &edit) {
+            Ok(())
+        } else {
+            Err(PropertyError::IncompatibleEdit {
+                path,
+                type_path: property.type_path.clone(),
+                edit,
+            })
+        }
+    }
+}
+
+fn build_property(
+    value: &dyn Reflect,
+    name: String,
+    path: Vec<Access<'static>>,
+    attributes: PropertyAttributes,
+) -> Property {
+    let type_info = value.get_represented_type_info();
+    let mut children = Vec::new();
+    let mut child = |child: &dyn Reflect,
+                     name: String,
+                     access: Access<'static>,
+                     own: Option<PropertyAttributes>| {
+        let mut child_attributes = own.unwrap_or_default();
+        child_attributes.read_only |= attributes.read_only;
+        let mut path = path.clone();
+        path.push(access);
+        children.push(build_property(child, name, path, child_attributes));
+    };
+    let inherited_range = || PropertyAttributes {
+        range: attributes.range.clone(),
+        ..Default::default()
+    };
+
+    let property_value = match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap().to_string();
+                let own = match type_info {
+                    Some(TypeInfo::Struct(info)) => info.field_at(index),
+                    _ => None,
+                }
+                .map(|field| PropertyAttributes::from_custom_attributes(field.custom_attributes()));
+                child(field, name.clone(), Access::Field(name.into()), own);
+            }
+            PropertyValue::Struct
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let own = match type_info {
+                    Some(TypeInfo::TupleStruct(info)) => info.field_at(index),
+                    _ => None,
+                }
+                .map(|field| PropertyAttributes::from_custom_attributes(field.custom_attributes()));
+                child(field, index.to_string(), Access::TupleIndex(index), own);
+            }
+            PropertyValue::Struct
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let own = match type_info {
+                    Some(TypeInfo::Tuple(info)) => info.field_at(index),
+                    _ => None,
+                }
+                .map(|field| PropertyAttributes::from_custom_attributes(field.custom_attributes()));
+                child(field, index.to_string(), Access::TupleIndex(index), own);
+            }
+            PropertyValue::Tuple
+        }
+        ReflectRef::List(value) => {
+            for (index, element) in value.iter().enumerate() {
+                let own = Some(inherited_range());
+                child(element, index.to_string(), Access::ListIndex(index), own);
+            }
+            PropertyValue::List { len: value.len() }
+        }
+        ReflectRef::Array(value) => {
+            for (index, element) in value.iter().enumerate() {
+                let own = Some(inherited_range());
+                child(element, index.to_string(), Access::ListIndex(index), own);
+            }
+            PropertyValue::List { len: value.len() }
+        }
+        ReflectRef::Map(value) => PropertyValue::Map { len: value.len() },
+        ReflectRef::Set(value) => {
+            let values = if value.is_ordered() {
+                value.iter()
+            } else {
+                Box::new(std::iter::empty())
+            };
+            for (index, element) in values.enumerate() {
+                let own = Some(PropertyAttributes {
+                    read_only: true,
+                    ..inherited_range()
+                });
+                child(element, index.to_string(), Access::ListIndex(index), own);
+            }
+            PropertyValue::Set { len: value.len() }
+        }
+        ReflectRef::Enum(value) => {
+            let (variant_info, variants) = match type_info {
+                Some(TypeInfo::Enum(info)) => (
+                    info.variant(value.variant_name()),
+                    info.variant_names().to_vec(),
+                ),
+                _ => (None, Vec::new()),
+            };
+            for (index, field) in value.iter_fields().enumerate() {
+                let own = match variant_info {
+                    Some(VariantInfo::Struct(info)) => info.field_at(index).map(|field| {
+                        PropertyAttributes::from_custom_attributes(field.custom_attributes())
+                    }),
+                    Some(VariantInfo::Tuple(info)) => info.field_at(index).map(|field| {
+                        PropertyAttributes::from_custom_attributes(field.custom_attributes())
+                    }),
+                    _ => None,
+                };
+                let own = own.map(|mut own| {
+                    own.range = own.range.or_else(|| attributes.range.clone());
+                    own
+                });
+                match field.name() {
+                    Some(name) => {
+                        let name = name.to_string();
+                        child(field.value(), name.clone(), Access::Field(name.into()), own);
+                    }
+                    None => {
+                        let access = Access::TupleIndex(index);
+                        child(field.value(), index.to_string(), access, own);
+                    }
+                }
+            }
+            PropertyValue::Enum {
+                variant: value.variant_name().to_string(),
+                variants,
+            }
+        }
+        ReflectRef::Value(value) => scalar_value(value),
+    };
+
+    Property {
+        name,
+        path: ParsedPath::from(path),
+        type_path: value.reflect_type_path().to_string(),
+        attributes,
+        value: property_value,
+        children,
+    }
+}
+
+fn scalar_value(value: &dyn Reflect) -> PropertyValue {
+    macro_rules! integer {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return match i128::try_from(*value) {
+                        Ok(value) => PropertyValue::Integer(value),
+                        Err(_) => PropertyValue::Opaque,
+                    };
+                }
+            )*
+        };
+    }
+
+    integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    if let Some(value) = value.downcast_ref::<bool>() {
+        PropertyValue::Bool(*value)
+    } else if let Some(value) = value.downcast_ref::<f32>() {
+        PropertyValue::Float(*value as f64)
+    } else if let Some(value) = value.downcast_ref::<f64>() {
+        PropertyValue::Float(*value)
+    } else if let Some(value) = value.downcast_ref::<char>() {
+        PropertyValue::Char(*value)
+    } else if let Some(value) = value.downcast_ref::<String>() {
+        PropertyValue::String(value.clone())
+    } else {
+        PropertyValue::Opaque
+    }
+}
+
+/// Sets `element` as described by `edit`, returning `false` if the edit doesn't fit it.
+fn set_element(element: &mut dyn Reflect, edit: &PropertyEdit) -> bool {
+    macro_rules! set_integer {
+        ($integer:expr, $($ty:ty),*) => {
+            $(
+                if let Some(element) = element.downcast_mut::<$ty>() {
+                    return match <$ty>::try_from($integer) {
+                        Ok(value) => {
+                            *element = value;
+                            true
+                        }
+                        Err(_) => false,
+                    };
+                }
+            )*
+        };
+    }
+
+    match edit {
+        PropertyEdit::Bool(value) => set(element, *value),
+        PropertyEdit::Integer(value) => {
+            set_integer!(*value, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+            set(element, *value as f32) || set(element, *value as f64)
+        }
+        PropertyEdit::Float(value) => set(element, *value as f32) || set(element, *value),
+        PropertyEdit::Char(value) => set(element, *value),
+        PropertyEdit::String(value) => set(element, value.clone()),
+        PropertyEdit::Variant(name) => {
+            let unit_variant = match element.get_represented_type_info() {
+                Some(TypeInfo::Enum(info)) => {
+                    matches!(info.variant(name), Some(VariantInfo::Unit(_)))
+                }
+                _ => false,
+            };
+            let ReflectMut::Enum(value) = element.reflect_mut() else {
+                return false;
+            };
+            if !unit_variant {
+                return false;
+            }
+            if value.variant_name() != name || value.variant_type() != VariantType::Unit {
+                value.apply(&DynamicEnum::new(name.clone(), DynamicVariant::Unit));
+            }
+            true
+        }
+    }
+}
+
+fn set<T: Reflect>(element: &mut dyn Reflect, value: T) -> bool {
+    match element.downcast_mut::<T>() {
+        Some(element) => {
+            *element = value;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Settings {
+        #[reflect(@DisplayName("Master volume"), @0..=1)]
+        volume: f32,
+        #[reflect(@1_u8..=8)]
+        players: Vec<u8>,
+        #[reflect(@Hidden)]
+        seed: u64,
+        #[reflect(@ReadOnly)]
+        version: (u32, String),
+        quality: Quality,
+        name: Option<String>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Quality {
+        Low,
+        High,
+        Custom(#[reflect(@0.5_f32..=2.0)] f32),
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            volume: 0.5,
+            players: vec![1, 2],
+            seed: 42,
+            version: (3, "beta".to_string()),
+            quality: Quality::Custom(1.0),
+            name: None,
+        }
+    }
+
+    fn path(path: &str) -> ParsedPath {
+        ParsedPath::parse(path).unwrap()
+    }
+
+    #[test]
+    fn should_build_property_tree() {
+        let tree = PropertyTree::new(&settings());
+        let root = tree.root();
+        assert_eq!(root.value, PropertyValue::Struct);
+        assert_eq!(root.children.len(), 6);
+
+        let volume = tree.get(&path(".volume")).unwrap();
+        assert_eq!(volume.display_name(), "Master volume");
+        assert_eq!(volume.attributes.range, Some(0.0..=1.0));
+        assert_eq!(volume.value, PropertyValue::Float(0.5));
+        assert!(volume.is_editable());
+
+        let player = tree.get(&path(".players[1]")).unwrap();
+        assert_eq!(player.name, "1");
+        assert_eq!(player.value, PropertyValue::Integer(2));
+        assert_eq!(player.attributes.range, Some(1.0..=8.0));
+
+        assert!(tree.get(&path(".seed")).unwrap().attributes.hidden);
+
+        let version_name = tree.get(&path(".version.1")).unwrap();
+        assert_eq!(
+            version_name.value,
+            PropertyValue::String("beta".to_string())
+        );
+        assert!(!version_name.is_editable());
+
+        let quality = tree.get(&path(".quality")).unwrap();
+        assert_eq!(
+            quality.value,
+            PropertyValue::Enum {
+                variant: "Custom".to_string(),
+                variants: vec!["Low", "High", "Custom"],
+            }
+        );
+        let custom = tree.get(&path(".quality.0")).unwrap();
+        assert_eq!(custom.attributes.range, Some(0.5..=2.0));
+
+        let paths: Vec<String> = tree.iter().map(|p| p.path.to_string()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "",
+                ".volume",
+                ".players",
+                ".players[0]",
+                ".players[1]",
+                ".seed",
+                ".version",
+                ".version.0",
+                ".version.1",
+                ".quality",
+                ".quality.0",
+                ".name",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_apply_edits() {
+        let mut value = settings();
+        let tree = PropertyTree::new(&value);
+
+        tree.apply(&mut value, &path(".volume"), PropertyEdit::Integer(1))
+            .unwrap();
+        tree.apply(&mut value, &path(".players[0]"), PropertyEdit::Integer(4))
+            .unwrap();
+        tree.apply(&mut value, &path(".seed"), PropertyEdit::Integer(7))
+            .unwrap();
+        tree.apply(&mut value, &path(".quality.0"), PropertyEdit::Float(2.0))
+            .unwrap();
+        assert_eq!(value.volume, 1.0);
+        assert_eq!(value.players, vec![4, 2]);
+        assert_eq!(value.seed, 7);
+        assert_eq!(value.quality, Quality::Custom(2.0));
+
+        tree.apply(
+            &mut value,
+            &path(".quality"),
+            PropertyEdit::Variant("High".to_string()),
+        )
+        .unwrap();
+        assert_eq!(value.quality, Quality::High);
+
+        let tree = PropertyTree::new(&value);
+        assert!(tree.get(&path(".quality.0")).is_none());
+    }
+
+    #[test]
+    fn should_reject_invalid_edits() {
+        let mut value = settings();
+        let tree = PropertyTree::new(&value);
+
+        let volume = path(".volume");
+        assert_eq!(
+            tree.apply(&mut value, &volume, PropertyEdit::Float(1.5)),
+            Err(PropertyError::OutOfRange {
+                path: &volume,
+                value: 1.5,
+                range: 0.0..=1.0,
+            })
+        );
+        let version = path(".version.0");
+        assert_eq!(
+            tree.apply(&mut value, &version, PropertyEdit::Integer(4)),
+            Err(PropertyError::ReadOnly { path: &version })
+        );
+        let missing = path(".missing");
+        assert_eq!(
+            tree.apply(&mut value, &missing, PropertyEdit::Bool(true)),
+            Err(PropertyError::NotFound { path: &missing })
+        );
+        assert!(matches!(
+            tree.apply(&mut value, &path(".seed"), PropertyEdit::Integer(-1)),
+            Err(PropertyError::IncompatibleEdit { .. })
+        ));
+        assert!(matches!(
+            tree.apply(&mut value, &path(".players[0]"), PropertyEdit::Float(2.0)),
+            Err(PropertyError::IncompatibleEdit { .. })
+        ));
+        assert!(matches!(
+            tree.apply(
+                &mut value,
+                &path(".quality"),
+                PropertyEdit::Variant("Custom".to_string())
+            ),
+            Err(PropertyError::IncompatibleEdit { .. })
+        ));
+        assert_eq!(value, settings());
+    }
+}
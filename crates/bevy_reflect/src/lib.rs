@@ -68,6 +68,7 @@
 //! * [`Array`]
 //! * [`List`]
 //! * [`Map`]
+//! * [`Set`]
 //! * [`Struct`]
 //! * [`TupleStruct`]
 //! * [`Enum`]
@@ -128,6 +129,7 @@
 //! * [`DynamicArray`]
 //! * [`DynamicList`]
 //! * [`DynamicMap`]
+//! * [`DynamicSet`]
 //! * [`DynamicStruct`]
 //! * [`DynamicTupleStruct`]
 //! * [`DynamicEnum`]
@@ -469,12 +471,15 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod attributes;
+mod diff;
 mod fields;
 mod from_reflect;
 mod list;
 mod map;
 mod path;
 mod reflect;
+mod set;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
@@ -500,6 +505,8 @@ mod impls {
 }
 
 mod enums;
+pub mod func;
+pub mod inspector;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -515,6 +522,8 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use attributes::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -522,6 +531,7 @@ pub use list::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
+pub use set::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
@@ -96,6 +96,10 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get(index)),
+            (&Self::ListIndex(index), Set(set)) if set.is_ordered() => Ok(set.iter().nth(index)),
+            (Self::ListIndex(_), Set(_)) => {
+                Err(AccessErrorKind::UnorderedElements(ReflectKind::Set))
+            }
             (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
                 expected: ReflectKind::List,
                 actual: actual.into(),
@@ -155,6 +159,9 @@ impl<'a> Access<'a> {
 
             (&Self::ListIndex(index), List(list)) => Ok(list.get_mut(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get_mut(index)),
+            (Self::ListIndex(_), Set(_)) => {
+                Err(AccessErrorKind::ImmutableElement(ReflectKind::Set))
+            }
             (Self::ListIndex(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
                 expected: ReflectKind::List,
                 actual: actual.into(),
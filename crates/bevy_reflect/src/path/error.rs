@@ -27,6 +27,14 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when mutably accessing an element of a type whose elements
+    /// can't be modified in place (i.e. a [`ListIndex`](Access::ListIndex) on a set).
+    ImmutableElement(ReflectKind),
+
+    /// An error that occurs when accessing an element by index in a type whose elements have
+    /// no deterministic order (i.e. a [`ListIndex`](Access::ListIndex) on an unordered set).
+    UnorderedElements(ReflectKind),
 }
 
 impl AccessErrorKind {
@@ -123,6 +131,14 @@ impl std::fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::ImmutableElement(type_accessed) => write!(
+                f,
+                "The elements of a {type_accessed} can't be accessed mutably",
+            ),
+            AccessErrorKind::UnorderedElements(type_accessed) => write!(
+                f,
+                "The elements of an unordered {type_accessed} can't be accessed by index",
+            ),
         }
     }
 }
@@ -162,6 +162,10 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// assert_eq!(my_list.path::<u32>("[2]").unwrap(), &3);
 /// ```
 ///
+/// The values of an ordered [`Set`], like a `BTreeSet`, can be read with brackets as well,
+/// in the iteration order of the set. Their values can't be accessed mutably, and sets without
+/// a deterministic order, like a `HashSet`, can't be indexed at all (see [`Set::is_ordered`]).
+///
 /// ## Enums
 ///
 /// Pathing for [`Enum`] elements works a bit differently than in normal Rust.
@@ -229,6 +233,8 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
+/// [`Set`]: crate::Set
+/// [`Set::is_ordered`]: crate::Set::is_ordered
 /// [`Enum`]: crate::Enum
 pub trait GetPath: Reflect {
     /// Returns a reference to the value specified by `path`.
@@ -701,6 +707,49 @@ mod tests {
         assert_eq!(*a.path_mut::<u8>("array[5]").unwrap(), 10);
     }
 
+    #[test]
+    fn reflect_set_is_read_only() {
+        #[derive(Reflect)]
+        struct A {
+            set: std::collections::BTreeSet<u8>,
+        }
+
+        let mut a = A {
+            set: [3, 1, 2].into(),
+        };
+
+        assert_eq!(*a.path::<u8>("set[0]").unwrap(), 1);
+        assert_eq!(*a.path::<u8>("set[2]").unwrap(), 3);
+        assert!(a.path::<u8>("set[3]").is_err());
+        assert!(matches!(
+            a.path_mut::<u8>("set[0]"),
+            Err(ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::ImmutableElement(ReflectKind::Set),
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn reflect_unordered_set_has_no_indices() {
+        #[derive(Reflect)]
+        struct A {
+            set: bevy_utils::HashSet<u8>,
+        }
+
+        let a = A {
+            set: [3, 1, 2].into_iter().collect(),
+        };
+
+        assert!(matches!(
+            a.path::<u8>("set[0]"),
+            Err(ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::UnorderedElements(ReflectKind::Set),
+                ..
+            }))
+        ));
+    }
+
     #[test]
     fn reflect_path() {
         let mut a = a_sample();
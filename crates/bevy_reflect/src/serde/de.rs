@@ -1,9 +1,13 @@
-use crate::serde::SerializationData;
+use crate::serde::{
+    migration::{set_represented_type, split_versioned_type_path},
+    SerializationData, TypeMigrations, TypeTable,
+};
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
-    TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo,
+    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField,
+    Reflect, ReflectDeserialize, Set, SetInfo, StructInfo, StructVariantInfo, TupleInfo,
+    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
+    VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -311,16 +315,35 @@ impl<'de> Visitor<'de> for U32Visitor {
 /// If the type is already known and the [`TypeInfo`] for it can be retrieved,
 /// [`TypedReflectDeserializer`] may be used instead to avoid requiring these entries.
 ///
+/// Data serialized with a [`TypeTable`] must be deserialized with
+/// [`UntypedReflectDeserializer::with_type_table`] instead.
+///
 /// [`Box<dyn Reflect>`]: crate::Reflect
 /// [`FromReflect`]: crate::FromReflect
 /// [type path]: crate::TypePath::type_path
 pub struct UntypedReflectDeserializer<'a> {
     registry: &'a TypeRegistry,
+    type_table: Option<&'a TypeTable>,
 }
 
 impl<'a> UntypedReflectDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            type_table: None,
+        }
+    }
+
+    /// Creates a deserializer for the compact data written by
+    /// [`ReflectSerializer::with_type_table`]: a tuple of the ID of the type in `type_table` and
+    /// of the value.
+    ///
+    /// [`ReflectSerializer::with_type_table`]: crate::serde::ReflectSerializer::with_type_table
+    pub fn with_type_table(registry: &'a TypeRegistry, type_table: &'a TypeTable) -> Self {
+        Self {
+            registry,
+            type_table: Some(type_table),
+        }
     }
 }
 
@@ -331,9 +354,18 @@ impl<'a, 'de> DeserializeSeed<'de> for UntypedReflectDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
-            registry: self.registry,
-        })
+        match self.type_table {
+            Some(type_table) => deserializer.deserialize_tuple(
+                2,
+                CompactReflectDeserializerVisitor {
+                    registry: self.registry,
+                    type_table,
+                },
+            ),
+            None => deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
+                registry: self.registry,
+            }),
+        }
     }
 }
 
@@ -385,6 +417,141 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
     }
 }
 
+/// A deserializer for type registrations and the version of the data that follows.
+///
+/// This will return the [`&TypeRegistration`] corresponding to the given type, along with the
+/// version of its data, as written by [`versioned_type_path`]:
+/// a string containing the _full_ [type path] of the type, optionally followed by `@` and the
+/// version. Data without a version is of version `0`.
+///
+/// The version should be passed to [`MigratingReflectDeserializer`] to deserialize the data.
+///
+/// [`&TypeRegistration`]: TypeRegistration
+/// [`versioned_type_path`]: crate::serde::versioned_type_path
+/// [type path]: crate::TypePath::type_path
+pub struct VersionedTypeRegistrationDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> VersionedTypeRegistrationDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for VersionedTypeRegistrationDeserializer<'a> {
+    type Value = (&'a TypeRegistration, u32);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct VersionedTypeRegistrationVisitor<'a>(&'a TypeRegistry);
+
+        impl<'de, 'a> Visitor<'de> for VersionedTypeRegistrationVisitor<'a> {
+            type Value = (&'a TypeRegistration, u32);
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("string containing `type` entry for the reflected value")
+            }
+
+            fn visit_str<E>(self, versioned_type_path: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                get_versioned_registration(self.0, versioned_type_path)
+            }
+        }
+
+        deserializer.deserialize_str(VersionedTypeRegistrationVisitor(self.registry))
+    }
+}
+
+fn get_versioned_registration<'a, E: Error>(
+    registry: &'a TypeRegistry,
+    versioned_type_path: &str,
+) -> Result<(&'a TypeRegistration, u32), E> {
+    let (type_path, version) = split_versioned_type_path(versioned_type_path).ok_or_else(|| {
+        Error::custom(format_args!(
+            "invalid version in type path `{versioned_type_path}`"
+        ))
+    })?;
+    let registration = registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| Error::custom(format_args!("No registration found for `{type_path}`")))?;
+    Ok((registration, version))
+}
+
+/// A deserializer for reflected types whose [`TypeInfo`] and data version are known.
+///
+/// Data of the current version of the type is deserialized like [`TypedReflectDeserializer`]
+/// does. Data of an older version is deserialized in the shape registered for that version, then
+/// brought to the current version by the [`TypeMigrations`] of the type.
+/// Data of a newer version is rejected.
+pub struct MigratingReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    version: u32,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> MigratingReflectDeserializer<'a> {
+    pub fn new(
+        registration: &'a TypeRegistration,
+        version: u32,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        Self {
+            registration,
+            version,
+            registry,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MigratingReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_info = self.registration.type_info();
+        let migrations = self.registration.data::<TypeMigrations>();
+        let current = migrations.map_or(0, TypeMigrations::version);
+        if self.version == current {
+            return TypedReflectDeserializer::new(self.registration, self.registry)
+                .deserialize(deserializer);
+        }
+
+        let type_path = type_info.type_path();
+        let migrations = migrations
+            .filter(|_| self.version < current)
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "data of `{type_path}` is of version {}, newer than the current version {current}",
+                    self.version
+                ))
+            })?;
+        let shape = migrations
+            .shape(self.version)
+            .and_then(|shape| self.registry.get(shape))
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no registered shape for version {} of `{type_path}`",
+                    self.version
+                ))
+            })?;
+
+        let value =
+            TypedReflectDeserializer::new(shape, self.registry).deserialize(deserializer)?;
+        let mut value = migrations
+            .migrate(value, self.version)
+            .map_err(|err| Error::custom(format_args!("cannot migrate `{type_path}`: {err}")))?;
+        set_represented_type(&mut *value, type_info);
+        Ok(value)
+    }
+}
+
 struct UntypedReflectDeserializerVisitor<'a> {
     registry: &'a TypeRegistry,
 }
@@ -400,14 +567,15 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
-        let registration = map
-            .next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        let (registration, version) = map
+            .next_key_seed(VersionedTypeRegistrationDeserializer::new(self.registry))?
             .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
 
-        let value = map.next_value_seed(TypedReflectDeserializer {
+        let value = map.next_value_seed(MigratingReflectDeserializer::new(
             registration,
-            registry: self.registry,
-        })?;
+            version,
+            self.registry,
+        ))?;
 
         if map.next_key::<IgnoredAny>()?.is_some() {
             return Err(Error::invalid_length(2, &"a single entry"));
@@ -417,6 +585,40 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
     }
 }
 
+struct CompactReflectDeserializerVisitor<'a> {
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializerVisitor<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple containing the type ID and the value of the reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let id: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let type_path = self
+            .type_table
+            .type_path(id)
+            .ok_or_else(|| Error::custom(format_args!("no type with ID {id} in the type table")))?;
+        let (registration, version) = get_versioned_registration(self.registry, type_path)?;
+
+        seq.next_element_seed(MigratingReflectDeserializer::new(
+            registration,
+            version,
+            self.registry,
+        ))?
+        .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
 /// A deserializer for reflected types whose [`TypeInfo`] is known.
 ///
 /// This will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -515,6 +717,14 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 dynamic_map.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_map))
             }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                })?;
+                dynamic_set.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_set))
+            }
             TypeInfo::Tuple(tuple_info) => {
                 let mut dynamic_tuple = deserializer.deserialize_tuple(
                     tuple_info.field_len(),
@@ -750,6 +960,39 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
+struct SetVisitor<'a> {
+    set_info: &'static SetInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SetVisitor<'a> {
+    type Value = DynamicSet;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected set value")
+    }
+
+    fn visit_seq<V>(self, mut set: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_set = DynamicSet::default();
+        let value_registration = get_registration(
+            self.set_info.value_type_id(),
+            self.set_info.value_type_path_table().path(),
+            self.registry,
+        )?;
+        while let Some(value) = set.next_element_seed(TypedReflectDeserializer {
+            registration: value_registration,
+            registry: self.registry,
+        })? {
+            dynamic_set.insert_boxed(value);
+        }
+
+        Ok(dynamic_set)
+    }
+}
+
 struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
@@ -0,0 +1,458 @@
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::{DiffOp, ParsedPath, Reflect, ReflectDiff, TypeRegistry};
+use serde::de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStructVariant};
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::fmt::Formatter;
+
+const DIFF_OP: &str = "DiffOp";
+const VARIANTS: &[&str] = &[
+    "Replace",
+    "ListInsert",
+    "ListRemove",
+    "MapInsert",
+    "MapRemove",
+];
+
+/// A serializer for [`ReflectDiff`]s.
+///
+/// The diff is serialized as a sequence of its operations, each one being an enum variant named
+/// after the [`DiffOp`] variant. Paths are serialized as strings, and values and keys with a
+/// [`ReflectSerializer`], so that they can be deserialized without knowing their type.
+pub struct ReflectDiffSerializer<'a> {
+    pub diff: &'a ReflectDiff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        ReflectDiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.diff.ops().len()))?;
+        for op in self.diff.ops() {
+            state.serialize_element(&DiffOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct DiffOpSerializer<'a> {
+    op: &'a DiffOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffOpSerializer<'a> {
+    fn reflect(&self, value: &'a dyn Reflect) -> ReflectSerializer<'a> {
+        ReflectSerializer::new(value, self.registry)
+    }
+}
+
+impl<'a> Serialize for DiffOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let path = self.op.path().to_string();
+        match self.op {
+            DiffOp::Replace { value, .. } => {
+                let mut state = serializer.serialize_struct_variant(DIFF_OP, 0, VARIANTS[0], 2)?;
+                state.serialize_field("path", &path)?;
+                state.serialize_field("value", &self.reflect(&**value))?;
+                state.end()
+            }
+            DiffOp::ListInsert { index, value, .. } => {
+                let mut state = serializer.serialize_struct_variant(DIFF_OP, 1, VARIANTS[1], 3)?;
+                state.serialize_field("path", &path)?;
+                state.serialize_field("index", index)?;
+                state.serialize_field("value", &self.reflect(&**value))?;
+                state.end()
+            }
+            DiffOp::ListRemove { index, .. } => {
+                let mut state = serializer.serialize_struct_variant(DIFF_OP, 2, VARIANTS[2], 2)?;
+                state.serialize_field("path", &path)?;
+                state.serialize_field("index", index)?;
+                state.end()
+            }
+            DiffOp::MapInsert { key, value, .. } => {
+                let mut state = serializer.serialize_struct_variant(DIFF_OP, 3, VARIANTS[3], 3)?;
+                state.serialize_field("path", &path)?;
+                state.serialize_field("key", &self.reflect(&**key))?;
+                state.serialize_field("value", &self.reflect(&**value))?;
+                state.end()
+            }
+            DiffOp::MapRemove { key, .. } => {
+                let mut state = serializer.serialize_struct_variant(DIFF_OP, 4, VARIANTS[4], 2)?;
+                state.serialize_field("path", &path)?;
+                state.serialize_field("key", &self.reflect(&**key))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// A deserializer for [`ReflectDiff`]s serialized with a [`ReflectDiffSerializer`].
+///
+/// The values of the diff are deserialized with an [`UntypedReflectDeserializer`], so they are
+/// dynamic values for types that don't register [`ReflectDeserialize`](crate::ReflectDeserialize).
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReflectDiffVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ReflectDiffVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectDiffVisitor<'a> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of diff operations")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut ops = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(op) = seq.next_element_seed(DiffOpDeserializer {
+            registry: self.registry,
+        })? {
+            ops.push(op);
+        }
+        Ok(ops.into())
+    }
+}
+
+struct DiffOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffOpDeserializer<'a> {
+    type Value = DiffOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(DIFF_OP, VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffOpDeserializer<'a> {
+    type Value = DiffOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("diff operation")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, data) = data.variant_seed(VariantDeserializer)?;
+        let fields: &[&str] = match variant {
+            0 => &["path", "value"],
+            1 => &["path", "index", "value"],
+            2 => &["path", "index"],
+            3 => &["path", "key", "value"],
+            _ => &["path", "key"],
+        };
+        data.struct_variant(
+            fields,
+            DiffOpFieldsVisitor {
+                variant,
+                fields,
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+/// Deserializes the index of a [`DiffOp`] variant, from its name or index.
+struct VariantDeserializer;
+
+impl<'de> DeserializeSeed<'de> for VariantDeserializer {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantDeserializer {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("diff operation name or index")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        usize::try_from(value)
+            .ok()
+            .filter(|&index| index < VARIANTS.len())
+            .ok_or_else(|| Error::custom(format_args!("no diff operation at index {value}")))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        VARIANTS
+            .iter()
+            .position(|variant| *variant == value)
+            .ok_or_else(|| Error::unknown_variant(value, VARIANTS))
+    }
+}
+
+/// The fields of a [`DiffOp`], which are all optional until the whole variant is read.
+#[derive(Default)]
+struct DiffOpFields {
+    path: Option<ParsedPath>,
+    index: Option<usize>,
+    key: Option<Box<dyn Reflect>>,
+    value: Option<Box<dyn Reflect>>,
+}
+
+struct DiffOpFieldsVisitor<'a> {
+    variant: usize,
+    fields: &'static [&'static str],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffOpFieldsVisitor<'a> {
+    fn reflect(&self) -> UntypedReflectDeserializer<'a> {
+        UntypedReflectDeserializer::new(self.registry)
+    }
+
+    fn build<E: Error>(&self, fields: DiffOpFields) -> Result<DiffOp, E> {
+        let path = fields.path.ok_or_else(|| Error::missing_field("path"))?;
+        let index = || fields.index.ok_or_else(|| Error::missing_field("index"));
+        let key = || fields.key.ok_or_else(|| Error::missing_field("key"));
+        let value = || fields.value.ok_or_else(|| Error::missing_field("value"));
+        Ok(match self.variant {
+            0 => DiffOp::Replace {
+                path,
+                value: value()?,
+            },
+            1 => DiffOp::ListInsert {
+                path,
+                index: index()?,
+                value: value()?,
+            },
+            2 => DiffOp::ListRemove {
+                path,
+                index: index()?,
+            },
+            3 => DiffOp::MapInsert {
+                path,
+                key: key()?,
+                value: value()?,
+            },
+            _ => DiffOp::MapRemove { path, key: key()? },
+        })
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffOpFieldsVisitor<'a> {
+    type Value = DiffOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{} operation", VARIANTS[self.variant])
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut fields = DiffOpFields::default();
+        for (position, name) in self.fields.iter().enumerate() {
+            let missing = || Error::invalid_length(position, &self);
+            match *name {
+                "path" => {
+                    let path: String = seq.next_element()?.ok_or_else(missing)?;
+                    fields.path = Some(parse_path(&path)?);
+                }
+                "index" => fields.index = Some(seq.next_element()?.ok_or_else(missing)?),
+                "key" => {
+                    fields.key = Some(seq.next_element_seed(self.reflect())?.ok_or_else(missing)?);
+                }
+                _ => {
+                    fields.value =
+                        Some(seq.next_element_seed(self.reflect())?.ok_or_else(missing)?);
+                }
+            }
+        }
+        self.build(fields)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = DiffOpFields::default();
+        while let Some(name) = map.next_key::<String>()? {
+            match name.as_str() {
+                "path" => fields.path = Some(parse_path(&map.next_value::<String>()?)?),
+                "index" => fields.index = Some(map.next_value()?),
+                "key" => fields.key = Some(map.next_value_seed(self.reflect())?),
+                "value" => fields.value = Some(map.next_value_seed(self.reflect())?),
+                name => return Err(Error::unknown_field(name, self.fields)),
+            }
+        }
+        self.build(fields)
+    }
+}
+
+fn parse_path<E: Error>(path: &str) -> Result<ParsedPath, E> {
+    ParsedPath::parse(path).map_err(|error| Error::custom(format_args!("invalid path: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::serde::{ReflectDiffDeserializer, ReflectDiffSerializer};
+    use crate::{Reflect, ReflectDiff, TypeRegistry};
+    use bevy_utils::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Scene {
+        name: String,
+        entities: Vec<Entity>,
+        tags: HashMap<String, bool>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Entity {
+        position: (f32, f32),
+        kind: Kind,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Kind {
+        Empty,
+        Light { intensity: f32 },
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Scene>();
+        registry.register::<Entity>();
+        registry.register::<Kind>();
+        registry.register::<(f32, f32)>();
+        registry.register::<HashMap<String, bool>>();
+        registry
+    }
+
+    fn scenes() -> (Scene, Scene) {
+        let old = Scene {
+            name: "Level".to_string(),
+            entities: vec![
+                Entity {
+                    position: (0.0, 0.0),
+                    kind: Kind::Empty,
+                },
+                Entity {
+                    position: (1.0, 0.0),
+                    kind: Kind::Light { intensity: 1.0 },
+                },
+            ],
+            tags: HashMap::from_iter([("dark".to_string(), true)]),
+        };
+        let mut new = old.clone();
+        new.name = "Level 2".to_string();
+        new.entities[1].kind = Kind::Empty;
+        new.entities.remove(0);
+        new.entities.push(Entity {
+            position: (3.0, 3.0),
+            kind: Kind::Light { intensity: 2.0 },
+        });
+        new.tags.remove("dark");
+        new.tags.insert("outdoor".to_string(), false);
+        (old, new)
+    }
+
+    #[test]
+    fn should_round_trip_diff_with_json() {
+        let registry = registry();
+        let (old, new) = scenes();
+        let diff = ReflectDiff::new(&old, &new);
+
+        let json = serde_json::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(deserialized.ops().len(), diff.ops().len());
+
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        // Replacing the root value uses an empty path.
+        let light = Kind::Light { intensity: 1.0 };
+        let diff = ReflectDiff::new(&Kind::Empty, &light);
+        let json = serde_json::to_string(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut value = Kind::Empty;
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, light);
+    }
+
+    #[test]
+    fn should_round_trip_diff_with_bincode() {
+        let registry = registry();
+        let (old, new) = scenes();
+        let diff = ReflectDiff::new(&old, &new);
+
+        let bytes = bincode::serialize(&ReflectDiffSerializer::new(&diff, &registry)).unwrap();
+        let deserialized = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDiffDeserializer::new(&registry), &bytes)
+            .unwrap();
+
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, Reflect, TypeInfo, TypeRegistry,
+};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::Arc;
+use thiserror::Error;
+
+type MigrateFn = dyn Fn(Box<dyn Reflect>) -> Box<dyn Reflect> + Send + Sync;
+
+#[derive(Clone)]
+struct TypeMigration {
+    shape: TypeId,
+    migrate: Arc<MigrateFn>,
+}
+
+/// Type data holding the migrations of the serialized data of a type, registered with
+/// [`TypeRegistry::register_migration`].
+///
+/// The current version of a type is the number of its migrations, and is stored next to its
+/// [type path] by [`ReflectSerializer`], as `type_path@version`. Data without a version is of
+/// version `0`. Data of an older version is deserialized in the shape of that version, then
+/// migrated one version at a time, before any [`FromReflect`] conversion.
+///
+/// Only values whose type is serialized along with them are versioned, such as the values of
+/// [`ReflectSerializer`] or the components of a scene: fields are expected to be of the version
+/// of the value containing them.
+///
+/// ```
+/// # use bevy_reflect::serde::{ReflectSerializer, UntypedReflectDeserializer};
+/// # use bevy_reflect::{DynamicStruct, FromReflect, Reflect, Struct, TypePath, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Player {
+///     health: f32,
+/// }
+///
+/// // The shape of `Player` before `hp` was renamed to `health`.
+/// #[derive(Reflect)]
+/// struct PlayerV0 {
+///     hp: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register_migration::<Player, PlayerV0>(0, |value| {
+///     let old = value.downcast::<DynamicStruct>().unwrap();
+///     let mut new = DynamicStruct::default();
+///     new.insert_boxed("health", old.field("hp").unwrap().clone_value());
+///     Box::new(new)
+/// });
+///
+/// let data = format!("{{\"{}\": (hp: 10.0)}}", Player::type_path());
+/// let mut deserializer = ron::de::Deserializer::from_str(&data).unwrap();
+/// let value = UntypedReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Player::from_reflect(&*value).unwrap(), Player { health: 10.0 });
+///
+/// let serialized = ron::to_string(&ReflectSerializer::new(&*value, &registry)).unwrap();
+/// assert_eq!(serialized, format!("{{\"{}@1\":(health:10.0)}}", Player::type_path()));
+/// ```
+///
+/// [type path]: crate::TypePath::type_path
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`FromReflect`]: crate::FromReflect
+#[derive(Clone, Default)]
+pub struct TypeMigrations {
+    migrations: Vec<Option<TypeMigration>>,
+}
+
+impl TypeMigrations {
+    /// Adds the migration from version `from_version` to version `from_version + 1`, taking data
+    /// deserialized as type `shape`.
+    ///
+    /// This replaces any migration previously added for `from_version`.
+    pub fn insert(
+        &mut self,
+        from_version: u32,
+        shape: TypeId,
+        migrate: impl Fn(Box<dyn Reflect>) -> Box<dyn Reflect> + Send + Sync + 'static,
+    ) {
+        let index = from_version as usize;
+        if self.migrations.len() <= index {
+            self.migrations.resize(index + 1, None);
+        }
+        self.migrations[index] = Some(TypeMigration {
+            shape,
+            migrate: Arc::new(migrate),
+        });
+    }
+
+    /// Returns the current version of the type.
+    pub fn version(&self) -> u32 {
+        self.migrations.len() as u32
+    }
+
+    /// Returns the [`TypeId`] of the type describing the shape of data of the given version.
+    ///
+    /// Returns `None` for the current version, or if no migration was added from `version`.
+    pub fn shape(&self, version: u32) -> Option<TypeId> {
+        self.migrations
+            .get(version as usize)?
+            .as_ref()
+            .map(|migration| migration.shape)
+    }
+
+    /// Migrates `value`, of version `from_version`, to the current version.
+    pub fn migrate(
+        &self,
+        mut value: Box<dyn Reflect>,
+        from_version: u32,
+    ) -> Result<Box<dyn Reflect>, MigrationError> {
+        if from_version > self.version() {
+            return Err(MigrationError::UnsupportedVersion {
+                version: from_version,
+                current: self.version(),
+            });
+        }
+        for version in from_version..self.version() {
+            let migration = self.migrations[version as usize]
+                .as_ref()
+                .ok_or(MigrationError::MissingMigration { version })?;
+            value = (migration.migrate)(value);
+        }
+        Ok(value)
+    }
+}
+
+/// An error returned when migrating serialized data.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum MigrationError {
+    #[error("data of version {version} is newer than the current version {current}")]
+    UnsupportedVersion { version: u32, current: u32 },
+    #[error("no migration was registered from version {version}")]
+    MissingMigration { version: u32 },
+}
+
+/// Returns the [type path] of the type described by `type_info`, followed by `@` and its
+/// current version if it has [migrations].
+///
+/// [type path]: crate::TypePath::type_path
+/// [migrations]: TypeMigrations
+pub fn versioned_type_path(
+    type_info: &'static TypeInfo,
+    registry: &TypeRegistry,
+) -> Cow<'static, str> {
+    match registry
+        .get_type_data::<TypeMigrations>(type_info.type_id())
+        .map(TypeMigrations::version)
+    {
+        Some(version) if version > 0 => Cow::Owned(format!("{}@{version}", type_info.type_path())),
+        _ => Cow::Borrowed(type_info.type_path()),
+    }
+}
+
+/// Splits a versioned type path into the type path and its version, `0` if it has none.
+///
+/// Returns `None` if the version isn't a valid number.
+pub(crate) fn split_versioned_type_path(versioned_type_path: &str) -> Option<(&str, u32)> {
+    match versioned_type_path.rsplit_once('@') {
+        Some((type_path, version)) => Some((type_path, version.parse().ok()?)),
+        None => Some((versioned_type_path, 0)),
+    }
+}
+
+/// Makes a migrated dynamic value represent the type described by `type_info`.
+pub(crate) fn set_represented_type(value: &mut dyn Reflect, type_info: &'static TypeInfo) {
+    let value = value.as_any_mut();
+    if let Some(value) = value.downcast_mut::<DynamicStruct>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicTupleStruct>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicTuple>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicList>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicArray>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicMap>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicSet>() {
+        value.set_represented_type(Some(type_info));
+    } else if let Some(value) = value.downcast_mut::<DynamicEnum>() {
+        value.set_represented_type(Some(type_info));
+    }
+}
@@ -1,20 +1,33 @@
 mod de;
+mod diff;
+mod migration;
+#[cfg(feature = "json_schema")]
+mod schema;
 mod ser;
 mod type_data;
+mod type_table;
 
 pub use de::*;
+pub use diff::*;
+pub use migration::{versioned_type_path, MigrationError, TypeMigrations};
+#[cfg(feature = "json_schema")]
+pub use schema::*;
 pub use ser::*;
 pub use type_data::*;
+pub use type_table::*;
 
 #[cfg(test)]
 mod tests {
     use crate::{self as bevy_reflect, DynamicTupleStruct, Struct};
     use crate::{
-        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        serde::{ReflectSerializer, TypeTable, UntypedReflectDeserializer},
         type_registry::TypeRegistry,
-        DynamicStruct, FromReflect, Reflect,
+        DynamicStruct, FromReflect, Reflect, TypePath,
     };
+    use bincode::Options;
     use serde::de::DeserializeSeed;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::ops::Range;
 
     #[test]
     fn test_serialization_struct() {
@@ -181,4 +194,190 @@ mod tests {
 
         assert!(expected.reflect_partial_eq(&result).unwrap());
     }
+
+    #[test]
+    fn should_roundtrip_collections() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Collections {
+            hash_set: bevy_utils::HashSet<u32>,
+            btree_set: BTreeSet<String>,
+            btree_map: BTreeMap<u8, bool>,
+            range: Range<f32>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Collections>();
+        registry.register::<bevy_utils::HashSet<u32>>();
+        registry.register::<BTreeSet<String>>();
+        registry.register::<BTreeMap<u8, bool>>();
+        registry.register::<Range<f32>>();
+
+        let value = Collections {
+            hash_set: [3, 1].into_iter().collect(),
+            btree_set: ["b".to_string(), "a".to_string()].into(),
+            btree_map: [(2, false), (1, true)].into(),
+            range: 0.0..1.0,
+        };
+
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert!(serialized.contains(r#"btree_set:["a","b"],btree_map:{1:true,2:false}"#));
+        assert!(serialized.contains("range:(start:0.0,end:1.0)"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Collections::from_reflect(&*deserialized).unwrap(), value);
+    }
+
+    #[test]
+    fn should_roundtrip_compact() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Save {
+            name: String,
+            #[reflect(skip_serializing)]
+            cached: u32,
+            position: (f32, f32),
+            state: State,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum State {
+            Idle,
+            Walking { speed: f32 },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Save>();
+        registry.register::<State>();
+        registry.register::<(f32, f32)>();
+        let type_table = TypeTable::from_registry(&registry);
+        let id = type_table.id(Save::type_path()).unwrap();
+
+        let value = Save {
+            name: "Ferris".to_string(),
+            cached: 3,
+            position: (1.0, 2.0),
+            state: State::Walking { speed: 0.5 },
+        };
+        let expected = Save {
+            name: "Ferris".to_string(),
+            cached: 0,
+            position: (1.0, 2.0),
+            state: State::Walking { speed: 0.5 },
+        };
+        let serializer = ReflectSerializer::with_type_table(&value, &registry, &type_table);
+
+        let json = serde_json::to_string(&serializer).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"[{id},["Ferris",[1.0,2.0],{{"Walking":[0.5]}}]]"#)
+        );
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let deserialized = UntypedReflectDeserializer::with_type_table(&registry, &type_table)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Save::from_reflect(&*deserialized).unwrap(), expected);
+
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let untyped_bytes = bincode::serialize(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert!(bytes.len() < untyped_bytes.len());
+        let deserialized = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                UntypedReflectDeserializer::with_type_table(&registry, &type_table),
+                &bytes,
+            )
+            .unwrap();
+        assert_eq!(Save::from_reflect(&*deserialized).unwrap(), expected);
+
+        let empty_table = TypeTable::default();
+        let serializer = ReflectSerializer::with_type_table(&value, &registry, &empty_table);
+        assert!(serde_json::to_string(&serializer).is_err());
+    }
+
+    #[test]
+    fn should_migrate_versioned_data() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            name: String,
+            health: f32,
+        }
+
+        #[derive(Reflect)]
+        struct PlayerV0 {
+            hp: f32,
+        }
+
+        #[derive(Reflect)]
+        struct PlayerV1 {
+            health: f32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register_type_alias::<Player>("game::Hero");
+        registry.register_migration::<Player, PlayerV0>(0, |value| {
+            let old = value.take::<DynamicStruct>().unwrap();
+            let mut new = DynamicStruct::default();
+            new.insert_boxed("health", old.field("hp").unwrap().clone_value());
+            Box::new(new)
+        });
+        registry.register_migration::<Player, PlayerV1>(1, |value| {
+            let mut value = value.take::<DynamicStruct>().unwrap();
+            value.insert("name", "Unnamed".to_string());
+            Box::new(value)
+        });
+
+        let expected = Player {
+            name: "Unnamed".to_string(),
+            health: 10.0,
+        };
+        let deserialize = |data: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(data).unwrap();
+            UntypedReflectDeserializer::new(&registry).deserialize(&mut deserializer)
+        };
+
+        let value = deserialize(r#"{"game::Hero": (hp: 10.0)}"#).unwrap();
+        assert_eq!(
+            value.get_represented_type_info().unwrap().type_path(),
+            Player::type_path()
+        );
+        assert_eq!(Player::from_reflect(&*value).unwrap(), expected);
+
+        let data = format!(r#"{{"{}@1": (health: 10.0)}}"#, Player::type_path());
+        let value = deserialize(&data).unwrap();
+        assert_eq!(Player::from_reflect(&*value).unwrap(), expected);
+
+        let serialized =
+            ron::ser::to_string(&ReflectSerializer::new(&expected, &registry)).unwrap();
+        assert_eq!(
+            serialized,
+            format!(
+                r#"{{"{}@2":(name:"Unnamed",health:10.0)}}"#,
+                Player::type_path()
+            )
+        );
+        let value = deserialize(&serialized).unwrap();
+        assert_eq!(Player::from_reflect(&*value).unwrap(), expected);
+
+        assert!(deserialize(r#"{"game::Hero@3": (name: "Ferris", health: 10.0)}"#).is_err());
+        assert!(deserialize(r#"{"game::Hero@one": (hp: 10.0)}"#).is_err());
+
+        let type_table = TypeTable::from_registry(&registry);
+        assert!(type_table
+            .id(&format!("{}@2", Player::type_path()))
+            .is_some());
+        let serializer = ReflectSerializer::with_type_table(&expected, &registry, &type_table);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                UntypedReflectDeserializer::with_type_table(&registry, &type_table),
+                &bytes,
+            )
+            .unwrap();
+        assert_eq!(Player::from_reflect(&*value).unwrap(), expected);
+    }
 }
@@ -0,0 +1,599 @@
+use crate::serde::{versioned_type_path, SerializationData};
+use crate::{
+    ArrayInfo, EnumInfo, ListInfo, MapInfo, NamedField, ReflectSerialize, SetInfo, StructInfo,
+    TupleInfo, TupleStructInfo, TypeInfo, TypeRegistry, UnnamedField, VariantInfo,
+};
+use serde_json::{json, Map, Value};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// The JSON Schema dialect of the generated documents.
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a [JSON Schema] describing the JSON produced by [`ReflectSerializer`] for any of the
+/// types registered in `registry`.
+///
+/// The document accepts a map with a single entry, from the type path of a registered type to its
+/// serialized value. Like in the serialized data, the type path is followed by the version of the
+/// type if it has [migrations], see [`versioned_type_path`]. Each registered type is described in
+/// the `$defs` of the document, keyed by its type path, so that it can also be referenced from
+/// other documents.
+///
+/// Fields marked with `#[reflect(skip_serializing)]` are left out, and the documentation of types,
+/// fields and variants is included as descriptions when the `documentation` feature is enabled.
+///
+/// Types that register [`ReflectSerialize`] are serialized with their own [`Serialize`]
+/// implementation, so their shape is only known for primitives: others accept any value.
+/// Types that can't be serialized, like value types without [`ReflectSerialize`], accept none.
+/// Maps are described as JSON objects, which is how `serde_json` serializes them when their keys
+/// are strings or integers.
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypePath, TypeRegistry, serde::registry_json_schema};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let schema = registry_json_schema(&registry);
+/// let player = &schema["$defs"][Player::type_path()];
+/// assert_eq!(player["properties"]["health"]["type"], "number");
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [migrations]: crate::serde::TypeMigrations
+/// [`Serialize`]: ::serde::Serialize
+pub fn registry_json_schema(registry: &TypeRegistry) -> Value {
+    let mut generator = SchemaGenerator::new(registry);
+    let mut type_paths: Vec<_> = registry
+        .iter()
+        .map(|registration| {
+            let type_info = registration.type_info();
+            (
+                versioned_type_path(type_info, registry),
+                type_info.type_path(),
+            )
+        })
+        .collect();
+    type_paths.sort_unstable();
+    for registration in registry.iter() {
+        generator.define(registration.type_id(), registration.type_info());
+    }
+    let entries: Vec<_> = type_paths
+        .into_iter()
+        .map(|(key, type_path)| {
+            json!({
+                "type": "object",
+                "properties": { &*key: { "$ref": definition_ref(type_path) } },
+                "required": [key],
+                "additionalProperties": false,
+            })
+        })
+        .collect();
+    json!({
+        "$schema": SCHEMA_DIALECT,
+        "oneOf": entries,
+        "$defs": generator.definitions,
+    })
+}
+
+/// Generates a [JSON Schema] describing the JSON produced by [`TypedReflectSerializer`] for the
+/// registered type with the given [`TypeId`].
+///
+/// Returns `None` if the type isn't registered in `registry`.
+/// See [`registry_json_schema`] for how types are described.
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+pub fn type_json_schema(registry: &TypeRegistry, type_id: TypeId) -> Option<Value> {
+    let registration = registry.get(type_id)?;
+    let mut generator = SchemaGenerator::new(registry);
+    let root = generator.schema_for(type_id, registration.type_info().type_path());
+    let mut document = Map::new();
+    document.insert("$schema".to_string(), SCHEMA_DIALECT.into());
+    match root {
+        Value::Object(root) => document.extend(root),
+        root => {
+            document.insert("allOf".to_string(), json!([root]));
+        }
+    }
+    if !generator.definitions.is_empty() {
+        document.insert("$defs".to_string(), generator.definitions.into());
+    }
+    Some(document.into())
+}
+
+/// Returns the reference to the definition of the type with the given type path.
+///
+/// The type path is escaped as a JSON Pointer, then percent-encoded to be a valid URI fragment.
+fn definition_ref(type_path: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    for c in type_path.chars() {
+        match c {
+            '~' => reference.push_str("~0"),
+            '/' => reference.push_str("~1"),
+            c if c.is_ascii_alphanumeric() || "-._!$&'()*+,;=:@".contains(c) => reference.push(c),
+            c => {
+                let mut buffer = [0; 4];
+                for byte in c.encode_utf8(&mut buffer).bytes() {
+                    reference.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    reference
+}
+
+/// Returns the schema of primitive types, whose serialized form is known.
+fn primitive_schema(type_id: TypeId) -> Option<Value> {
+    macro_rules! is_any {
+        ($($ty: ty),*) => {
+            false $(|| type_id == TypeId::of::<$ty>())*
+        };
+    }
+
+    Some(if is_any!(bool) {
+        json!({ "type": "boolean" })
+    } else if is_any!(u8, u16, u32, u64, u128, usize) {
+        json!({ "type": "integer", "minimum": 0 })
+    } else if is_any!(i8, i16, i32, i64, i128, isize) {
+        json!({ "type": "integer" })
+    } else if is_any!(f32, f64) {
+        json!({ "type": "number" })
+    } else if is_any!(char) {
+        json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+    } else if is_any!(String, &'static str, Cow<'static, str>, PathBuf, OsString) {
+        json!({ "type": "string" })
+    } else {
+        return None;
+    })
+}
+
+/// Adds the `description` of a schema, when documentation is available.
+#[cfg(feature = "documentation")]
+fn with_docs(mut schema: Value, docs: Option<&str>) -> Value {
+    if let (Some(docs), Value::Object(schema)) = (docs, &mut schema) {
+        schema.insert("description".to_string(), docs.trim().into());
+    }
+    schema
+}
+
+struct SchemaGenerator<'a> {
+    registry: &'a TypeRegistry,
+    definitions: Map<String, Value>,
+}
+
+impl<'a> SchemaGenerator<'a> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            definitions: Map::new(),
+        }
+    }
+
+    /// Returns the schema of a value of the given type, referencing its definition if it has one.
+    fn schema_for(&mut self, type_id: TypeId, type_path: &str) -> Value {
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+        match self.registry.get(type_id) {
+            Some(registration) => {
+                self.define(type_id, registration.type_info());
+                json!({ "$ref": definition_ref(type_path) })
+            }
+            // Unregistered types can't be serialized.
+            None => json!({ "not": {}, "description": format!("`{type_path}` is not registered") }),
+        }
+    }
+
+    /// Adds the definition of a registered type, unless it was already added.
+    fn define(&mut self, type_id: TypeId, type_info: &TypeInfo) {
+        let type_path = type_info.type_path();
+        if self.definitions.contains_key(type_path) {
+            return;
+        }
+        // Insert a placeholder first, so that recursive types terminate.
+        self.definitions.insert(type_path.to_string(), Value::Null);
+        let schema = self.type_schema(type_id, type_info);
+        #[cfg(feature = "documentation")]
+        let schema = with_docs(schema, type_info.docs());
+        let mut schema = schema;
+        if let Value::Object(schema) = &mut schema {
+            schema
+                .entry("title")
+                .or_insert_with(|| type_info.type_path_table().short_path().into());
+        }
+        self.definitions.insert(type_path.to_string(), schema);
+    }
+
+    fn type_schema(&mut self, type_id: TypeId, type_info: &TypeInfo) -> Value {
+        if let Some(schema) = primitive_schema(type_id) {
+            return schema;
+        }
+        let registration = self.registry.get(type_id);
+        // Types with their own `Serialize` implementation are serialized with it.
+        if registration
+            .is_some_and(|registration| registration.data::<ReflectSerialize>().is_some())
+        {
+            return json!({});
+        }
+        let serialization_data = registration.and_then(|registration| registration.data());
+        match type_info {
+            TypeInfo::Struct(info) => self.struct_schema(info, serialization_data),
+            TypeInfo::TupleStruct(info) => self.tuple_struct_schema(info, serialization_data),
+            TypeInfo::Tuple(info) => self.tuple_schema(info),
+            TypeInfo::List(info) => self.list_schema(info),
+            TypeInfo::Array(info) => self.array_schema(info),
+            TypeInfo::Map(info) => self.map_schema(info),
+            TypeInfo::Set(info) => self.set_schema(info),
+            TypeInfo::Enum(info) => self.enum_schema(info),
+            // Value types can only be serialized through `ReflectSerialize`.
+            TypeInfo::Value(_) => json!({ "not": {} }),
+        }
+    }
+
+    fn named_fields_schema<'f>(&mut self, fields: impl Iterator<Item = &'f NamedField>) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in fields {
+            let schema = self.schema_for(field.type_id(), field.type_path());
+            #[cfg(feature = "documentation")]
+            let schema = with_docs(schema, field.docs());
+            properties.insert(field.name().to_string(), schema);
+            required.push(Value::from(field.name()));
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    fn unnamed_fields_schema<'f>(
+        &mut self,
+        fields: impl Iterator<Item = &'f UnnamedField>,
+    ) -> Value {
+        let items: Vec<_> = fields
+            .map(|field| {
+                let schema = self.schema_for(field.type_id(), field.type_path());
+                #[cfg(feature = "documentation")]
+                let schema = with_docs(schema, field.docs());
+                schema
+            })
+            .collect();
+        json!({
+            "type": "array",
+            "minItems": items.len(),
+            "maxItems": items.len(),
+            "prefixItems": items,
+        })
+    }
+
+    fn struct_schema(
+        &mut self,
+        info: &StructInfo,
+        serialization_data: Option<&SerializationData>,
+    ) -> Value {
+        let fields = info.iter().enumerate().filter_map(|(index, field)| {
+            let skipped = serialization_data.is_some_and(|data| data.is_field_skipped(index));
+            (!skipped).then_some(field)
+        });
+        self.named_fields_schema(fields)
+    }
+
+    fn tuple_struct_schema(
+        &mut self,
+        info: &TupleStructInfo,
+        serialization_data: Option<&SerializationData>,
+    ) -> Value {
+        let fields = info.iter().enumerate().filter_map(|(index, field)| {
+            let skipped = serialization_data.is_some_and(|data| data.is_field_skipped(index));
+            (!skipped).then_some(field)
+        });
+        self.unnamed_fields_schema(fields)
+    }
+
+    fn tuple_schema(&mut self, info: &TupleInfo) -> Value {
+        self.unnamed_fields_schema(info.iter())
+    }
+
+    fn list_schema(&mut self, info: &ListInfo) -> Value {
+        json!({
+            "type": "array",
+            "items": self.schema_for(info.item_type_id(), info.item_type_path_table().path()),
+        })
+    }
+
+    fn array_schema(&mut self, info: &ArrayInfo) -> Value {
+        json!({
+            "type": "array",
+            "items": self.schema_for(info.item_type_id(), info.item_type_path_table().path()),
+            "minItems": info.capacity(),
+            "maxItems": info.capacity(),
+        })
+    }
+
+    fn map_schema(&mut self, info: &MapInfo) -> Value {
+        json!({
+            "type": "object",
+            "additionalProperties":
+                self.schema_for(info.value_type_id(), info.value_type_path_table().path()),
+        })
+    }
+
+    fn set_schema(&mut self, info: &SetInfo) -> Value {
+        json!({
+            "type": "array",
+            "items": self.schema_for(info.value_type_id(), info.value_type_path_table().path()),
+            "uniqueItems": true,
+        })
+    }
+
+    fn enum_schema(&mut self, info: &EnumInfo) -> Value {
+        let type_path_table = info.type_path_table();
+        // Options are serialized as their value, or as `null`.
+        if type_path_table.module_path() == Some("core::option")
+            && type_path_table.ident() == Some("Option")
+        {
+            let some = match info.variant("Some") {
+                Some(VariantInfo::Tuple(variant)) => variant
+                    .field_at(0)
+                    .map(|field| self.schema_for(field.type_id(), field.type_path())),
+                _ => None,
+            };
+            return json!({ "oneOf": [{ "type": "null" }, some.unwrap_or(json!({}))] });
+        }
+
+        let variants: Vec<_> = info
+            .iter()
+            .map(|variant| {
+                let name = variant.name();
+                let schema = match variant {
+                    VariantInfo::Unit(_) => json!({ "const": name }),
+                    VariantInfo::Tuple(variant) => {
+                        let value = if variant.field_len() == 1 {
+                            let field = variant.field_at(0).unwrap();
+                            self.schema_for(field.type_id(), field.type_path())
+                        } else {
+                            self.unnamed_fields_schema(variant.iter())
+                        };
+                        externally_tagged(name, value)
+                    }
+                    VariantInfo::Struct(variant) => {
+                        let value = self.named_fields_schema(variant.iter());
+                        externally_tagged(name, value)
+                    }
+                };
+                #[cfg(feature = "documentation")]
+                let schema = with_docs(schema, variant.docs());
+                schema
+            })
+            .collect();
+        json!({ "oneOf": variants })
+    }
+}
+
+/// The schema of an enum variant with fields, which serde represents as a map from its name to
+/// its fields.
+fn externally_tagged(name: &str, value: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: value },
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{ReflectSerializer, TypedReflectSerializer, UntypedReflectDeserializer};
+    use crate::{FromReflect, Reflect, TypePath};
+    use bevy_utils::HashMap;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        #[reflect(skip_serializing)]
+        cached: u32,
+        position: Position,
+        inventory: Vec<Item>,
+        stats: HashMap<String, f32>,
+        pet: Option<u8>,
+    }
+
+    #[derive(Reflect)]
+    struct Position(f32, f32, f32);
+
+    #[derive(Reflect)]
+    enum Item {
+        Empty,
+        Coins(u32),
+        Potion { strength: f32 },
+        Pair(u8, u8),
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Position>();
+        registry.register::<Item>();
+        registry.register::<Vec<Item>>();
+        registry.register::<HashMap<String, f32>>();
+        registry.register::<Option<u8>>();
+        registry
+    }
+
+    fn reference<T: TypePath>() -> Value {
+        json!({ "$ref": definition_ref(T::type_path()) })
+    }
+
+    #[test]
+    fn should_describe_registered_types() {
+        let schema = registry_json_schema(&registry());
+        let defs = &schema["$defs"];
+
+        let player = &defs[Player::type_path()];
+        assert_eq!(player["type"], "object");
+        assert_eq!(player["title"], "Player");
+        assert_eq!(
+            player["required"],
+            json!(["name", "position", "inventory", "stats", "pet"])
+        );
+        assert_eq!(player["properties"]["name"], json!({ "type": "string" }));
+        assert!(player["properties"].get("cached").is_none());
+        assert_eq!(player["properties"]["position"], reference::<Position>());
+        assert_eq!(player["properties"]["inventory"], reference::<Vec<Item>>());
+
+        assert_eq!(
+            defs[Position::type_path()]["prefixItems"],
+            json!([{ "type": "number" }, { "type": "number" }, { "type": "number" }])
+        );
+        assert_eq!(defs[<Vec<Item>>::type_path()]["items"], reference::<Item>());
+        assert_eq!(
+            defs[<HashMap<String, f32>>::type_path()]["additionalProperties"],
+            json!({ "type": "number" })
+        );
+        assert_eq!(
+            defs[<Option<u8>>::type_path()]["oneOf"],
+            json!([{ "type": "null" }, { "type": "integer", "minimum": 0 }])
+        );
+
+        let variants = &defs[Item::type_path()]["oneOf"];
+        assert_eq!(variants[0], json!({ "const": "Empty" }));
+        assert_eq!(
+            variants[1]["properties"]["Coins"],
+            json!({ "type": "integer", "minimum": 0 })
+        );
+        assert_eq!(
+            variants[2]["properties"]["Potion"]["required"],
+            json!(["strength"])
+        );
+        assert_eq!(variants[3]["properties"]["Pair"]["maxItems"], 2);
+
+        let entries = schema["oneOf"].as_array().unwrap();
+        assert_eq!(entries.len(), registry().iter().count());
+        assert!(entries
+            .iter()
+            .any(|entry| entry["properties"][Player::type_path()] == reference::<Player>()));
+    }
+
+    #[test]
+    fn should_match_serialized_output() {
+        let registry = registry();
+        let player = Player {
+            name: String::from("Ferris"),
+            cached: 3,
+            position: Position(1.0, 2.0, 3.0),
+            inventory: vec![
+                Item::Empty,
+                Item::Coins(5),
+                Item::Potion { strength: 0.5 },
+                Item::Pair(1, 2),
+            ],
+            stats: HashMap::default(),
+            pet: None,
+        };
+
+        let serialized = serde_json::to_value(ReflectSerializer::new(&player, &registry)).unwrap();
+        let value = &serialized[Player::type_path()];
+        let schema = registry_json_schema(&registry);
+        let player_schema = &schema["$defs"][Player::type_path()];
+        let mut fields: Vec<_> = value.as_object().unwrap().keys().collect();
+        let mut properties: Vec<_> = player_schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect();
+        fields.sort();
+        properties.sort();
+        assert_eq!(fields, properties);
+        assert_eq!(value["inventory"][0], "Empty");
+        assert_eq!(value["inventory"][1], json!({ "Coins": 5 }));
+        assert_eq!(value["pet"], Value::Null);
+
+        let typed = serde_json::to_value(TypedReflectSerializer::new(&player, &registry)).unwrap();
+        assert_eq!(&typed, value);
+    }
+
+    #[test]
+    fn should_match_versioned_serialized_output() {
+        #[derive(Reflect, PartialEq, Debug)]
+        struct Versioned {
+            health: f32,
+        }
+
+        #[derive(Reflect)]
+        struct VersionedV0 {
+            hp: f32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Versioned>();
+        registry.register_migration::<Versioned, VersionedV0>(0, |value| value);
+
+        let value = Versioned { health: 10.0 };
+        let serialized = serde_json::to_value(ReflectSerializer::new(&value, &registry)).unwrap();
+        let (key, data) = serialized.as_object().unwrap().iter().next().unwrap();
+        assert_eq!(*key, format!("{}@1", Versioned::type_path()));
+
+        let schema = registry_json_schema(&registry);
+        let entry = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["required"] == json!([key]))
+            .unwrap();
+        assert_eq!(entry["properties"][key], reference::<Versioned>());
+        let definition = &schema["$defs"][Versioned::type_path()];
+        assert_eq!(definition["required"], json!(["health"]));
+        assert_eq!(data["health"], 10.0);
+
+        let deserialized = UntypedReflectDeserializer::new(&registry)
+            .deserialize(serialized.clone())
+            .unwrap();
+        assert_eq!(
+            Versioned::from_reflect(&*deserialized).unwrap(),
+            Versioned { health: 10.0 }
+        );
+    }
+
+    #[test]
+    fn should_generate_type_document() {
+        let registry = registry();
+        let schema = type_json_schema(&registry, TypeId::of::<Position>()).unwrap();
+        assert_eq!(schema["$schema"], SCHEMA_DIALECT);
+        assert_eq!(schema["$ref"], reference::<Position>()["$ref"]);
+        assert_eq!(schema["$defs"][Position::type_path()]["type"], "array");
+
+        let schema = type_json_schema(&registry, TypeId::of::<Player>()).unwrap();
+        assert!(schema["$defs"].get(Item::type_path()).is_some());
+
+        let schema = type_json_schema(&registry, TypeId::of::<f32>()).unwrap();
+        assert_eq!(schema["type"], "number");
+        assert!(schema.get("$defs").is_none());
+
+        #[derive(Reflect)]
+        struct Unregistered;
+        assert!(type_json_schema(&registry, TypeId::of::<Unregistered>()).is_none());
+    }
+
+    #[test]
+    fn should_escape_definition_refs() {
+        assert_eq!(
+            definition_ref("a::B<c::D>"),
+            "#/$defs/a::B%3Cc::D%3E".to_string()
+        );
+        assert_eq!(definition_ref("[u8; 2]"), "#/$defs/%5Bu8;%202%5D");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
+    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Set, Struct, Tuple, TupleStruct,
     TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
 use serde::ser::{
@@ -11,7 +11,7 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{versioned_type_path, SerializationData, TypeTable};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -49,15 +49,43 @@ fn get_serializable<'a, E: Error>(
 /// 1. `type`: The _full_ [type path]
 /// 2. `value`: The serialized value of the reflected type
 ///
+/// With a [`TypeTable`], see [`ReflectSerializer::with_type_table`], the data is compact instead:
+/// a tuple of the ID of the type in the table and of the value, serialized without field names.
+///
 /// [type path]: crate::TypePath::type_path
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    type_table: Option<&'a TypeTable>,
 }
 
 impl<'a> ReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        ReflectSerializer { value, registry }
+        ReflectSerializer {
+            value,
+            registry,
+            type_table: None,
+        }
+    }
+
+    /// Creates a serializer writing the ID of the type of `value` in `type_table` instead of its
+    /// type path, and the value in the compact form of [`TypedReflectSerializer::compact`].
+    ///
+    /// Serializing fails if the type of `value` isn't in `type_table`.
+    /// The data can be deserialized with [`UntypedReflectDeserializer::with_type_table`], given
+    /// the same table.
+    ///
+    /// [`UntypedReflectDeserializer::with_type_table`]: crate::serde::UntypedReflectDeserializer::with_type_table
+    pub fn with_type_table(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        type_table: &'a TypeTable,
+    ) -> Self {
+        ReflectSerializer {
+            value,
+            registry,
+            type_table: Some(type_table),
+        }
     }
 }
 
@@ -66,26 +94,36 @@ impl<'a> Serialize for ReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(
-            self.value
-                .get_represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        Error::custom(format_args!(
-                            "cannot serialize dynamic value without represented type: {}",
-                            self.value.reflect_type_path()
-                        ))
-                    } else {
-                        Error::custom(format_args!(
-                            "cannot get type info for {}",
-                            self.value.reflect_type_path()
-                        ))
-                    }
-                })?
-                .type_path(),
-            &TypedReflectSerializer::new(self.value, self.registry),
-        )?;
+        let type_info = self.value.get_represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                Error::custom(format_args!(
+                    "cannot serialize dynamic value without represented type: {}",
+                    self.value.reflect_type_path()
+                ))
+            } else {
+                Error::custom(format_args!(
+                    "cannot get type info for {}",
+                    self.value.reflect_type_path()
+                ))
+            }
+        })?;
+        let type_path = versioned_type_path(type_info, self.registry);
+
+        let Some(type_table) = self.type_table else {
+            let mut state = serializer.serialize_map(Some(1))?;
+            state.serialize_entry(
+                &*type_path,
+                &TypedReflectSerializer::new(self.value, self.registry),
+            )?;
+            return state.end();
+        };
+
+        let id = type_table.id(&type_path).ok_or_else(|| {
+            Error::custom(format_args!("type `{type_path}` is not in the type table"))
+        })?;
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&id)?;
+        state.serialize_element(&TypedReflectSerializer::compact(self.value, self.registry))?;
         state.end()
     }
 }
@@ -95,11 +133,41 @@ impl<'a> Serialize for ReflectSerializer<'a> {
 pub struct TypedReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    /// Whether structs and struct variants are serialized without their field names.
+    compact: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        TypedReflectSerializer { value, registry }
+        TypedReflectSerializer {
+            value,
+            registry,
+            compact: false,
+        }
+    }
+
+    /// Creates a serializer writing structs and struct variants as tuples, without the names of
+    /// their fields.
+    ///
+    /// Binary formats like `bincode` already leave field names out, but self-describing formats
+    /// like JSON don't. The data can be deserialized with a [`TypedReflectDeserializer`], for
+    /// formats that support deserializing structs from sequences.
+    ///
+    /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+    pub fn compact(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        TypedReflectSerializer {
+            value,
+            registry,
+            compact: true,
+        }
+    }
+
+    fn with_compact(value: &'a dyn Reflect, registry: &'a TypeRegistry, compact: bool) -> Self {
+        TypedReflectSerializer {
+            value,
+            registry,
+            compact,
+        }
     }
 }
 
@@ -118,36 +186,49 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
             ReflectRef::Struct(value) => StructSerializer {
                 struct_value: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(value) => TupleStructSerializer {
                 tuple_struct: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Tuple(value) => TupleSerializer {
                 tuple: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::List(value) => ListSerializer {
                 list: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Array(value) => ArraySerializer {
                 array: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Map(value) => MapSerializer {
                 map: value,
                 registry: self.registry,
+                compact: self.compact,
+            }
+            .serialize(serializer),
+            ReflectRef::Set(value) => SetSerializer {
+                set: value,
+                registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Value(_) => Err(serializable.err().unwrap()),
@@ -174,6 +255,17 @@ impl<'a> Serialize for ReflectValueSerializer<'a> {
 pub struct StructSerializer<'a> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> StructSerializer<'a> {
+    pub fn new(struct_value: &'a dyn Struct, registry: &'a TypeRegistry) -> Self {
+        StructSerializer {
+            struct_value,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for StructSerializer<'a> {
@@ -205,6 +297,21 @@ impl<'a> Serialize for StructSerializer<'a> {
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
         let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
+        if self.compact {
+            let mut state =
+                serializer.serialize_tuple(self.struct_value.field_len() - ignored_len)?;
+            for (index, value) in self.struct_value.iter_fields().enumerate() {
+                if serialization_data
+                    .map(|data| data.is_field_skipped(index))
+                    .unwrap_or(false)
+                {
+                    continue;
+                }
+                state.serialize_element(&TypedReflectSerializer::compact(value, self.registry))?;
+            }
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct(
             struct_info.type_path_table().ident().unwrap(),
             self.struct_value.field_len() - ignored_len,
@@ -218,7 +325,10 @@ impl<'a> Serialize for StructSerializer<'a> {
                 continue;
             }
             let key = struct_info.field_at(index).unwrap().name();
-            state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(
+                key,
+                &TypedReflectSerializer::with_compact(value, self.registry, self.compact),
+            )?;
         }
         state.end()
     }
@@ -227,6 +337,17 @@ impl<'a> Serialize for StructSerializer<'a> {
 pub struct TupleStructSerializer<'a> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> TupleStructSerializer<'a> {
+    pub fn new(tuple_struct: &'a dyn TupleStruct, registry: &'a TypeRegistry) -> Self {
+        TupleStructSerializer {
+            tuple_struct,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for TupleStructSerializer<'a> {
@@ -270,7 +391,11 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             {
                 continue;
             }
-            state.serialize_field(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_field(&TypedReflectSerializer::with_compact(
+                value,
+                self.registry,
+                self.compact,
+            ))?;
         }
         state.end()
     }
@@ -279,6 +404,17 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
 pub struct EnumSerializer<'a> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> EnumSerializer<'a> {
+    pub fn new(enum_value: &'a dyn Enum, registry: &'a TypeRegistry) -> Self {
+        EnumSerializer {
+            enum_value,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for EnumSerializer<'a> {
@@ -335,6 +471,22 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     }
                 };
 
+                if self.compact {
+                    let mut state = serializer.serialize_tuple_variant(
+                        enum_name,
+                        variant_index,
+                        variant_name,
+                        field_len,
+                    )?;
+                    for field in self.enum_value.iter_fields() {
+                        state.serialize_field(&TypedReflectSerializer::compact(
+                            field.value(),
+                            self.registry,
+                        ))?;
+                    }
+                    return state.end();
+                }
+
                 let mut state = serializer.serialize_struct_variant(
                     enum_name,
                     variant_index,
@@ -345,7 +497,11 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     let field_info = struct_info.field_at(index).unwrap();
                     state.serialize_field(
                         field_info.name(),
-                        &TypedReflectSerializer::new(field.value(), self.registry),
+                        &TypedReflectSerializer::with_compact(
+                            field.value(),
+                            self.registry,
+                            self.compact,
+                        ),
                     )?;
                 }
                 state.end()
@@ -356,13 +512,17 @@ impl<'a> Serialize for EnumSerializer<'a> {
                 if type_info.type_path_table().module_path() == Some("core::option")
                     && type_info.type_path_table().ident() == Some("Option")
                 {
-                    serializer.serialize_some(&TypedReflectSerializer::new(field, self.registry))
+                    serializer.serialize_some(&TypedReflectSerializer::with_compact(
+                        field,
+                        self.registry,
+                        self.compact,
+                    ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new(field, self.registry),
+                        &TypedReflectSerializer::with_compact(field, self.registry, self.compact),
                     )
                 }
             }
@@ -374,9 +534,10 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     field_len,
                 )?;
                 for field in self.enum_value.iter_fields() {
-                    state.serialize_field(&TypedReflectSerializer::new(
+                    state.serialize_field(&TypedReflectSerializer::with_compact(
                         field.value(),
                         self.registry,
+                        self.compact,
                     ))?;
                 }
                 state.end()
@@ -388,6 +549,17 @@ impl<'a> Serialize for EnumSerializer<'a> {
 pub struct TupleSerializer<'a> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> TupleSerializer<'a> {
+    pub fn new(tuple: &'a dyn Tuple, registry: &'a TypeRegistry) -> Self {
+        TupleSerializer {
+            tuple,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for TupleSerializer<'a> {
@@ -398,7 +570,11 @@ impl<'a> Serialize for TupleSerializer<'a> {
         let mut state = serializer.serialize_tuple(self.tuple.field_len())?;
 
         for value in self.tuple.iter_fields() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::with_compact(
+                value,
+                self.registry,
+                self.compact,
+            ))?;
         }
         state.end()
     }
@@ -407,6 +583,17 @@ impl<'a> Serialize for TupleSerializer<'a> {
 pub struct MapSerializer<'a> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> MapSerializer<'a> {
+    pub fn new(map: &'a dyn Map, registry: &'a TypeRegistry) -> Self {
+        MapSerializer {
+            map,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for MapSerializer<'a> {
@@ -417,17 +604,61 @@ impl<'a> Serialize for MapSerializer<'a> {
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
             state.serialize_entry(
-                &TypedReflectSerializer::new(key, self.registry),
-                &TypedReflectSerializer::new(value, self.registry),
+                &TypedReflectSerializer::with_compact(key, self.registry, self.compact),
+                &TypedReflectSerializer::with_compact(value, self.registry, self.compact),
             )?;
         }
         state.end()
     }
 }
 
+pub struct SetSerializer<'a> {
+    pub set: &'a dyn Set,
+    pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> SetSerializer<'a> {
+    pub fn new(set: &'a dyn Set, registry: &'a TypeRegistry) -> Self {
+        SetSerializer {
+            set,
+            registry,
+            compact: false,
+        }
+    }
+}
+
+impl<'a> Serialize for SetSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.set.len()))?;
+        for value in self.set.iter() {
+            state.serialize_element(&TypedReflectSerializer::with_compact(
+                value,
+                self.registry,
+                self.compact,
+            ))?;
+        }
+        state.end()
+    }
+}
+
 pub struct ListSerializer<'a> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> ListSerializer<'a> {
+    pub fn new(list: &'a dyn List, registry: &'a TypeRegistry) -> Self {
+        ListSerializer {
+            list,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for ListSerializer<'a> {
@@ -437,7 +668,11 @@ impl<'a> Serialize for ListSerializer<'a> {
     {
         let mut state = serializer.serialize_seq(Some(self.list.len()))?;
         for value in self.list.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::with_compact(
+                value,
+                self.registry,
+                self.compact,
+            ))?;
         }
         state.end()
     }
@@ -446,6 +681,17 @@ impl<'a> Serialize for ListSerializer<'a> {
 pub struct ArraySerializer<'a> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
+    compact: bool,
+}
+
+impl<'a> ArraySerializer<'a> {
+    pub fn new(array: &'a dyn Array, registry: &'a TypeRegistry) -> Self {
+        ArraySerializer {
+            array,
+            registry,
+            compact: false,
+        }
+    }
 }
 
 impl<'a> Serialize for ArraySerializer<'a> {
@@ -455,7 +701,11 @@ impl<'a> Serialize for ArraySerializer<'a> {
     {
         let mut state = serializer.serialize_tuple(self.array.len())?;
         for value in self.array.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(&TypedReflectSerializer::with_compact(
+                value,
+                self.registry,
+                self.compact,
+            ))?;
         }
         state.end()
     }
@@ -463,7 +713,7 @@ impl<'a> Serialize for ArraySerializer<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::serde::ReflectSerializer;
+    use crate::serde::{EnumSerializer, ListSerializer, ReflectSerializer, StructSerializer};
     use crate::{self as bevy_reflect, Struct};
     use crate::{Reflect, ReflectSerialize, TypeRegistry};
     use bevy_utils::HashMap;
@@ -919,4 +1169,22 @@ mod tests {
 
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn should_serialize_with_nested_serializers() {
+        let registry = get_registry();
+        let value = SomeStruct { foo: 123 };
+        let output = ron::ser::to_string(&StructSerializer::new(&value, &registry)).unwrap();
+        assert_eq!("(foo:123)", output);
+
+        let value = vec![1_i32, 2, 3];
+        let output = ron::ser::to_string(&ListSerializer::new(&value, &registry)).unwrap();
+        assert_eq!("[1,2,3]", output);
+
+        let value = SomeEnum::Struct {
+            foo: String::from("bar"),
+        };
+        let output = ron::ser::to_string(&EnumSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(r#"Struct(foo:"bar")"#, output);
+    }
 }
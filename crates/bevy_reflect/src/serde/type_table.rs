@@ -0,0 +1,152 @@
+use crate::{serde::versioned_type_path, TypeRegistry};
+use bevy_utils::HashMap;
+use serde::de::Error;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A table giving each of its types a numeric ID, used to identify types in compact serialization.
+///
+/// [`ReflectSerializer::with_type_table`] writes the ID of the serialized type instead of its
+/// [type path], and [`UntypedReflectDeserializer::with_type_table`] reads it back. IDs are only
+/// meaningful for a given table, so the table should be serialized along with the data, for
+/// instance at the start of a save file. Its serialized form is the list of its type paths.
+///
+/// ```
+/// # use bevy_reflect::serde::{ReflectSerializer, TypeTable, UntypedReflectDeserializer};
+/// # use bevy_reflect::{FromReflect, Reflect, TypePath, TypeRegistry};
+/// # use bincode::Options;
+/// # use serde::{de::DeserializeSeed, Deserialize};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let player = Player {
+///     name: "Ferris".to_string(),
+///     health: 10.0,
+/// };
+/// let mut type_table = TypeTable::default();
+/// type_table.insert(Player::type_path());
+///
+/// let serializer = ReflectSerializer::with_type_table(&player, &registry, &type_table);
+/// let options = bincode::DefaultOptions::new();
+/// let bytes = options.serialize(&(&type_table, serializer)).unwrap();
+///
+/// let mut deserializer = bincode::Deserializer::from_slice(&bytes, options);
+/// let type_table = TypeTable::deserialize(&mut deserializer).unwrap();
+/// let value = UntypedReflectDeserializer::with_type_table(&registry, &type_table)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Player::from_reflect(&*value).unwrap(), player);
+/// ```
+///
+/// [`ReflectSerializer::with_type_table`]: crate::serde::ReflectSerializer::with_type_table
+/// [`UntypedReflectDeserializer::with_type_table`]: crate::serde::UntypedReflectDeserializer::with_type_table
+/// [type path]: crate::TypePath::type_path
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeTable {
+    type_paths: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl TypeTable {
+    /// Creates a table of all the types registered in `registry`.
+    ///
+    /// Types are sorted by type path, so that registries with the same types give the same IDs.
+    /// Types with [migrations] are stored with their current version, see [`versioned_type_path`].
+    ///
+    /// [migrations]: crate::serde::TypeMigrations
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut type_paths: Vec<_> = registry
+            .iter()
+            .map(|registration| versioned_type_path(registration.type_info(), registry))
+            .collect();
+        type_paths.sort_unstable();
+        let mut table = Self::default();
+        for type_path in type_paths {
+            table.insert(&type_path);
+        }
+        table
+    }
+
+    /// Adds a type to the table, returning its ID.
+    ///
+    /// If the type is already in the table, its existing ID is returned.
+    pub fn insert(&mut self, type_path: &str) -> u32 {
+        if let Some(id) = self.ids.get(type_path) {
+            return *id;
+        }
+        let id = self.type_paths.len() as u32;
+        self.type_paths.push(type_path.to_string());
+        self.ids.insert(type_path.to_string(), id);
+        id
+    }
+
+    /// Returns the ID of the type with the given [type path], if it's in the table.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn id(&self, type_path: &str) -> Option<u32> {
+        self.ids.get(type_path).copied()
+    }
+
+    /// Returns the [type path] of the type with the given ID, if it's in the table.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn type_path(&self, id: u32) -> Option<&str> {
+        self.type_paths.get(id as usize).map(String::as_str)
+    }
+
+    /// Returns the number of types in the table.
+    pub fn len(&self) -> usize {
+        self.type_paths.len()
+    }
+
+    /// Returns `true` if the table has no types.
+    pub fn is_empty(&self) -> bool {
+        self.type_paths.is_empty()
+    }
+
+    /// Returns an iterator over the IDs and type paths of the table, ordered by ID.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.type_paths
+            .iter()
+            .enumerate()
+            .map(|(id, type_path)| (id as u32, type_path.as_str()))
+    }
+}
+
+impl Serialize for TypeTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.type_paths.len()))?;
+        for type_path in &self.type_paths {
+            state.serialize_element(type_path)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let type_paths = Vec::<String>::deserialize(deserializer)?;
+        let mut table = TypeTable::default();
+        for type_path in &type_paths {
+            let id = table.insert(type_path);
+            if id as usize != table.len() - 1 {
+                return Err(Error::custom(format_args!(
+                    "duplicate type in type table: `{type_path}`"
+                )));
+            }
+        }
+        Ok(table)
+    }
+}
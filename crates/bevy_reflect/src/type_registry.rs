@@ -1,4 +1,7 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::{Serializable, TypeMigrations},
+    Reflect, TypeInfo, TypePath, Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
 use downcast_rs::{impl_downcast, Downcast};
@@ -25,6 +28,7 @@ pub struct TypeRegistry {
     registrations: TypeIdMap<TypeRegistration>,
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
+    type_path_aliases: HashMap<String, TypeId>,
     ambiguous_names: HashSet<&'static str>,
 }
 
@@ -73,6 +77,7 @@ impl TypeRegistry {
             registrations: Default::default(),
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
+            type_path_aliases: Default::default(),
             ambiguous_names: Default::default(),
         }
     }
@@ -162,6 +167,65 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers `alias` as another [type path] for type `T`.
+    ///
+    /// Lookups by type path, such as [`TypeRegistry::get_with_type_path`], fall back to aliases,
+    /// so that data serialized before a type was renamed or moved can still be deserialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn register_type_alias<T: Reflect + TypePath>(&mut self, alias: impl Into<String>) {
+        if !self.registrations.contains_key(&TypeId::of::<T>()) {
+            panic!(
+                "attempted to call `TypeRegistry::register_type_alias` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            );
+        }
+        self.type_path_aliases
+            .insert(alias.into(), TypeId::of::<T>());
+    }
+
+    /// Registers a migration of the serialized data of type `T` from version `from_version`
+    /// to version `from_version + 1`.
+    ///
+    /// Data of version `from_version` is deserialized as type `Old`, which describes its shape,
+    /// before being passed to `migrate`. The returned value must have the shape of the next
+    /// version, which is type `T` itself for the latest migration.
+    /// `Old` is registered if it wasn't already.
+    ///
+    /// See [`TypeMigrations`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    pub fn register_migration<T, Old>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Box<dyn Reflect>) -> Box<dyn Reflect> + Send + Sync + 'static,
+    ) where
+        T: Reflect + TypePath,
+        Old: Reflect + GetTypeRegistration,
+    {
+        self.register::<Old>();
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migration` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+        if registration.data::<TypeMigrations>().is_none() {
+            registration.insert(TypeMigrations::default());
+        }
+        registration.data_mut::<TypeMigrations>().unwrap().insert(
+            from_version,
+            TypeId::of::<Old>(),
+            migrate,
+        );
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -184,11 +248,14 @@ impl TypeRegistry {
     /// given [type path].
     ///
     /// If no type with the given path has been registered, returns `None`.
+    /// [Aliases] are used if no type has the given path.
     ///
     /// [type path]: TypePath::type_path
+    /// [Aliases]: TypeRegistry::register_type_alias
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
         self.type_path_to_id
             .get(type_path)
+            .or_else(|| self.type_path_aliases.get(type_path))
             .and_then(|id| self.get(*id))
     }
 
@@ -196,11 +263,14 @@ impl TypeRegistry {
     /// the given [type path].
     ///
     /// If no type with the given type path has been registered, returns `None`.
+    /// [Aliases] are used if no type has the given path.
     ///
     /// [type path]: TypePath::type_path
+    /// [Aliases]: TypeRegistry::register_type_alias
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
         self.type_path_to_id
             .get(type_path)
+            .or_else(|| self.type_path_aliases.get(type_path))
             .cloned()
             .and_then(move |id| self.get_mut(id))
     }
@@ -0,0 +1,106 @@
+//! Remote inspection of a Bevy [`World`](bevy_ecs::world::World) over a JSON-RPC protocol.
+//!
+//! [`RemotePlugin`] listens for TCP connections, on which each line sent by a client is a
+//! [JSON-RPC 2.0] request, and each line sent by the server is a response or a notification.
+//! Requests are answered in the [`Last`] schedule with exclusive access to the world.
+//!
+//! Components and resources are accessed through reflection: they must be registered in the
+//! [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) with their
+//! [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent) or
+//! [`ReflectResource`](bevy_ecs::reflect::ReflectResource) type data. Types are named by their
+//! type path, or by their short type path, and values use the format of
+//! [`TypedReflectSerializer`](bevy_reflect::serde::TypedReflectSerializer). Entities are the
+//! number given by [`Entity::to_bits`](bevy_ecs::entity::Entity::to_bits).
+//!
+//! | Method | Params | Result |
+//! |-|-|-|
+//! | `bevy/entities` | | all the entities |
+//! | `bevy/list` | `entity`? | the reflected components of `entity`, or all the registered ones |
+//! | `bevy/get` | `entity`, `components` | an object of component values |
+//! | `bevy/insert` | `entity`, `components`: object of values | `null` |
+//! | `bevy/remove` | `entity`, `components` | `null` |
+//! | `bevy/spawn` | `components`: object of values | the new entity |
+//! | `bevy/despawn` | `entity` | `null` |
+//! | `bevy/query` | `components`, `with`?, `without`? | an array of `{ entity, components }` |
+//! | `bevy/list_resources` | | the registered resources |
+//! | `bevy/get_resource` | `resource` | the resource value |
+//! | `bevy/insert_resource` | `resource`, `value` | `null` |
+//! | `bevy/remove_resource` | `resource` | `null` |
+//! | `bevy/watch` | `entity`, `components` | the id of the watch |
+//! | `bevy/unwatch` | `watch` | `null` |
+//!
+//! A watch sends a [`CHANGED_NOTIFICATION`] with the `watch`, the `entity`, an object of the
+//! `components` that were added or changed, and an array of the `removed` ones, at the end of
+//! each frame in which one of the watched components changed. The first notification holds every
+//! watched component of the entity. Once the entity is despawned, the notification has
+//! `despawned: true` instead, and the watch is dropped.
+//!
+//! ```text
+//! --> {"jsonrpc": "2.0", "id": 1, "method": "bevy/get", "params": {"entity": 4294967296, "components": ["Transform"]}}
+//! <-- {"jsonrpc": "2.0", "id": 1, "result": {"bevy_transform::components::transform::Transform": {...}}}
+//! ```
+//!
+//! The protocol gives full access to the world, so the server should only listen on a local
+//! address.
+//!
+//! [JSON-RPC 2.0]: https://www.jsonrpc.org/specification
+
+mod methods;
+mod protocol;
+mod server;
+
+pub use protocol::*;
+pub use server::RemoteServer;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_utils::tracing::error;
+use methods::{process_remote_requests, RemoteWatches};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+/// The port [`RemotePlugin`] listens on by default.
+pub const DEFAULT_PORT: u16 = 15702;
+
+/// The method of the notifications sent by watches.
+pub const CHANGED_NOTIFICATION: &str = "bevy/changed";
+
+/// Plugin that lets clients inspect and edit the world over a JSON-RPC protocol.
+///
+/// See the [crate documentation](crate) for the protocol.
+pub struct RemotePlugin {
+    /// The address to listen on, which is `127.0.0.1:15702` by default.
+    ///
+    /// Use port `0` to let the system pick one, which can be read from
+    /// [`RemoteServer::local_addr`].
+    pub address: SocketAddr,
+}
+
+impl RemotePlugin {
+    /// Creates a plugin listening on `address`.
+    pub fn with_address(address: impl Into<SocketAddr>) -> Self {
+        Self {
+            address: address.into(),
+        }
+    }
+}
+
+impl Default for RemotePlugin {
+    fn default() -> Self {
+        Self::with_address((IpAddr::V4(Ipv4Addr::LOCALHOST), DEFAULT_PORT))
+    }
+}
+
+impl Plugin for RemotePlugin {
+    fn build(&self, app: &mut App) {
+        match RemoteServer::bind(self.address) {
+            Ok(server) => {
+                app.insert_resource(server)
+                    .init_resource::<RemoteWatches>()
+                    .add_systems(Last, process_remote_requests);
+            }
+            Err(err) => error!(
+                "Failed to start the remote server on {}. {}",
+                self.address, err
+            ),
+        }
+    }
+}
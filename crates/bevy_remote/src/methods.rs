@@ -0,0 +1,553 @@
+use crate::{
+    error_codes, server::RemoteConnection, RemoteError, RemoteRequest, RemoteResponse,
+    RemoteServer, CHANGED_NOTIFICATION,
+};
+use bevy_ecs::{
+    component::{ComponentId, Tick},
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    system::Resource,
+    world::{EntityRef, Mut, World},
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    Reflect, TypeRegistration, TypeRegistry,
+};
+use serde::{de::DeserializeOwned, de::DeserializeSeed, Deserialize};
+use serde_json::{json, Map, Value};
+use std::any::TypeId;
+
+/// The watches created by the `bevy/watch` method.
+#[derive(Resource, Default)]
+pub(crate) struct RemoteWatches {
+    next_id: u64,
+    watches: Vec<Watch>,
+}
+
+struct Watch {
+    id: u64,
+    connection: RemoteConnection,
+    entity: Entity,
+    components: Vec<WatchedComponent>,
+    last_run: Option<Tick>,
+}
+
+struct WatchedComponent {
+    type_id: TypeId,
+    present: bool,
+}
+
+/// Answers the requests received by the [`RemoteServer`], then notifies watches of changes.
+pub(crate) fn process_remote_requests(world: &mut World) {
+    let requests = world.resource::<RemoteServer>().take_requests();
+    for (request, connection) in requests {
+        let result = handle_request(world, &request, &connection);
+        if let Some(id) = request.id {
+            connection.send(&RemoteResponse::new(id, result));
+        }
+    }
+    update_watches(world);
+}
+
+fn handle_request(
+    world: &mut World,
+    request: &RemoteRequest,
+    connection: &RemoteConnection,
+) -> Result<Value, RemoteError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let params = request.params.clone();
+    match request.method.as_str() {
+        "bevy/entities" => Ok(entities(world)),
+        "bevy/list" => list(world, &registry, parse(params)?),
+        "bevy/get" => get(world, &registry, parse(params)?),
+        "bevy/insert" => insert(world, &registry, parse(params)?),
+        "bevy/remove" => remove(world, &registry, parse(params)?),
+        "bevy/spawn" => spawn(world, &registry, parse(params)?),
+        "bevy/despawn" => despawn(world, parse(params)?),
+        "bevy/query" => query(world, &registry, parse(params)?),
+        "bevy/list_resources" => Ok(list_resources(&registry)),
+        "bevy/get_resource" => get_resource(world, &registry, parse(params)?),
+        "bevy/insert_resource" => insert_resource(world, &registry, parse(params)?),
+        "bevy/remove_resource" => remove_resource(world, &registry, parse(params)?),
+        "bevy/watch" => watch(world, &registry, parse(params)?, connection),
+        "bevy/unwatch" => unwatch(world, parse(params)?),
+        method => Err(RemoteError::new(
+            error_codes::METHOD_NOT_FOUND,
+            format!("unknown method `{method}`"),
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+struct ListParams {
+    #[serde(default)]
+    entity: Option<Entity>,
+}
+
+#[derive(Deserialize)]
+struct ComponentsParams {
+    entity: Entity,
+    components: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct InsertParams {
+    entity: Entity,
+    components: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct SpawnParams {
+    components: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct EntityParams {
+    entity: Entity,
+}
+
+#[derive(Deserialize)]
+struct QueryParams {
+    components: Vec<String>,
+    #[serde(default)]
+    with: Vec<String>,
+    #[serde(default)]
+    without: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ResourceParams {
+    resource: String,
+}
+
+#[derive(Deserialize)]
+struct InsertResourceParams {
+    resource: String,
+    value: Value,
+}
+
+#[derive(Deserialize)]
+struct UnwatchParams {
+    watch: u64,
+}
+
+fn entities(world: &World) -> Value {
+    let entities: Vec<Entity> = world
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+        .collect();
+    json!(entities)
+}
+
+fn list(world: &World, registry: &TypeRegistry, params: ListParams) -> Result<Value, RemoteError> {
+    let mut type_paths: Vec<&str> = match params.entity {
+        Some(entity) => {
+            let entity = get_entity(world, entity)?;
+            entity
+                .archetype()
+                .components()
+                .filter_map(|id| world.components().get_info(id)?.type_id())
+                .filter_map(|type_id| registry.get(type_id))
+                .filter(|registration| registration.data::<ReflectComponent>().is_some())
+                .map(|registration| registration.type_info().type_path())
+                .collect()
+        }
+        None => registry
+            .iter()
+            .filter(|registration| registration.data::<ReflectComponent>().is_some())
+            .map(|registration| registration.type_info().type_path())
+            .collect(),
+    };
+    type_paths.sort_unstable();
+    Ok(json!(type_paths))
+}
+
+fn get(
+    world: &World,
+    registry: &TypeRegistry,
+    params: ComponentsParams,
+) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let mut components = Map::new();
+    for type_path in &params.components {
+        let (registration, reflect_component) = component_registration(registry, type_path)?;
+        let value = reflect_component.reflect(entity).ok_or_else(|| {
+            RemoteError::new(
+                error_codes::COMPONENT_NOT_PRESENT,
+                format!("{:?} doesn't have a `{type_path}` component", params.entity),
+            )
+        })?;
+        components.insert(
+            registration.type_info().type_path().to_string(),
+            serialize(value, registry)?,
+        );
+    }
+    Ok(Value::Object(components))
+}
+
+fn insert(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: InsertParams,
+) -> Result<Value, RemoteError> {
+    let components = deserialize_components(registry, params.components)?;
+    get_entity(world, params.entity)?;
+    let mut entity = world.entity_mut(params.entity);
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut entity, &*value, registry);
+    }
+    Ok(Value::Null)
+}
+
+fn remove(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: ComponentsParams,
+) -> Result<Value, RemoteError> {
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| component_registration(registry, type_path))
+        .collect::<Result<Vec<_>, _>>()?;
+    get_entity(world, params.entity)?;
+    let mut entity = world.entity_mut(params.entity);
+    for (_, reflect_component) in components {
+        reflect_component.remove(&mut entity);
+    }
+    Ok(Value::Null)
+}
+
+fn spawn(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: SpawnParams,
+) -> Result<Value, RemoteError> {
+    let components = deserialize_components(registry, params.components)?;
+    let mut entity = world.spawn_empty();
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut entity, &*value, registry);
+    }
+    Ok(json!(entity.id()))
+}
+
+fn despawn(world: &mut World, params: EntityParams) -> Result<Value, RemoteError> {
+    get_entity(world, params.entity)?;
+    world.despawn(params.entity);
+    Ok(Value::Null)
+}
+
+fn query(
+    world: &World,
+    registry: &TypeRegistry,
+    params: QueryParams,
+) -> Result<Value, RemoteError> {
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| component_registration(registry, type_path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let with = component_ids(world, registry, &params.with)?;
+    let without = component_ids(world, registry, &params.without)?;
+    let mut required = component_ids(world, registry, &params.components)?;
+    required.extend(with);
+
+    // A component that was never initialized isn't on any entity.
+    let Some(required) = required.into_iter().collect::<Option<Vec<ComponentId>>>() else {
+        return Ok(json!([]));
+    };
+    let without: Vec<ComponentId> = without.into_iter().flatten().collect();
+
+    let mut results = Vec::new();
+    for archetype in world.archetypes().iter() {
+        if !required.iter().all(|id| archetype.contains(*id))
+            || without.iter().any(|id| archetype.contains(*id))
+        {
+            continue;
+        }
+        for archetype_entity in archetype.entities() {
+            let entity = world.entity(archetype_entity.id());
+            let mut values = Map::new();
+            for (registration, reflect_component) in &components {
+                // The archetype contains the component, so it can be reflected.
+                if let Some(value) = reflect_component.reflect(entity) {
+                    values.insert(
+                        registration.type_info().type_path().to_string(),
+                        serialize(value, registry)?,
+                    );
+                }
+            }
+            results.push(json!({ "entity": entity.id(), "components": values }));
+        }
+    }
+    Ok(Value::Array(results))
+}
+
+fn list_resources(registry: &TypeRegistry) -> Value {
+    let mut type_paths: Vec<&str> = registry
+        .iter()
+        .filter(|registration| registration.data::<ReflectResource>().is_some())
+        .map(|registration| registration.type_info().type_path())
+        .collect();
+    type_paths.sort_unstable();
+    json!(type_paths)
+}
+
+fn get_resource(
+    world: &World,
+    registry: &TypeRegistry,
+    params: ResourceParams,
+) -> Result<Value, RemoteError> {
+    let (_, reflect_resource) = resource_registration(registry, &params.resource)?;
+    let value = reflect_resource.reflect(world).ok_or_else(|| {
+        RemoteError::new(
+            error_codes::RESOURCE_NOT_PRESENT,
+            format!("the `{}` resource doesn't exist", params.resource),
+        )
+    })?;
+    serialize(value, registry)
+}
+
+fn insert_resource(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: InsertResourceParams,
+) -> Result<Value, RemoteError> {
+    let (registration, reflect_resource) = resource_registration(registry, &params.resource)?;
+    let value = deserialize(registration, registry, params.value)?;
+    reflect_resource.insert(world, &*value);
+    Ok(Value::Null)
+}
+
+fn remove_resource(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: ResourceParams,
+) -> Result<Value, RemoteError> {
+    let (_, reflect_resource) = resource_registration(registry, &params.resource)?;
+    reflect_resource.remove(world);
+    Ok(Value::Null)
+}
+
+fn watch(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: ComponentsParams,
+    connection: &RemoteConnection,
+) -> Result<Value, RemoteError> {
+    get_entity(world, params.entity)?;
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| {
+            component_registration(registry, type_path).map(|(registration, _)| WatchedComponent {
+                type_id: registration.type_id(),
+                present: false,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut watches = world.resource_mut::<RemoteWatches>();
+    let id = watches.next_id;
+    watches.next_id += 1;
+    watches.watches.push(Watch {
+        id,
+        connection: connection.clone(),
+        entity: params.entity,
+        components,
+        last_run: None,
+    });
+    Ok(json!(id))
+}
+
+fn unwatch(world: &mut World, params: UnwatchParams) -> Result<Value, RemoteError> {
+    let mut watches = world.resource_mut::<RemoteWatches>();
+    let len = watches.watches.len();
+    watches.watches.retain(|watch| watch.id != params.watch);
+    if watches.watches.len() == len {
+        return Err(RemoteError::new(
+            error_codes::WATCH_NOT_FOUND,
+            format!("the watch {} doesn't exist", params.watch),
+        ));
+    }
+    Ok(Value::Null)
+}
+
+fn update_watches(world: &mut World) {
+    world.resource_scope(|world, mut watches: Mut<RemoteWatches>| {
+        if watches.watches.is_empty() {
+            return;
+        }
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let this_run = world.change_tick();
+        watches
+            .watches
+            .retain_mut(|watch| watch.update(world, &registry, this_run));
+    });
+}
+
+impl Watch {
+    /// Notifies the client of the changes since the last update, returning `false` once the watch
+    /// should be dropped.
+    fn update(&mut self, world: &World, registry: &TypeRegistry, this_run: Tick) -> bool {
+        let Some(entity) = world.get_entity(self.entity) else {
+            let params = json!({ "watch": self.id, "entity": self.entity, "despawned": true });
+            self.connection
+                .send(&RemoteRequest::notification(CHANGED_NOTIFICATION, params));
+            return false;
+        };
+
+        let mut changed = Map::new();
+        let mut removed = Vec::new();
+        for component in &mut self.components {
+            let Some(registration) = registry.get(component.type_id) else {
+                continue;
+            };
+            let type_path = registration.type_info().type_path();
+            let ticks = world
+                .components()
+                .get_id(component.type_id)
+                .and_then(|id| entity.get_change_ticks_by_id(id));
+            let Some(ticks) = ticks else {
+                if component.present {
+                    removed.push(type_path);
+                }
+                component.present = false;
+                continue;
+            };
+            let is_changed = match self.last_run {
+                Some(last_run) => ticks.is_changed(last_run, this_run),
+                None => true,
+            };
+            if !component.present || is_changed {
+                let value = registration
+                    .data::<ReflectComponent>()
+                    .and_then(|reflect_component| reflect_component.reflect(entity));
+                if let Some(Ok(value)) = value.map(|value| serialize(value, registry)) {
+                    changed.insert(type_path.to_string(), value);
+                }
+            }
+            component.present = true;
+        }
+        self.last_run = Some(this_run);
+
+        if changed.is_empty() && removed.is_empty() {
+            return true;
+        }
+        let params = json!({
+            "watch": self.id,
+            "entity": self.entity,
+            "components": changed,
+            "removed": removed,
+        });
+        self.connection
+            .send(&RemoteRequest::notification(CHANGED_NOTIFICATION, params))
+    }
+}
+
+fn parse<T: DeserializeOwned>(params: Value) -> Result<T, RemoteError> {
+    serde_json::from_value(params)
+        .map_err(|err| RemoteError::new(error_codes::INVALID_PARAMS, err.to_string()))
+}
+
+fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, RemoteError> {
+    world.get_entity(entity).ok_or_else(|| {
+        RemoteError::new(
+            error_codes::ENTITY_NOT_FOUND,
+            format!("{entity:?} doesn't exist"),
+        )
+    })
+}
+
+/// Finds the registration of a type by its type path, or by its short type path.
+fn registration<'r>(
+    registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<&'r TypeRegistration, RemoteError> {
+    registry
+        .get_with_type_path(type_path)
+        .or_else(|| registry.get_with_short_type_path(type_path))
+        .ok_or_else(|| {
+            RemoteError::new(
+                error_codes::UNKNOWN_TYPE,
+                format!("`{type_path}` isn't registered"),
+            )
+        })
+}
+
+fn component_registration<'r>(
+    registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectComponent), RemoteError> {
+    let registration = registration(registry, type_path)?;
+    let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+        RemoteError::new(
+            error_codes::UNKNOWN_TYPE,
+            format!("`{type_path}` isn't a reflected component"),
+        )
+    })?;
+    Ok((registration, reflect_component))
+}
+
+fn resource_registration<'r>(
+    registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectResource), RemoteError> {
+    let registration = registration(registry, type_path)?;
+    let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+        RemoteError::new(
+            error_codes::UNKNOWN_TYPE,
+            format!("`{type_path}` isn't a reflected resource"),
+        )
+    })?;
+    Ok((registration, reflect_resource))
+}
+
+/// Returns the id of each component, or `None` if it was never initialized in the world.
+fn component_ids(
+    world: &World,
+    registry: &TypeRegistry,
+    type_paths: &[String],
+) -> Result<Vec<Option<ComponentId>>, RemoteError> {
+    type_paths
+        .iter()
+        .map(|type_path| {
+            let (registration, _) = component_registration(registry, type_path)?;
+            Ok(world.components().get_id(registration.type_id()))
+        })
+        .collect()
+}
+
+fn serialize(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Value, RemoteError> {
+    serde_json::to_value(TypedReflectSerializer::new(value, registry))
+        .map_err(|err| RemoteError::new(error_codes::INTERNAL_ERROR, err.to_string()))
+}
+
+fn deserialize(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    value: Value,
+) -> Result<Box<dyn Reflect>, RemoteError> {
+    TypedReflectDeserializer::new(registration, registry)
+        .deserialize(value)
+        .map_err(|err| RemoteError::new(error_codes::INVALID_PARAMS, err.to_string()))
+}
+
+/// Deserializes all the components before any is inserted, so that a request either fully
+/// succeeds or leaves the world untouched.
+fn deserialize_components(
+    registry: &TypeRegistry,
+    components: Map<String, Value>,
+) -> Result<Vec<(&ReflectComponent, Box<dyn Reflect>)>, RemoteError> {
+    components
+        .into_iter()
+        .map(|(type_path, value)| {
+            let (registration, reflect_component) = component_registration(registry, &type_path)?;
+            Ok((
+                reflect_component,
+                deserialize(registration, registry, value)?,
+            ))
+        })
+        .collect()
+}
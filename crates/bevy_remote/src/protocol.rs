@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The version of the JSON-RPC protocol spoken by the remote server.
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// A [JSON-RPC] request, or a notification if it has no `id`.
+///
+/// Clients send requests to the server, which answers each request having an `id` with a
+/// [`RemoteResponse`]. The server sends notifications to clients, such as the
+/// [`CHANGED_NOTIFICATION`](crate::CHANGED_NOTIFICATION) of watches.
+///
+/// [JSON-RPC]: https://www.jsonrpc.org/specification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteRequest {
+    /// The version of the protocol, which must be [`JSONRPC_VERSION`].
+    pub jsonrpc: String,
+    /// The name of the method to call.
+    pub method: String,
+    /// The identifier of the request, repeated in its response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    /// The parameters of the method.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub params: Value,
+}
+
+impl RemoteRequest {
+    /// Creates a request of the given method.
+    pub fn new(id: impl Into<Value>, method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            method: method.into(),
+            id: Some(id.into()),
+            params,
+        }
+    }
+
+    /// Creates a notification of the given method, which isn't answered.
+    pub fn notification(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            method: method.into(),
+            id: None,
+            params,
+        }
+    }
+}
+
+/// A [JSON-RPC] response to a [`RemoteRequest`], holding either a result or an error.
+///
+/// [JSON-RPC]: https://www.jsonrpc.org/specification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteResponse {
+    /// The version of the protocol, which is [`JSONRPC_VERSION`].
+    pub jsonrpc: String,
+    /// The identifier of the request, or `null` if it couldn't be read.
+    pub id: Value,
+    /// The result of the method, if it succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// The error of the method, if it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RemoteError>,
+}
+
+impl RemoteResponse {
+    /// Creates a response to the request with the given `id`.
+    pub fn new(id: Value, result: Result<Value, RemoteError>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result,
+            error,
+        }
+    }
+
+    /// Returns the result of the method, or its error.
+    pub fn into_result(self) -> Result<Value, RemoteError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.result.unwrap_or_default()),
+        }
+    }
+}
+
+/// The error of a failed [`RemoteRequest`], with one of the [`error_codes`](crate::error_codes).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteError {
+    /// The kind of error.
+    pub code: i32,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl RemoteError {
+    /// Creates an error with the given code.
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// The codes of the errors returned by the remote server.
+///
+/// Codes from `-32768` to `-32000` are the ones defined by JSON-RPC.
+pub mod error_codes {
+    /// The request isn't valid JSON.
+    pub const PARSE_ERROR: i32 = -32700;
+    /// The request isn't a valid JSON-RPC request.
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// The method doesn't exist.
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    /// The parameters don't match the method, or a value couldn't be deserialized.
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// A value couldn't be serialized.
+    pub const INTERNAL_ERROR: i32 = -32603;
+    /// The entity doesn't exist.
+    pub const ENTITY_NOT_FOUND: i32 = -23401;
+    /// The type isn't registered, or isn't a reflected component or resource.
+    pub const UNKNOWN_TYPE: i32 = -23402;
+    /// The entity doesn't have the component.
+    pub const COMPONENT_NOT_PRESENT: i32 = -23403;
+    /// The resource isn't in the world.
+    pub const RESOURCE_NOT_PRESENT: i32 = -23404;
+    /// The watch doesn't exist.
+    pub const WATCH_NOT_FOUND: i32 = -23405;
+}
@@ -0,0 +1,226 @@
+use crate::{error_codes, RemoteError, RemoteRequest, RemoteResponse, JSONRPC_VERSION};
+use bevy_ecs::system::Resource;
+use bevy_utils::tracing::warn;
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often the background threads check whether the server is shutting down.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The server of [`RemotePlugin`](crate::RemotePlugin), receiving the requests of its clients.
+///
+/// Connections are accepted, read and written on background threads, while requests are
+/// processed by a system with exclusive access to the world. The connections are closed and the
+/// threads are joined when the server is dropped.
+#[derive(Resource)]
+pub struct RemoteServer {
+    local_addr: SocketAddr,
+    requests: Receiver<(RemoteRequest, RemoteConnection)>,
+    shutdown: Arc<AtomicBool>,
+    listener: Option<JoinHandle<()>>,
+}
+
+impl RemoteServer {
+    /// Starts listening for connections on `address`.
+    pub fn bind(address: SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let local_addr = listener.local_addr()?;
+        // The listener is polled, so that it can stop when the server is dropped.
+        listener.set_nonblocking(true)?;
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let listener = {
+            let shutdown = shutdown.clone();
+            thread::Builder::new()
+                .name("remote listener".to_string())
+                .spawn(move || listen(listener, sender, shutdown))?
+        };
+        Ok(Self {
+            local_addr,
+            requests: receiver,
+            shutdown,
+            listener: Some(listener),
+        })
+    }
+
+    /// The address the server is listening on.
+    ///
+    /// This is useful to find the port picked by the system when binding to port `0`.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Takes the requests received since the last call.
+    pub(crate) fn take_requests(&self) -> Vec<(RemoteRequest, RemoteConnection)> {
+        self.requests.try_iter().collect()
+    }
+}
+
+impl Drop for RemoteServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(listener) = self.listener.take() {
+            if listener.join().is_err() {
+                warn!("The remote listener thread panicked");
+            }
+        }
+    }
+}
+
+/// A connection to a client, used to send it responses and notifications.
+#[derive(Clone)]
+pub(crate) struct RemoteConnection(Sender<String>);
+
+impl RemoteConnection {
+    /// Sends a message to the client, returning `false` if it's disconnected.
+    pub(crate) fn send(&self, message: &impl Serialize) -> bool {
+        match serde_json::to_string(message) {
+            Ok(message) => self.0.send(message).is_ok(),
+            Err(err) => {
+                warn!("Failed to serialize a remote message: {err}");
+                true
+            }
+        }
+    }
+}
+
+/// Accepts connections until `shutdown` is set, then closes them.
+fn listen(
+    listener: TcpListener,
+    requests: Sender<(RemoteRequest, RemoteConnection)>,
+    shutdown: Arc<AtomicBool>,
+) {
+    let mut connections: Vec<ConnectionThreads> = Vec::new();
+    while !shutdown.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                connections.retain(|connection| !connection.is_finished());
+                match accept(stream, requests.clone(), shutdown.clone()) {
+                    Ok(connection) => connections.push(connection),
+                    Err(err) => warn!("Failed to accept a remote connection: {err}"),
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(err) => warn!("Failed to accept a remote connection: {err}"),
+        }
+    }
+    for connection in connections {
+        connection.close();
+    }
+}
+
+/// The threads reading and writing a connection.
+struct ConnectionThreads {
+    stream: TcpStream,
+    reader: JoinHandle<()>,
+    writer: JoinHandle<()>,
+}
+
+impl ConnectionThreads {
+    fn is_finished(&self) -> bool {
+        self.reader.is_finished() && self.writer.is_finished()
+    }
+
+    /// Closes the connection, then waits for its threads to finish.
+    ///
+    /// The writer thread only finishes once the server is shutting down or the client is gone.
+    fn close(self) {
+        // Unblocks the reader thread. This fails if the client already closed the connection.
+        let _ = self.stream.shutdown(Shutdown::Both);
+        if self.reader.join().is_err() || self.writer.join().is_err() {
+            warn!("A remote connection thread panicked");
+        }
+    }
+}
+
+fn accept(
+    stream: TcpStream,
+    requests: Sender<(RemoteRequest, RemoteConnection)>,
+    shutdown: Arc<AtomicBool>,
+) -> io::Result<ConnectionThreads> {
+    // Accepted streams may inherit the non-blocking mode of the listener.
+    stream.set_nonblocking(false)?;
+    let reader_stream = stream.try_clone()?;
+    let writer_stream = stream.try_clone()?;
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    let writer = thread::Builder::new()
+        .name("remote writer".to_string())
+        .spawn(move || write_messages(writer_stream, receiver, &shutdown))?;
+    let connection = RemoteConnection(sender);
+    let reader = thread::Builder::new()
+        .name("remote reader".to_string())
+        .spawn(move || read_requests(reader_stream, connection, requests))?;
+    Ok(ConnectionThreads {
+        stream,
+        reader,
+        writer,
+    })
+}
+
+fn write_messages(mut stream: TcpStream, messages: Receiver<String>, shutdown: &AtomicBool) {
+    loop {
+        let mut message = match messages.recv_timeout(POLL_INTERVAL) {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) if !shutdown.load(Ordering::Relaxed) => continue,
+            Err(_) => break,
+        };
+        message.push('\n');
+        if stream.write_all(message.as_bytes()).is_err() {
+            break;
+        }
+    }
+}
+
+fn read_requests(
+    stream: TcpStream,
+    connection: RemoteConnection,
+    requests: Sender<(RemoteRequest, RemoteConnection)>,
+) {
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_request(&line) {
+            Ok(request) => {
+                if requests.send((request, connection.clone())).is_err() {
+                    break;
+                }
+            }
+            Err((id, error)) => {
+                connection.send(&RemoteResponse::new(id, Err(error)));
+            }
+        }
+    }
+}
+
+fn parse_request(line: &str) -> Result<RemoteRequest, (Value, RemoteError)> {
+    let value: Value = serde_json::from_str(line).map_err(|err| {
+        (
+            Value::Null,
+            RemoteError::new(error_codes::PARSE_ERROR, err.to_string()),
+        )
+    })?;
+    let id = value.get("id").cloned().unwrap_or_default();
+    let request: RemoteRequest = serde_json::from_value(value).map_err(|err| {
+        let error = RemoteError::new(error_codes::INVALID_REQUEST, err.to_string());
+        (id.clone(), error)
+    })?;
+    if request.jsonrpc != JSONRPC_VERSION {
+        let error = RemoteError::new(
+            error_codes::INVALID_REQUEST,
+            format!("unsupported JSON-RPC version `{}`", request.jsonrpc),
+        );
+        return Err((id, error));
+    }
+    Ok(request)
+}
@@ -0,0 +1,274 @@
+//! Round trips between clients and the server of `RemotePlugin`.
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_remote::{
+    error_codes, RemoteError, RemotePlugin, RemoteRequest, RemoteResponse, RemoteServer,
+    CHANGED_NOTIFICATION,
+};
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+#[derive(Component, Reflect, Default, PartialEq, Debug)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Enemy;
+
+#[derive(Resource, Reflect, Default, PartialEq, Debug)]
+#[reflect(Resource)]
+struct Score {
+    value: u32,
+}
+
+struct Client {
+    writer: TcpStream,
+    reader: BufReader<TcpStream>,
+    next_id: u64,
+}
+
+impl Client {
+    fn connect(app: &App) -> Self {
+        let address = app.world.resource::<RemoteServer>().local_addr();
+        let writer = TcpStream::connect(address).unwrap();
+        writer
+            .set_read_timeout(Some(Duration::from_millis(10)))
+            .unwrap();
+        let reader = BufReader::new(writer.try_clone().unwrap());
+        Self {
+            writer,
+            reader,
+            next_id: 0,
+        }
+    }
+
+    fn send(&mut self, line: &str) {
+        self.writer.write_all(line.as_bytes()).unwrap();
+        self.writer.write_all(b"\n").unwrap();
+    }
+
+    /// Updates the app until the server sends a message.
+    fn receive(&mut self, app: &mut App) -> Value {
+        let mut line = String::new();
+        for _ in 0..500 {
+            app.update();
+            if self
+                .reader
+                .read_line(&mut line)
+                .is_ok_and(|_| line.ends_with('\n'))
+            {
+                return serde_json::from_str(&line).unwrap();
+            }
+        }
+        panic!("no message received");
+    }
+
+    fn call(&mut self, app: &mut App, method: &str, params: Value) -> Result<Value, RemoteError> {
+        self.next_id += 1;
+        let request = RemoteRequest::new(self.next_id, method, params);
+        self.send(&serde_json::to_string(&request).unwrap());
+        let response: RemoteResponse = serde_json::from_value(self.receive(app)).unwrap();
+        assert_eq!(response.id, json!(self.next_id));
+        response.into_result()
+    }
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.register_type::<Health>()
+        .register_type::<Enemy>()
+        .register_type::<Score>()
+        .add_plugins(RemotePlugin::with_address(([127, 0, 0, 1], 0)));
+    app
+}
+
+#[test]
+fn get_insert_remove_components() {
+    let mut app = app();
+    let entity = app.world.spawn(Health(10)).id();
+    let mut client = Client::connect(&app);
+
+    let list = client.call(&mut app, "bevy/list", json!({ "entity": entity }));
+    assert_eq!(list, Ok(json!(["remote::Health"])));
+
+    let health = client.call(
+        &mut app,
+        "bevy/get",
+        json!({ "entity": entity, "components": ["Health"] }),
+    );
+    assert_eq!(health, Ok(json!({ "remote::Health": [10] })));
+
+    client
+        .call(
+            &mut app,
+            "bevy/insert",
+            json!({ "entity": entity, "components": { "Health": [3], "Enemy": {} } }),
+        )
+        .unwrap();
+    assert_eq!(app.world.get::<Health>(entity), Some(&Health(3)));
+    assert!(app.world.entity(entity).contains::<Enemy>());
+
+    client
+        .call(
+            &mut app,
+            "bevy/remove",
+            json!({ "entity": entity, "components": ["Enemy"] }),
+        )
+        .unwrap();
+    assert!(!app.world.entity(entity).contains::<Enemy>());
+
+    let error = client
+        .call(
+            &mut app,
+            "bevy/get",
+            json!({ "entity": entity, "components": ["Enemy"] }),
+        )
+        .unwrap_err();
+    assert_eq!(error.code, error_codes::COMPONENT_NOT_PRESENT);
+
+    let error = client
+        .call(&mut app, "bevy/unknown", Value::Null)
+        .unwrap_err();
+    assert_eq!(error.code, error_codes::METHOD_NOT_FOUND);
+}
+
+#[test]
+fn spawn_query_and_despawn() {
+    let mut app = app();
+    app.world.spawn(Health(1));
+    let mut client = Client::connect(&app);
+
+    let enemy = client
+        .call(
+            &mut app,
+            "bevy/spawn",
+            json!({ "components": { "Health": [2], "Enemy": {} } }),
+        )
+        .unwrap();
+
+    let enemies = client.call(
+        &mut app,
+        "bevy/query",
+        json!({ "components": ["Health"], "with": ["Enemy"] }),
+    );
+    let expected = json!([{ "entity": enemy, "components": { "remote::Health": [2] } }]);
+    assert_eq!(enemies, Ok(expected));
+
+    let others = client
+        .call(
+            &mut app,
+            "bevy/query",
+            json!({ "components": ["Health"], "without": ["Enemy"] }),
+        )
+        .unwrap();
+    assert_eq!(others[0]["components"], json!({ "remote::Health": [1] }));
+
+    client
+        .call(&mut app, "bevy/despawn", json!({ "entity": enemy }))
+        .unwrap();
+    let error = client
+        .call(&mut app, "bevy/despawn", json!({ "entity": enemy }))
+        .unwrap_err();
+    assert_eq!(error.code, error_codes::ENTITY_NOT_FOUND);
+}
+
+#[test]
+fn read_write_resources() {
+    let mut app = app();
+    let mut client = Client::connect(&app);
+
+    let error = client
+        .call(
+            &mut app,
+            "bevy/get_resource",
+            json!({ "resource": "Score" }),
+        )
+        .unwrap_err();
+    assert_eq!(error.code, error_codes::RESOURCE_NOT_PRESENT);
+
+    client
+        .call(
+            &mut app,
+            "bevy/insert_resource",
+            json!({ "resource": "Score", "value": { "value": 7 } }),
+        )
+        .unwrap();
+    assert_eq!(app.world.resource::<Score>(), &Score { value: 7 });
+
+    let score = client.call(
+        &mut app,
+        "bevy/get_resource",
+        json!({ "resource": "Score" }),
+    );
+    assert_eq!(score, Ok(json!({ "value": 7 })));
+}
+
+#[test]
+fn watch_changes() {
+    let mut app = app();
+    let entity = app.world.spawn(Health(5)).id();
+    let mut client = Client::connect(&app);
+
+    let watch = client
+        .call(
+            &mut app,
+            "bevy/watch",
+            json!({ "entity": entity, "components": ["Health", "Enemy"] }),
+        )
+        .unwrap();
+    let notification = client.receive(&mut app);
+    assert_eq!(notification["method"], json!(CHANGED_NOTIFICATION));
+    assert_eq!(notification["params"]["watch"], watch);
+    assert_eq!(
+        notification["params"]["components"],
+        json!({ "remote::Health": [5] })
+    );
+
+    app.world.get_mut::<Health>(entity).unwrap().0 = 4;
+    let notification = client.receive(&mut app);
+    assert_eq!(
+        notification["params"]["components"],
+        json!({ "remote::Health": [4] })
+    );
+
+    app.world.entity_mut(entity).remove::<Health>();
+    let notification = client.receive(&mut app);
+    assert_eq!(notification["params"]["removed"], json!(["remote::Health"]));
+
+    app.world.despawn(entity);
+    let notification = client.receive(&mut app);
+    assert_eq!(notification["params"]["despawned"], json!(true));
+}
+
+#[test]
+fn invalid_requests() {
+    let mut app = app();
+    let mut client = Client::connect(&app);
+
+    client.send("{ not json");
+    let response: RemoteResponse = serde_json::from_value(client.receive(&mut app)).unwrap();
+    assert_eq!(response.error.unwrap().code, error_codes::PARSE_ERROR);
+
+    client.send(r#"{"jsonrpc": "1.0", "id": 3, "method": "bevy/entities"}"#);
+    let response: RemoteResponse = serde_json::from_value(client.receive(&mut app)).unwrap();
+    assert_eq!(response.id, json!(3));
+    assert_eq!(response.error.unwrap().code, error_codes::INVALID_REQUEST);
+}
+
+#[test]
+fn dropping_the_server_closes_connections() {
+    let mut app = app();
+    let address = app.world.resource::<RemoteServer>().local_addr();
+    let mut client = Client::connect(&app);
+    client.call(&mut app, "bevy/entities", Value::Null).unwrap();
+
+    app.world.remove_resource::<RemoteServer>();
+    let mut line = String::new();
+    assert_eq!(client.reader.read_line(&mut line).unwrap(), 0);
+    assert!(TcpStream::connect(address).is_err());
+}
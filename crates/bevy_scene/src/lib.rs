@@ -11,6 +11,7 @@ mod scene;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
+mod world_snapshot;
 
 #[cfg(feature = "serialize")]
 pub mod serde;
@@ -26,13 +27,14 @@ pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
+pub use world_snapshot::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle, SceneFilter,
-        SceneSpawner,
+        SceneSpawner, WorldSnapshot,
     };
 }
 
@@ -2,13 +2,16 @@
 
 use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
-use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy_reflect::serde::{
+    versioned_type_path, MigratingReflectDeserializer, ReflectSerializer, TypeTable,
+    TypedReflectSerializer,
+};
 use bevy_reflect::{
-    serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
+    serde::{UntypedReflectDeserializer, VersionedTypeRegistrationDeserializer},
     Reflect, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
-use serde::ser::SerializeMap;
+use serde::ser::{SerializeMap, SerializeSeq, SerializeTuple};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
@@ -178,17 +181,133 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
     where
         S: Serializer,
     {
+        let registry = self.registry.read();
         let mut state = serializer.serialize_map(Some(self.entries.len()))?;
         for reflect in self.entries {
             state.serialize_entry(
-                reflect.get_represented_type_info().unwrap().type_path(),
-                &TypedReflectSerializer::new(&**reflect, &self.registry.read()),
+                &*versioned_type_path(reflect.get_represented_type_info().unwrap(), &registry),
+                &TypedReflectSerializer::new(&**reflect, &registry),
             )?;
         }
         state.end()
     }
 }
 
+/// Handles serialization of a scene in a compact form, for binary formats like `bincode`.
+///
+/// The types of the resources and components of the scene are written once, in a [`TypeTable`]
+/// at the start of the scene, and the values then refer to their type with its numeric ID in the
+/// table. Structs are written without their field names, see
+/// [`TypedReflectSerializer::compact`].
+///
+/// The scene is serialized as a tuple of:
+/// 1. The type table
+/// 2. The resources, as a sequence of (type ID, value) tuples
+/// 3. The entities, as a sequence of (entity, components) tuples, the components being a
+///    sequence of (type ID, value) tuples
+///
+/// Use a [`CompactSceneDeserializer`] to deserialize it.
+pub struct CompactSceneSerializer<'a> {
+    /// The scene to serialize.
+    pub scene: &'a DynamicScene,
+    /// Type registry in which the components and resources types used in the scene are registered.
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> CompactSceneSerializer<'a> {
+    /// Creates a compact scene serializer.
+    pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistryArc) -> Self {
+        CompactSceneSerializer { scene, registry }
+    }
+}
+
+impl<'a> Serialize for CompactSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry.read();
+        let mut type_table = TypeTable::default();
+        let entity_components = self
+            .scene
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.components);
+        for value in self.scene.resources.iter().chain(entity_components) {
+            let type_info = value.get_represented_type_info().ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "cannot get type info for {}",
+                    value.reflect_type_path()
+                ))
+            })?;
+            type_table.insert(&versioned_type_path(type_info, &registry));
+        }
+
+        let mut state = serializer.serialize_tuple(3)?;
+        state.serialize_element(&type_table)?;
+        state.serialize_element(&CompactValuesSerializer {
+            values: &self.scene.resources,
+            registry: &registry,
+            type_table: &type_table,
+        })?;
+        state.serialize_element(&CompactEntitiesSerializer {
+            entities: &self.scene.entities,
+            registry: &registry,
+            type_table: &type_table,
+        })?;
+        state.end()
+    }
+}
+
+struct CompactEntitiesSerializer<'a> {
+    entities: &'a [DynamicEntity],
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a> Serialize for CompactEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_element(&(
+                entity.entity,
+                CompactValuesSerializer {
+                    values: &entity.components,
+                    registry: self.registry,
+                    type_table: self.type_table,
+                },
+            ))?;
+        }
+        state.end()
+    }
+}
+
+struct CompactValuesSerializer<'a> {
+    values: &'a [Box<dyn Reflect>],
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a> Serialize for CompactValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in self.values {
+            state.serialize_element(&ReflectSerializer::with_type_table(
+                &**value,
+                self.registry,
+                self.type_table,
+            ))?;
+        }
+        state.end()
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
@@ -475,8 +594,8 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     {
         let mut added = HashSet::new();
         let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        while let Some((registration, version)) =
+            map.next_key_seed(VersionedTypeRegistrationDeserializer::new(self.registry))?
         {
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
@@ -485,26 +604,216 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            entries.push(
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
-            );
+            entries.push(map.next_value_seed(MigratingReflectDeserializer::new(
+                registration,
+                version,
+                self.registry,
+            ))?);
         }
 
         Ok(entries)
     }
 }
 
+/// Handles deserialization of a scene serialized with a [`CompactSceneSerializer`].
+pub struct CompactSceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            3,
+            CompactSceneVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct CompactSceneVisitor<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactSceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("compact scene tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_table: TypeTable = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let resources = seq
+            .next_element_seed(CompactValuesDeserializer {
+                registry: self.type_registry,
+                type_table: &type_table,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let entities = seq
+            .next_element_seed(CompactEntitiesDeserializer {
+                registry: self.type_registry,
+                type_table: &type_table,
+            })?
+            .ok_or_else(|| Error::invalid_length(2, &self))?;
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+        })
+    }
+}
+
+struct CompactEntitiesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entity) = seq.next_element_seed(CompactEntityDeserializer {
+            registry: self.registry,
+            type_table: self.type_table,
+        })? {
+            entities.push(entity);
+        }
+        Ok(entities)
+    }
+}
+
+struct CompactEntityDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("entity and components tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let components = seq
+            .next_element_seed(CompactValuesDeserializer {
+                registry: self.registry,
+                type_table: self.type_table,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(DynamicEntity { entity, components })
+    }
+}
+
+struct CompactValuesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    type_table: &'a TypeTable,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of reflect values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(
+            UntypedReflectDeserializer::with_type_table(self.registry, self.type_table),
+        )? {
+            // Deserialized values always have a represented type.
+            let type_info = value.get_represented_type_info().unwrap();
+            if !added.insert(type_info.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    type_info.type_path(),
+                )));
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ron;
-    use crate::serde::{SceneDeserializer, SceneSerializer};
+    use crate::serde::{
+        CompactSceneDeserializer, CompactSceneSerializer, SceneDeserializer, SceneSerializer,
+    };
     use crate::{DynamicScene, DynamicSceneBuilder};
     use bevy_ecs::entity::{Entity, EntityMapper, MapEntities};
     use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
     use bevy_ecs::query::{With, Without};
     use bevy_ecs::reflect::{AppTypeRegistry, ReflectMapEntities};
     use bevy_ecs::world::FromWorld;
-    use bevy_reflect::{Reflect, ReflectSerialize};
+    use bevy_reflect::{
+        DynamicStruct, DynamicTupleStruct, Reflect, ReflectSerialize, Struct, TypePath,
+    };
     use bevy_utils::EntityHashMap;
     use bincode::Options;
     use serde::de::DeserializeSeed;
@@ -693,6 +1002,58 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_migrate_components() {
+        #[derive(Reflect)]
+        struct OldFoo {
+            value: i32,
+        }
+
+        let mut world = create_world();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register_type_alias::<Foo>("game::Foo");
+            registry.register_migration::<Foo, OldFoo>(0, |value| {
+                let old = value.take::<DynamicStruct>().unwrap();
+                let mut foo = DynamicTupleStruct::default();
+                foo.insert_boxed(old.field("value").unwrap().clone_value());
+                Box::new(foo)
+            });
+        }
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "game::Foo": (
+          value: 123,
+        ),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut map = EntityHashMap::default();
+        scene.write_to_world(&mut world, &mut map).unwrap();
+        let foos: Vec<i32> = world
+            .query::<&Foo>()
+            .iter(&world)
+            .map(|foo| foo.0)
+            .collect();
+        assert_eq!(foos, vec![123]);
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let serialized = scene.serialize_ron(registry).unwrap();
+        assert!(serialized.contains(r#""bevy_scene::serde::tests::Foo@1": (123)"#));
+    }
+
     #[test]
     fn should_roundtrip_with_later_generations_and_obsolete_references() {
         let mut world = create_world();
@@ -867,6 +1228,50 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_compact() {
+        let mut world = create_world();
+
+        for i in 0..10 {
+            world.spawn(MyComponent {
+                foo: [i, 2, 3],
+                bar: (1.3, 3.7),
+                baz: MyEnum::Tuple("Hello World!".to_string()),
+            });
+        }
+
+        let registry = world.resource::<AppTypeRegistry>();
+
+        let scene = DynamicScene::from_world(&world);
+
+        let compact_serializer = CompactSceneSerializer::new(&scene, &registry.0);
+        let serialized_scene = bincode::serialize(&compact_serializer).unwrap();
+        let scene_serializer = SceneSerializer::new(&scene, &registry.0);
+        let full_size = bincode::serialize(&scene_serializer).unwrap().len();
+        // The type path of `MyComponent` is only written once, instead of once per entity.
+        let type_path_len = MyComponent::type_path().len();
+        assert!(serialized_scene.len() + 9 * type_path_len <= full_size);
+
+        let scene_deserializer = CompactSceneDeserializer {
+            type_registry: &registry.0.read(),
+        };
+        let deserialized_scene = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(scene_deserializer, &serialized_scene)
+            .unwrap();
+        assert_eq!(10, deserialized_scene.entities.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let serialized_scene = postcard::to_allocvec(&compact_serializer).unwrap();
+        let scene_deserializer = CompactSceneDeserializer {
+            type_registry: &registry.0.read(),
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+            .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
@@ -0,0 +1,679 @@
+use crate::SceneFilter;
+use bevy_ecs::{
+    archetype::ArchetypeId,
+    component::{Component, ComponentId},
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+    system::Resource,
+    world::World,
+};
+use bevy_reflect::{FromType, Reflect, TypeRegistry};
+use bevy_utils::{EntityHashMap, HashSet};
+
+/// A snapshot of some components and resources of a [`World`], that can be restored any number
+/// of times, for example to roll back and resimulate the world in networked games.
+///
+/// Unlike a [`DynamicScene`](crate::DynamicScene), a snapshot is meant to be captured and
+/// restored every frame: it reuses its storage between captures, and restores entities with
+/// their original [`Entity`] IDs.
+///
+/// # Component and Resource Capture
+///
+/// By default, all components and resources registered in a world's [`AppTypeRegistry`] are
+/// captured. This can be changed by [specifying a filter](WorldSnapshot::with_filter) for
+/// components, or a [resource filter](WorldSnapshot::with_resource_filter).
+///
+/// Types registered with [`ReflectSnapshotComponent`] or [`ReflectSnapshotResource`] type data
+/// are captured by cloning them, which avoids allocating for each value once the snapshot has
+/// been captured a first time. Other types are captured through their [`ReflectComponent`] or
+/// [`ReflectResource`] type data, which clones their reflected value on each capture.
+///
+/// # Restoring
+///
+/// [Restoring](WorldSnapshot::restore) a snapshot sets the captured components and resources
+/// back to their captured value, inserting them if they were removed, and removes the ones that
+/// were added since, for the types the snapshot captured at least once. Other components and
+/// resources are left untouched. Captured entities that were despawned are spawned again with
+/// the same ID, unless the ID has been reused, in which case a new entity is spawned and added
+/// to the entity map.
+///
+/// Entities spawned since the capture are not despawned. Use
+/// [`contains_entity`](WorldSnapshot::contains_entity) to find them.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_scene::{ReflectSnapshotComponent, WorldSnapshot};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::AppTypeRegistry;
+/// # use bevy_reflect::Reflect;
+/// # use bevy_utils::EntityHashMap;
+/// #[derive(Component, Reflect, Clone, Default)]
+/// #[reflect(Component, SnapshotComponent)]
+/// struct Position(f32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Position>();
+///
+/// let entity = world.spawn(Position(1.0)).id();
+/// let mut snapshot = WorldSnapshot::default();
+/// snapshot.capture(&world, [entity]);
+///
+/// world.entity_mut(entity).get_mut::<Position>().unwrap().0 = 2.0;
+/// world.despawn(entity);
+///
+/// snapshot.restore(&mut world, &mut EntityHashMap::default());
+/// assert_eq!(world.get::<Position>(entity).unwrap().0, 1.0);
+/// ```
+#[derive(Default)]
+pub struct WorldSnapshot {
+    component_filter: SceneFilter,
+    resource_filter: SceneFilter,
+    /// The captured entities, in ascending order.
+    entities: Vec<Entity>,
+    components: Vec<ComponentSnapshot>,
+    resources: Vec<ResourceSnapshot>,
+    /// The components and resources that have been checked against the filters and registry.
+    checked_ids: HashSet<ComponentId>,
+    checked_archetypes: HashSet<ArchetypeId>,
+}
+
+impl WorldSnapshot {
+    /// Specify a custom component [`SceneFilter`] to be used with this snapshot.
+    ///
+    /// This clears the snapshot.
+    #[must_use]
+    pub fn with_filter(mut self, filter: SceneFilter) -> Self {
+        self.component_filter = filter;
+        self.clear();
+        self
+    }
+
+    /// Specify a custom resource [`SceneFilter`] to be used with this snapshot.
+    ///
+    /// This clears the snapshot.
+    #[must_use]
+    pub fn with_resource_filter(mut self, filter: SceneFilter) -> Self {
+        self.resource_filter = filter;
+        self.clear();
+        self
+    }
+
+    /// Removes the captured entities, components and resources, and the storage reused between
+    /// captures.
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.components.clear();
+        self.resources.clear();
+        self.checked_ids.clear();
+        self.checked_archetypes.clear();
+    }
+
+    /// Returns the captured entities, in ascending order.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Returns `true` if `entity` was captured.
+    pub fn contains_entity(&self, entity: Entity) -> bool {
+        self.entities.binary_search(&entity).is_ok()
+    }
+
+    /// Captures the given `entities` and the resources of `world`, replacing the previous capture.
+    ///
+    /// Entities that don't exist are ignored. A snapshot should only be used with the world it
+    /// was first captured from.
+    ///
+    /// # Panics
+    ///
+    /// If `world` doesn't contain an [`AppTypeRegistry`] resource.
+    pub fn capture(&mut self, world: &World, entities: impl IntoIterator<Item = Entity>) {
+        self.entities.clear();
+        self.entities.extend(
+            entities
+                .into_iter()
+                .filter(|&entity| world.get_entity(entity).is_some()),
+        );
+        self.entities.sort_unstable();
+        self.entities.dedup();
+
+        self.check_new_types(world);
+
+        for snapshot in &mut self.components {
+            snapshot.store.capture(world, &self.entities);
+        }
+        for snapshot in &mut self.resources {
+            snapshot.store.capture(world);
+        }
+    }
+
+    /// Adds the snapshots of the components and resources that haven't been checked yet.
+    fn check_new_types(&mut self, world: &World) {
+        let mut type_registry = None;
+
+        for &entity in &self.entities {
+            let archetype_id = world.entity(entity).location().archetype_id;
+            if !self.checked_archetypes.insert(archetype_id) {
+                continue;
+            }
+            let archetype = world.archetypes().get(archetype_id).unwrap();
+            for component_id in archetype.components() {
+                if !self.checked_ids.insert(component_id) {
+                    continue;
+                }
+                let type_registry = type_registry
+                    .get_or_insert_with(|| world.resource::<AppTypeRegistry>().clone());
+                if let Some(snapshot) = ComponentSnapshot::new(
+                    world,
+                    component_id,
+                    &self.component_filter,
+                    &type_registry.read(),
+                ) {
+                    self.components.push(snapshot);
+                }
+            }
+        }
+
+        for (component_id, data) in world.storages().resources.iter() {
+            if !data.is_present() || !self.checked_ids.insert(component_id) {
+                continue;
+            }
+            let type_registry =
+                type_registry.get_or_insert_with(|| world.resource::<AppTypeRegistry>().clone());
+            if let Some(snapshot) = ResourceSnapshot::new(
+                world,
+                component_id,
+                &self.resource_filter,
+                &type_registry.read(),
+            ) {
+                self.resources.push(snapshot);
+            }
+        }
+    }
+
+    /// Restores the captured components and resources to `world`.
+    ///
+    /// Captured entities are restored with their original ID when possible. Otherwise, the
+    /// entity they were restored to is stored in `entity_map`, which should be reused between
+    /// restores, and the entity references of components with [`ReflectMapEntities`] type data
+    /// are mapped to the restored entities.
+    ///
+    /// # Panics
+    ///
+    /// If `world` doesn't contain an [`AppTypeRegistry`] resource.
+    pub fn restore(&self, world: &mut World, entity_map: &mut EntityHashMap<Entity, Entity>) {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+
+        for snapshot in &self.resources {
+            snapshot.store.restore(world, &type_registry);
+        }
+
+        // only allocate if some entities can't be restored with their original ID
+        let mut mapped_entities: Option<Vec<Entity>> = None;
+        for (index, &entity) in self.entities.iter().enumerate() {
+            let target = restore_entity(world, entity, entity_map);
+            if target != entity {
+                mapped_entities.get_or_insert_with(|| self.entities.clone())[index] = target;
+            }
+        }
+        let entities = mapped_entities.as_deref().unwrap_or(&self.entities);
+
+        for snapshot in &self.components {
+            snapshot.store.restore(world, entities, &type_registry);
+        }
+
+        if mapped_entities.is_none() {
+            return;
+        }
+
+        // Updates the references to the captured entities. Other entities are mapped to
+        // themselves, so that their references are kept as they are.
+        let mut references: EntityHashMap<Entity, Entity> = world
+            .iter_entities()
+            .map(|entity| (entity.id(), entity.id()))
+            .collect();
+        references.extend(self.entities.iter().copied().zip(entities.iter().copied()));
+        for snapshot in &self.components {
+            if let Some(map_entities) = &snapshot.map_entities {
+                let entities: Vec<Entity> = entities
+                    .iter()
+                    .copied()
+                    .filter(|&entity| world.entity(entity).contains_id(snapshot.component_id))
+                    .collect();
+                map_entities.map_entities(world, &mut references, &entities);
+            }
+        }
+    }
+}
+
+/// Returns the entity that the captured `entity` should be restored to, spawning it if needed.
+fn restore_entity(
+    world: &mut World,
+    entity: Entity,
+    entity_map: &mut EntityHashMap<Entity, Entity>,
+) -> Entity {
+    if let Some(&mapped) = entity_map.get(&entity) {
+        if world.get_entity(mapped).is_some() {
+            return mapped;
+        }
+    }
+    if world.get_or_spawn(entity).is_some() {
+        entity_map.remove(&entity);
+        return entity;
+    }
+    // the ID has been reused by another entity
+    let mapped = world.spawn_empty().id();
+    entity_map.insert(entity, mapped);
+    mapped
+}
+
+struct ComponentSnapshot {
+    component_id: ComponentId,
+    map_entities: Option<ReflectMapEntities>,
+    store: Box<dyn ComponentStore>,
+}
+
+impl ComponentSnapshot {
+    fn new(
+        world: &World,
+        component_id: ComponentId,
+        filter: &SceneFilter,
+        type_registry: &TypeRegistry,
+    ) -> Option<Self> {
+        let type_id = world.components().get_info(component_id)?.type_id()?;
+        if filter.is_denied_by_id(type_id) {
+            return None;
+        }
+
+        let registration = type_registry.get(type_id)?;
+        let store = if let Some(snapshot) = registration.data::<ReflectSnapshotComponent>() {
+            (snapshot.new_store)(component_id)
+        } else {
+            Box::new(ReflectComponentStore {
+                component_id,
+                reflect_component: registration.data::<ReflectComponent>()?.clone(),
+                values: Vec::new(),
+            })
+        };
+        Some(Self {
+            component_id,
+            map_entities: registration.data::<ReflectMapEntities>().cloned(),
+            store,
+        })
+    }
+}
+
+struct ResourceSnapshot {
+    store: Box<dyn ResourceStore>,
+}
+
+impl ResourceSnapshot {
+    fn new(
+        world: &World,
+        component_id: ComponentId,
+        filter: &SceneFilter,
+        type_registry: &TypeRegistry,
+    ) -> Option<Self> {
+        let type_id = world.components().get_info(component_id)?.type_id()?;
+        if filter.is_denied_by_id(type_id) {
+            return None;
+        }
+
+        let registration = type_registry.get(type_id)?;
+        let store = if let Some(snapshot) = registration.data::<ReflectSnapshotResource>() {
+            (snapshot.new_store)()
+        } else {
+            Box::new(ReflectResourceStore {
+                reflect_resource: registration.data::<ReflectResource>()?.clone(),
+                value: None,
+            })
+        };
+        Some(Self { store })
+    }
+}
+
+/// The captured values of a component type, for each captured entity.
+trait ComponentStore: Send + Sync + 'static {
+    fn capture(&mut self, world: &World, entities: &[Entity]);
+    fn restore(&self, world: &mut World, entities: &[Entity], type_registry: &TypeRegistry);
+}
+
+/// The captured value of a resource type.
+trait ResourceStore: Send + Sync + 'static {
+    fn capture(&mut self, world: &World);
+    fn restore(&self, world: &mut World, type_registry: &TypeRegistry);
+}
+
+struct CloneComponentStore<C> {
+    component_id: ComponentId,
+    values: Vec<Option<C>>,
+}
+
+impl<C: Component + Clone> ComponentStore for CloneComponentStore<C> {
+    fn capture(&mut self, world: &World, entities: &[Entity]) {
+        self.values.resize_with(entities.len(), || None);
+        for (value, &entity) in self.values.iter_mut().zip(entities) {
+            match (value.as_mut(), world.get::<C>(entity)) {
+                (Some(value), Some(component)) => value.clone_from(component),
+                (None, Some(component)) => *value = Some(component.clone()),
+                (_, None) => *value = None,
+            }
+        }
+    }
+
+    fn restore(&self, world: &mut World, entities: &[Entity], _type_registry: &TypeRegistry) {
+        for (value, &entity) in self.values.iter().zip(entities) {
+            let mut entity = world.entity_mut(entity);
+            match (value, entity.get_mut::<C>()) {
+                (Some(value), Some(mut component)) => component.clone_from(value),
+                (Some(value), None) => {
+                    entity.insert(value.clone());
+                }
+                (None, _) => {
+                    if entity.contains_id(self.component_id) {
+                        entity.remove::<C>();
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct ReflectComponentStore {
+    component_id: ComponentId,
+    reflect_component: ReflectComponent,
+    values: Vec<Option<Box<dyn Reflect>>>,
+}
+
+impl ComponentStore for ReflectComponentStore {
+    fn capture(&mut self, world: &World, entities: &[Entity]) {
+        self.values.resize_with(entities.len(), || None);
+        for (value, &entity) in self.values.iter_mut().zip(entities) {
+            *value = self
+                .reflect_component
+                .reflect(world.entity(entity))
+                .map(Reflect::clone_value);
+        }
+    }
+
+    fn restore(&self, world: &mut World, entities: &[Entity], type_registry: &TypeRegistry) {
+        for (value, &entity) in self.values.iter().zip(entities) {
+            let mut entity = world.entity_mut(entity);
+            match value {
+                Some(value) => {
+                    self.reflect_component
+                        .apply_or_insert(&mut entity, &**value, type_registry);
+                }
+                None => {
+                    if entity.contains_id(self.component_id) {
+                        self.reflect_component.remove(&mut entity);
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct CloneResourceStore<R> {
+    value: Option<R>,
+}
+
+impl<R: Resource + Clone> ResourceStore for CloneResourceStore<R> {
+    fn capture(&mut self, world: &World) {
+        match (self.value.as_mut(), world.get_resource::<R>()) {
+            (Some(value), Some(resource)) => value.clone_from(resource),
+            (None, Some(resource)) => self.value = Some(resource.clone()),
+            (_, None) => self.value = None,
+        }
+    }
+
+    fn restore(&self, world: &mut World, _type_registry: &TypeRegistry) {
+        match (&self.value, world.get_resource_mut::<R>()) {
+            (Some(value), Some(mut resource)) => resource.clone_from(value),
+            (Some(value), None) => world.insert_resource(value.clone()),
+            (None, _) => {
+                world.remove_resource::<R>();
+            }
+        }
+    }
+}
+
+struct ReflectResourceStore {
+    reflect_resource: ReflectResource,
+    value: Option<Box<dyn Reflect>>,
+}
+
+impl ResourceStore for ReflectResourceStore {
+    fn capture(&mut self, world: &World) {
+        self.value = self
+            .reflect_resource
+            .reflect(world)
+            .map(Reflect::clone_value);
+    }
+
+    fn restore(&self, world: &mut World, _type_registry: &TypeRegistry) {
+        match &self.value {
+            Some(value) => self.reflect_resource.apply_or_insert(world, &**value),
+            None => self.reflect_resource.remove(world),
+        }
+    }
+}
+
+/// Type data that lets a [`WorldSnapshot`] capture a [`Component`] by cloning it, instead of
+/// cloning its reflected value.
+///
+/// This is added to a type's registration with `#[reflect(SnapshotComponent)]`, and requires
+/// the type to implement [`Clone`].
+#[derive(Clone)]
+pub struct ReflectSnapshotComponent {
+    new_store: fn(ComponentId) -> Box<dyn ComponentStore>,
+}
+
+impl<C: Component + Clone> FromType<C> for ReflectSnapshotComponent {
+    fn from_type() -> Self {
+        Self {
+            new_store: |component_id| {
+                Box::new(CloneComponentStore::<C> {
+                    component_id,
+                    values: Vec::new(),
+                })
+            },
+        }
+    }
+}
+
+/// Type data that lets a [`WorldSnapshot`] capture a [`Resource`] by cloning it, instead of
+/// cloning its reflected value.
+///
+/// This is added to a type's registration with `#[reflect(SnapshotResource)]`, and requires
+/// the type to implement [`Clone`].
+#[derive(Clone)]
+pub struct ReflectSnapshotResource {
+    new_store: fn() -> Box<dyn ResourceStore>,
+}
+
+impl<R: Resource + Clone> FromType<R> for ReflectSnapshotResource {
+    fn from_type() -> Self {
+        Self {
+            new_store: || Box::new(CloneResourceStore::<R> { value: None }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityMapper, MapEntities},
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+        system::Resource,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+    use bevy_utils::EntityHashMap;
+
+    use super::{ReflectSnapshotComponent, ReflectSnapshotResource, WorldSnapshot};
+    use crate::SceneFilter;
+
+    #[derive(Component, Reflect, Clone, Default, PartialEq, Debug)]
+    #[reflect(Component, SnapshotComponent)]
+    struct Cloned(Vec<u32>);
+
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Reflected(u32);
+
+    #[derive(Component, Reflect, Clone, PartialEq, Debug)]
+    #[reflect(Component, MapEntities, SnapshotComponent)]
+    struct Target(Entity);
+
+    impl Default for Target {
+        fn default() -> Self {
+            Self(Entity::PLACEHOLDER)
+        }
+    }
+
+    impl MapEntities for Target {
+        fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+            self.0 = entity_mapper.map_entity(self.0);
+        }
+    }
+
+    #[derive(Resource, Reflect, Clone, Default, PartialEq, Debug)]
+    #[reflect(Resource, SnapshotResource)]
+    struct ClonedResource(u32);
+
+    #[derive(Resource, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct ReflectedResource(u32);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Cloned>();
+            registry.register::<Reflected>();
+            registry.register::<Target>();
+            registry.register::<ClonedResource>();
+            registry.register::<ReflectedResource>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn restores_components() {
+        let mut world = create_world();
+        let a = world.spawn((Cloned(vec![1]), Reflected(1))).id();
+        let b = world.spawn(Cloned(vec![2])).id();
+        let c = world.spawn(Reflected(3)).id();
+
+        let mut snapshot = WorldSnapshot::default();
+        snapshot.capture(&world, [c, a, b]);
+        assert_eq!(snapshot.entities(), &[a, b, c]);
+
+        for _ in 0..2 {
+            world.get_mut::<Cloned>(a).unwrap().0.push(10);
+            world.entity_mut(a).remove::<Reflected>();
+            world.entity_mut(b).insert(Reflected(20));
+            let d = world.spawn(Cloned(vec![4])).id();
+            world.despawn(c);
+            assert!(!snapshot.contains_entity(d));
+
+            snapshot.restore(&mut world, &mut EntityHashMap::default());
+
+            assert_eq!(world.get::<Cloned>(a), Some(&Cloned(vec![1])));
+            assert_eq!(world.get::<Reflected>(a), Some(&Reflected(1)));
+            assert_eq!(world.get::<Cloned>(b), Some(&Cloned(vec![2])));
+            assert_eq!(world.get::<Reflected>(b), None);
+            assert_eq!(world.get::<Reflected>(c), Some(&Reflected(3)));
+            assert_eq!(world.get::<Cloned>(c), None);
+            world.despawn(d);
+        }
+    }
+
+    #[test]
+    fn restores_resources() {
+        let mut world = create_world();
+        world.insert_resource(ClonedResource(1));
+        world.insert_resource(ReflectedResource(2));
+
+        let mut snapshot = WorldSnapshot::default();
+        snapshot.capture(&world, []);
+
+        world.resource_mut::<ClonedResource>().0 = 10;
+        world.remove_resource::<ReflectedResource>();
+        snapshot.restore(&mut world, &mut EntityHashMap::default());
+        assert_eq!(world.resource::<ClonedResource>(), &ClonedResource(1));
+        assert_eq!(world.resource::<ReflectedResource>(), &ReflectedResource(2));
+
+        // resources removed at the time of the capture are removed when restoring
+        world.remove_resource::<ClonedResource>();
+        snapshot.capture(&world, []);
+        world.insert_resource(ClonedResource(3));
+        snapshot.restore(&mut world, &mut EntityHashMap::default());
+        assert!(!world.contains_resource::<ClonedResource>());
+    }
+
+    #[test]
+    fn filters() {
+        let mut world = create_world();
+        world.insert_resource(ClonedResource(1));
+        world.insert_resource(ReflectedResource(2));
+        let entity = world.spawn((Cloned(vec![1]), Reflected(1))).id();
+
+        let mut snapshot = WorldSnapshot::default()
+            .with_filter(SceneFilter::default().deny::<Reflected>())
+            .with_resource_filter(SceneFilter::default().allow::<ClonedResource>());
+        snapshot.capture(&world, [entity]);
+
+        world
+            .entity_mut(entity)
+            .insert((Cloned(vec![]), Reflected(10)));
+        world.resource_mut::<ClonedResource>().0 = 10;
+        world.resource_mut::<ReflectedResource>().0 = 20;
+        snapshot.restore(&mut world, &mut EntityHashMap::default());
+
+        assert_eq!(world.get::<Cloned>(entity), Some(&Cloned(vec![1])));
+        assert_eq!(world.get::<Reflected>(entity), Some(&Reflected(10)));
+        assert_eq!(world.resource::<ClonedResource>(), &ClonedResource(1));
+        assert_eq!(
+            world.resource::<ReflectedResource>(),
+            &ReflectedResource(20)
+        );
+    }
+
+    #[test]
+    fn maps_reused_entities() {
+        let mut world = create_world();
+        let a = world.spawn(Cloned(vec![1])).id();
+        let b = world.spawn(Target(a)).id();
+        let outside = world.spawn_empty().id();
+        let c = world.spawn(Target(outside)).id();
+
+        let mut snapshot = WorldSnapshot::default();
+        snapshot.capture(&world, [a, b, c]);
+
+        // the index of `a` is reused by another entity
+        world.despawn(a);
+        let reused = world.spawn_empty().id();
+        assert_eq!(reused.index(), a.index());
+
+        let mut entity_map = EntityHashMap::default();
+        snapshot.restore(&mut world, &mut entity_map);
+        let mapped = entity_map[&a];
+        assert_ne!(mapped, reused);
+        assert_eq!(world.get::<Cloned>(mapped), Some(&Cloned(vec![1])));
+        assert_eq!(world.get::<Target>(b), Some(&Target(mapped)));
+        assert_eq!(world.get::<Target>(c), Some(&Target(outside)));
+        assert!(world.get::<Cloned>(reused).is_none());
+
+        // restoring again reuses the mapped entity
+        snapshot.restore(&mut world, &mut entity_map);
+        assert_eq!(entity_map[&a], mapped);
+        assert_eq!(world.get::<Target>(b), Some(&Target(mapped)));
+    }
+}